@@ -1,13 +1,17 @@
 use std::{env, fs, path::Path};
 
-use clap::{Parser, Subcommand, command};
+use clap::{Parser, Subcommand};
 use owo_colors::OwoColorize;
 
 use crate::errors::CliError;
 
 #[derive(Subcommand, Debug, Clone)]
 pub enum CliCommand {
-    Build,
+    Build {
+        /// Build without the runtime checks emitted in debug builds
+        #[arg(long)]
+        release: bool,
+    },
 }
 
 #[derive(Parser, Debug)]
@@ -54,16 +58,8 @@ pub fn print_warning(warning: &str, depth: usize) {
 }
 
 pub fn get_current_directory() -> Result<std::path::PathBuf, CliError> {
-    let result = env::current_dir();
-
-    if result.is_err() {
-        return Err(CliError::InternalError(format!(
-            "Failed to get current directory: {}",
-            result.unwrap_err()
-        )));
-    }
-
-    Ok(result.unwrap())
+    env::current_dir()
+        .map_err(|err| CliError::InternalError(format!("Failed to get current directory: {}", err)))
 }
 
 pub fn make_folder(current_dir: &Path, name: &str) -> Result<(), CliError> {
@@ -82,10 +78,3 @@ pub fn folder_exists(current_dir: &Path, name: &str) -> Result<(), CliError> {
         ))),
     }
 }
-
-pub fn read_file(file_path: &Path) -> Result<String, CliError> {
-    let content = fs::read_to_string(file_path)
-        .map_err(|e| CliError::IOError(format!("Failed to read file: {}", e)))?;
-
-    Ok(content)
-}
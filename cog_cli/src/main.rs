@@ -1,4 +1,5 @@
 use std::{
+    collections::HashSet,
     fs::File,
     io::Write,
     path::Path,
@@ -7,7 +8,7 @@ use std::{
 };
 
 use clap::Parser;
-use cog_parser::parser;
+use cog_core::modules::ModuleTree;
 use inkwell::{
     OptimizationLevel,
     context::Context,
@@ -15,10 +16,7 @@ use inkwell::{
 };
 
 use crate::{
-    cli::{
-        Cli, CliCommand, make_folder, print_error, print_section, print_value, print_warning,
-        read_file,
-    },
+    cli::{Cli, CliCommand, make_folder, print_error, print_section, print_value, print_warning},
     config::find_target_files,
     errors::CliError,
 };
@@ -50,33 +48,29 @@ fn main() {
         (false, false) => LogLevel::Default,
     };
 
-    let current_dir = cli::get_current_directory();
-
-    if current_dir.is_err() {
-        let err = current_dir.unwrap_err();
-        print_error(err.to_string().as_str(), 0);
-        process::exit(1);
-    }
-
-    let current_dir = current_dir.unwrap();
+    let current_dir = match cli::get_current_directory() {
+        Ok(dir) => dir,
+        Err(err) => {
+            print_error(err.to_string().as_str(), 0);
+            process::exit(1);
+        }
+    };
 
     match cli.command {
-        CliCommand::Build => build(&current_dir, log_level),
+        CliCommand::Build { release } => build(&current_dir, log_level, release),
     }
 }
 
-fn build(current_dir: &Path, log_level: LogLevel) {
+fn build(current_dir: &Path, log_level: LogLevel, release: bool) {
     println!("{} `build`", "Running".green().bold());
 
-    let config = config::get_config(current_dir);
-
-    if config.is_err() {
-        let err = config.unwrap_err();
-        print_error(err.to_string().as_str(), 0);
-        process::exit(1);
-    }
-
-    let config = config.unwrap();
+    let config = match config::get_config(current_dir) {
+        Ok(config) => config,
+        Err(err) => {
+            print_error(err.to_string().as_str(), 0);
+            process::exit(1);
+        }
+    };
 
     if log_level == LogLevel::Verbose {
         print_section("Config", 4);
@@ -110,32 +104,56 @@ fn build(current_dir: &Path, log_level: LogLevel) {
         process::exit(1);
     }
 
-    println!("{} {} target(s).", "Found".bold().green(), targets.len());
+    // Every file reached through a `mod` belongs to the program that declares
+    // it, rather than being a program of its own.
+    let trees: Vec<_> = targets
+        .iter()
+        .map(|target_file| (target_file, ModuleTree::load(target_file)))
+        .collect();
+    let module_files: HashSet<&Path> = trees
+        .iter()
+        .filter_map(|(_, tree)| tree.as_ref().ok())
+        .flat_map(|tree| tree.files().skip(1))
+        .collect();
+    let mut programs = Vec::new();
+    for (target_file, tree) in &trees {
+        if module_files.contains(target_file.as_path()) {
+            continue;
+        }
+        match tree {
+            Ok(tree) => programs.push((target_file, tree)),
+            Err(err) => {
+                print_error(err.to_string().as_str(), 0);
+                process::exit(1);
+            }
+        }
+    }
+
+    println!("{} {} target(s).", "Found".bold().green(), programs.len());
 
     let start = Instant::now();
-    for target_file in targets {
-        let source = read_file(&source_dir.join(&target_file));
+    for (target_file, tree) in programs {
+        let file_name = target_file.file_stem();
 
-        if source.is_err() {
-            print_error(source.err().unwrap().to_string().as_str(), 0);
+        if file_name.is_none() {
+            print_error("Failed to get file name", 0);
             process::exit(1);
         }
 
-        let source = source.unwrap();
-
-        let context = Context::create();
-        let mut codegen = cog_core::codegen::CodeGen::new(&context, source.as_str());
-
-        let parser = parser::Parser::new(source);
+        let file_name = file_name.unwrap().to_str();
 
-        if parser.is_err() {
-            print_error(parser.err().unwrap().to_string().as_str(), 0);
+        if file_name.is_none() {
+            print_error("Could not convert file name to string", 0);
             process::exit(1);
         }
 
-        let mut parser = parser.unwrap();
+        let file_name = file_name.unwrap();
+
+        let context = Context::create();
+        let mut codegen = cog_core::codegen::CodeGen::new(&context, file_name);
+        codegen.set_debug_checks(!release);
 
-        let statements = parser.parse();
+        let statements = tree.link();
 
         if statements.is_err() {
             print_error(statements.err().unwrap().to_string().as_str(), 0);
@@ -144,13 +162,16 @@ fn build(current_dir: &Path, log_level: LogLevel) {
 
         let statements = statements.unwrap();
 
-        let result = codegen.compile(&statements);
+        let mut checker = cog_core::check::Checker::new();
+        let result = checker.check(&statements);
 
         if result.is_err() {
             print_error(result.err().unwrap().to_string().as_str(), 0);
             process::exit(1);
         }
 
+        let statements = cog_core::mono::Monomorphizer::new(checker.instances()).run(&statements);
+
         Target::initialize_x86(&InitializationConfig::default());
         let triple = TargetMachine::get_default_triple();
         let target = Target::from_triple(&triple);
@@ -176,6 +197,14 @@ fn build(current_dir: &Path, log_level: LogLevel) {
         }
 
         let target_machine = target_machine.unwrap();
+        codegen.set_target(&target_machine);
+
+        let result = codegen.compile(&statements);
+
+        if result.is_err() {
+            print_error(result.err().unwrap().to_string().as_str(), 0);
+            process::exit(1);
+        }
 
         let mem_buffer =
             target_machine.write_to_memory_buffer(&codegen.lvvm_module, FileType::Object);
@@ -187,22 +216,6 @@ fn build(current_dir: &Path, log_level: LogLevel) {
 
         let mem_buffer = mem_buffer.unwrap();
 
-        let file_name = target_file.file_stem();
-
-        if file_name.is_none() {
-            print_error("Failed to get file name", 0);
-            process::exit(1);
-        }
-
-        let file_name = file_name.unwrap().to_str();
-
-        if file_name.is_none() {
-            print_error("Could not convert file name to string", 0);
-            process::exit(1);
-        }
-
-        let file_name = file_name.unwrap();
-
         let obj_path = target_dir.join(format!("{}.o", file_name));
         let obj_file = File::create(&obj_path)
             .map_err(|e| CliError::IOError(format!("Failed to create object file `{}`", e)));
@@ -0,0 +1,242 @@
+use std::cmp::Ordering;
+
+use cog_parser::parser::core::{
+    expr::{Expr, StructField},
+    nodes::Nodes,
+    ops::{BinaryOp, UnaryOp},
+    types::Types,
+};
+
+/// A value worked out at compile time, such as the initializer of a static.
+/// Integers are kept wrapped to the width of their type.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum ConstValue {
+    Int(i128),
+    Float(f64),
+    Bool(bool),
+    Char(char),
+    Null,
+    String(String),
+    /// The elements of a tuple or the fields of a struct, in declaration
+    /// order.
+    Aggregate(Vec<ConstValue>),
+}
+
+/// Evaluates `expr` as a constant of type `t`, which it has already been
+/// checked against. `fields` looks up the declared fields of a struct. Fails
+/// with the expression that cannot be evaluated: anything but literals,
+/// operators, tuples and struct literals, as well as division by zero and
+/// out of range shifts.
+pub fn eval_const(
+    expr: &Expr,
+    t: &Types,
+    fields: &dyn Fn(&str) -> Option<Vec<StructField>>,
+) -> Result<ConstValue, String> {
+    let not_constant = || format!("{:?}", expr);
+
+    match (expr, t) {
+        (Expr::Literal(Nodes::Integer(v)), _) if t.is_integer() => {
+            Ok(ConstValue::Int(wrap(*v as i128, t)))
+        }
+        (Expr::Literal(Nodes::Float(v)), _) if t.is_float() => Ok(ConstValue::Float(round(*v, t))),
+        (Expr::Literal(Nodes::Boolean(v)), Types::Bool) => Ok(ConstValue::Bool(*v)),
+        (Expr::Literal(Nodes::Char(v)), Types::Char) => Ok(ConstValue::Char(*v)),
+        (Expr::Literal(Nodes::Byte(v)), Types::U8) => Ok(ConstValue::Int(*v as i128)),
+        (Expr::Literal(Nodes::String(v)), _) if *t == Types::String || t.is_c_string() => {
+            Ok(ConstValue::String(v.clone()))
+        }
+        (Expr::Literal(Nodes::Null), Types::Pointer(_)) => Ok(ConstValue::Null),
+        (Expr::Unary { operator, operand }, _) => {
+            match (operator.as_ref(), eval_const(operand, t, fields)?) {
+                (UnaryOp::Minus, ConstValue::Int(v)) => {
+                    Ok(ConstValue::Int(wrap(v.wrapping_neg(), t)))
+                }
+                (UnaryOp::Minus, ConstValue::Float(v)) => Ok(ConstValue::Float(-v)),
+                (UnaryOp::Not, ConstValue::Bool(v)) => Ok(ConstValue::Bool(!v)),
+                (UnaryOp::BitNot, ConstValue::Int(v)) => Ok(ConstValue::Int(wrap(!v, t))),
+                _ => Err(not_constant()),
+            }
+        }
+        (
+            Expr::Binary {
+                left,
+                operator,
+                right,
+            },
+            _,
+        ) => eval_binary(left, operator, right, t, fields).ok_or_else(not_constant),
+        (Expr::Tuple(elements), Types::Tuple(element_types))
+            if elements.len() == element_types.len() =>
+        {
+            elements
+                .iter()
+                .zip(element_types)
+                .map(|(element, element_type)| eval_const(element, element_type, fields))
+                .collect::<Result<_, _>>()
+                .map(ConstValue::Aggregate)
+        }
+        (
+            Expr::StructLiteral {
+                identifier,
+                fields: values,
+            },
+            Types::Struct(name),
+        ) if identifier == name => {
+            let declared = fields(name).ok_or_else(not_constant)?;
+            declared
+                .iter()
+                .map(|field| {
+                    let (_, value) = values
+                        .iter()
+                        .find(|(name, _)| *name == field.identifier)
+                        .ok_or_else(not_constant)?;
+                    eval_const(value, &field.field_type, fields)
+                })
+                .collect::<Result<_, _>>()
+                .map(ConstValue::Aggregate)
+        }
+        _ => Err(not_constant()),
+    }
+}
+
+fn eval_binary(
+    left: &Expr,
+    operator: &BinaryOp,
+    right: &Expr,
+    t: &Types,
+    fields: &dyn Fn(&str) -> Option<Vec<StructField>>,
+) -> Option<ConstValue> {
+    let eval = |expr: &Expr, t: &Types| eval_const(expr, t, fields).ok();
+
+    match operator {
+        BinaryOp::Equal
+        | BinaryOp::NotEqual
+        | BinaryOp::Less
+        | BinaryOp::LessEqual
+        | BinaryOp::Greater
+        | BinaryOp::GreaterEqual => {
+            let operand_type = operand_type(left, right);
+            let ordering = eval(left, &operand_type)?.partial_cmp(&eval(right, &operand_type)?);
+            let result = match operator {
+                BinaryOp::Equal => ordering == Some(Ordering::Equal),
+                BinaryOp::NotEqual => ordering != Some(Ordering::Equal),
+                BinaryOp::Less => ordering == Some(Ordering::Less),
+                BinaryOp::LessEqual => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+                BinaryOp::Greater => ordering == Some(Ordering::Greater),
+                _ => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+            };
+            Some(ConstValue::Bool(result))
+        }
+        BinaryOp::ShiftLeft | BinaryOp::ShiftRight => {
+            let (ConstValue::Int(value), ConstValue::Int(amount)) =
+                (eval(left, t)?, eval(right, &literal_type(right))?)
+            else {
+                return None;
+            };
+            let width = t.integer_bits()?;
+            let amount = u32::try_from(amount).ok().filter(|a| *a < width)?;
+            let shifted = match operator {
+                BinaryOp::ShiftLeft => value << amount,
+                _ => value >> amount,
+            };
+            Some(ConstValue::Int(wrap(shifted, t)))
+        }
+        _ => match (eval(left, t)?, eval(right, t)?) {
+            (ConstValue::Int(l), ConstValue::Int(r)) => {
+                let value = match operator {
+                    BinaryOp::Add => l.wrapping_add(r),
+                    BinaryOp::Subtract => l.wrapping_sub(r),
+                    BinaryOp::Multiply => l.wrapping_mul(r),
+                    BinaryOp::Divide => l.checked_div(r)?,
+                    BinaryOp::Modulo => l.checked_rem(r)?,
+                    BinaryOp::BitAnd => l & r,
+                    BinaryOp::BitOr => l | r,
+                    BinaryOp::BitXor => l ^ r,
+                    _ => return None,
+                };
+                Some(ConstValue::Int(wrap(value, t)))
+            }
+            (ConstValue::Float(l), ConstValue::Float(r)) => {
+                let value = match operator {
+                    BinaryOp::Add => l + r,
+                    BinaryOp::Subtract => l - r,
+                    BinaryOp::Multiply => l * r,
+                    BinaryOp::Divide => l / r,
+                    BinaryOp::Modulo => l % r,
+                    _ => return None,
+                };
+                Some(ConstValue::Float(round(value, t)))
+            }
+            (ConstValue::Bool(l), ConstValue::Bool(r)) => match operator {
+                BinaryOp::And | BinaryOp::BitAnd => Some(ConstValue::Bool(l & r)),
+                BinaryOp::Or | BinaryOp::BitOr => Some(ConstValue::Bool(l | r)),
+                BinaryOp::BitXor => Some(ConstValue::Bool(l ^ r)),
+                _ => None,
+            },
+            _ => None,
+        },
+    }
+}
+
+/// The type the operands of a comparison were checked as: a literal on the
+/// left takes the type of the right side.
+fn operand_type(left: &Expr, right: &Expr) -> Types {
+    match left {
+        Expr::Literal(Nodes::Integer(_) | Nodes::Float(_) | Nodes::Null) => literal_type(right),
+        _ => literal_type(left),
+    }
+}
+
+/// The type the checker gives a constant expression on its own.
+fn literal_type(expr: &Expr) -> Types {
+    match expr {
+        Expr::Literal(Nodes::Integer(_)) => Types::I32,
+        Expr::Literal(Nodes::Float(_)) => Types::F64,
+        Expr::Literal(Nodes::Boolean(_)) => Types::Bool,
+        Expr::Literal(Nodes::Char(_)) => Types::Char,
+        Expr::Literal(Nodes::Byte(_)) => Types::U8,
+        Expr::Literal(Nodes::String(_)) => Types::String,
+        Expr::Literal(Nodes::Null) => Types::Pointer(Box::new(Types::Void)),
+        Expr::Unary { operand, .. } => literal_type(operand),
+        Expr::Binary {
+            left,
+            operator,
+            right,
+        } => match operator.as_ref() {
+            BinaryOp::Equal
+            | BinaryOp::NotEqual
+            | BinaryOp::Less
+            | BinaryOp::LessEqual
+            | BinaryOp::Greater
+            | BinaryOp::GreaterEqual
+            | BinaryOp::And
+            | BinaryOp::Or => Types::Bool,
+            BinaryOp::ShiftLeft | BinaryOp::ShiftRight => literal_type(left),
+            _ => operand_type(left, right),
+        },
+        Expr::Tuple(elements) => Types::Tuple(elements.iter().map(literal_type).collect()),
+        Expr::StructLiteral { identifier, .. } => Types::Struct(identifier.clone()),
+        _ => Types::Void,
+    }
+}
+
+/// Wraps `value` to the range of the integer type `t`, as the same
+/// arithmetic does at run time in release builds.
+fn wrap(value: i128, t: &Types) -> i128 {
+    let Some(bits) = t.integer_bits() else {
+        return value;
+    };
+    let unused = 128 - bits;
+    if t.is_signed() {
+        (value << unused) >> unused
+    } else {
+        ((value as u128) << unused >> unused) as i128
+    }
+}
+
+fn round(value: f64, t: &Types) -> f64 {
+    match t {
+        Types::F32 => value as f32 as f64,
+        _ => value,
+    }
+}
@@ -0,0 +1,346 @@
+use cog_parser::parser::core::{expr::Expr, nodes::Nodes, ops::UnaryOp, types::Types};
+
+use crate::check::{Checker, errors::CheckError};
+
+impl Checker {
+    pub fn check_expr(&mut self, expr: &Expr) -> Result<Option<Types>, CheckError> {
+        match expr {
+            Expr::Literal(node) => Ok(Some(self.check_literal(node)?)),
+            Expr::Binary {
+                left,
+                operator,
+                right,
+            } => Ok(Some(self.check_binary(left, operator, right)?)),
+            Expr::Unary { operator, operand } => Ok(Some(self.check_unary(operator, operand)?)),
+            Expr::Assignment { target, value } => {
+                self.check_assignment(target, value)?;
+                Ok(None)
+            }
+            Expr::CompoundAssignment {
+                target,
+                operator,
+                value,
+            } => {
+                self.check_compound_assignment(target, operator, value)?;
+                Ok(None)
+            }
+            Expr::Declaration {
+                identifier,
+                var_type,
+                value,
+            } => {
+                self.check_declaration(identifier, var_type, value)?;
+                Ok(None)
+            }
+            Expr::FunctionDeclaration {
+                identifier,
+                parameters,
+                body,
+                return_type,
+            } => {
+                self.check_func_decl(identifier, parameters, body, return_type)?;
+                Ok(None)
+            }
+            Expr::ExternFunctionDeclaration { .. } => Ok(None),
+            Expr::Call { callee, arguments } => self.check_call(callee, arguments),
+            Expr::Return { value } => {
+                self.check_return(value)?;
+                Ok(None)
+            }
+            Expr::Block(exprs) => {
+                self.check_block(exprs)?;
+                Ok(None)
+            }
+            Expr::Defer(deferred) => {
+                self.check_defer(deferred)?;
+                Ok(None)
+            }
+            Expr::IfElse {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                self.check_value_as(condition, &Types::Bool)?;
+                self.check_branches(then_branch, else_branch)?;
+                Ok(None)
+            }
+            Expr::AddressOf(operand) => Ok(Some(self.check_address_of(operand)?)),
+            Expr::Dereference(operand) => Ok(Some(self.check_dereference(operand)?)),
+            Expr::Cast { value, target } => Ok(Some(self.check_cast(value, target)?)),
+            Expr::Unsafe(block) => {
+                self.check_unsafe_block(block)?;
+                Ok(None)
+            }
+            Expr::StructDeclaration { fields, .. } => {
+                for field in fields {
+                    self.check_type(&field.field_type)?;
+                }
+                Ok(None)
+            }
+            Expr::StructLiteral { identifier, fields }
+                if self.generic_structs.contains_key(identifier) =>
+            {
+                Ok(Some(self.check_generic_struct_literal(
+                    expr, identifier, fields, None,
+                )?))
+            }
+            Expr::StructLiteral { identifier, fields } => {
+                Ok(Some(self.check_struct_literal(identifier, fields)?))
+            }
+            Expr::FieldAccess { object, field } => {
+                Ok(Some(self.check_field_access(object, field)?))
+            }
+            Expr::ImplBlock { type_name, methods } => {
+                self.check_impl_block(type_name, methods)?;
+                Ok(None)
+            }
+            Expr::MethodCall {
+                receiver,
+                method,
+                arguments,
+            } => self.check_method_call(receiver, method, arguments),
+            Expr::Path(segments) => match self.static_name(expr) {
+                Some(name) => Ok(Some(self.check_static_value(&name)?)),
+                None => Ok(Some(self.check_function_value(&segments.join("::"))?)),
+            },
+            Expr::Documented { item, .. } | Expr::Public { item } => self.check_expr(item),
+            // Generic items are checked once per specialization, when used.
+            Expr::Generic {
+                type_parameters, ..
+            } => {
+                for param in type_parameters {
+                    for bound in &param.bounds {
+                        self.check_visible(bound)?;
+                        self.lookup_trait(bound)?;
+                    }
+                }
+                Ok(None)
+            }
+            Expr::TraitDeclaration { methods, .. } => {
+                self.check_trait_declaration(methods)?;
+                Ok(None)
+            }
+            // Resolved when the modules of the program are linked.
+            Expr::ModuleDeclaration { .. } | Expr::Import { .. } => Ok(None),
+            Expr::StaticDeclaration {
+                identifier,
+                var_type,
+                value,
+                ..
+            } => {
+                self.check_static(identifier, var_type, value)?;
+                Ok(None)
+            }
+            Expr::TypeAlias { aliased, .. } => {
+                self.check_type(aliased)?;
+                Ok(None)
+            }
+            Expr::TraitImpl {
+                trait_name,
+                type_name,
+                methods,
+            } => {
+                self.check_trait_impl(trait_name, type_name, methods)?;
+                Ok(None)
+            }
+            Expr::Tuple(elements) => {
+                let mut element_types = Vec::with_capacity(elements.len());
+                for element in elements {
+                    element_types.push(self.check_value(element)?);
+                }
+                Ok(Some(Types::Tuple(element_types)))
+            }
+            Expr::TupleDeclaration {
+                identifiers,
+                var_type,
+                value,
+            } => {
+                self.check_tuple_declaration(identifiers, var_type, value)?;
+                Ok(None)
+            }
+            Expr::Variant { variant, value } => {
+                Ok(Some(self.check_variant(*variant, value, None)?))
+            }
+            Expr::Try(operand) => Ok(Some(self.check_try(operand)?)),
+            Expr::Alloc(t) => Ok(Some(self.check_alloc(t)?)),
+            Expr::Free(operand) => {
+                self.check_free(operand)?;
+                Ok(None)
+            }
+            Expr::BoxNew(value) => Ok(Some(self.check_box_new(value, None)?)),
+            Expr::Print {
+                pieces, arguments, ..
+            } => {
+                self.check_print(pieces, arguments)?;
+                Ok(None)
+            }
+            Expr::Closure {
+                parameters,
+                return_type,
+                body,
+                ..
+            } => Ok(Some(self.check_closure(
+                expr,
+                parameters,
+                return_type,
+                body,
+                None,
+            )?)),
+        }
+    }
+
+    /// Checks an expression that must produce a value.
+    pub fn check_value(&mut self, expr: &Expr) -> Result<Types, CheckError> {
+        self.check_expr(expr)?
+            .ok_or_else(|| CheckError::ExpectedValue(format!("{:?}", expr)))
+    }
+
+    /// Checks an expression against an expected type, letting numeric literals
+    /// adopt the expected width, string literals decay to `*i8` and `null`
+    /// become the expected pointer type. A negated
+    /// expression is checked against a signed or float type as is. Errors name
+    /// the expected type as written, so an alias is reported by its name. A
+    /// box variable checked this way is moved into the expected slot.
+    pub fn check_value_as(&mut self, expr: &Expr, written: &Types) -> Result<Types, CheckError> {
+        let expected = &self.expand(written)?;
+        match expr {
+            Expr::Literal(Nodes::Integer(value)) if expected.is_integer() => {
+                check_integer_literal(*value, false, expected)?;
+                Ok(expected.clone())
+            }
+            Expr::Unary { operator, operand }
+                if **operator == UnaryOp::Minus
+                    && (expected.is_signed() || expected.is_float()) =>
+            {
+                if let Expr::Literal(Nodes::Integer(value)) = operand.as_ref()
+                    && expected.is_signed()
+                {
+                    check_integer_literal(*value, true, expected)?;
+                    return Ok(expected.clone());
+                }
+                self.check_value_as(operand, expected)
+            }
+            Expr::Unary { operator, operand }
+                if **operator == UnaryOp::BitNot && expected.is_integer() =>
+            {
+                self.check_value_as(operand, written)
+            }
+            Expr::Binary {
+                left,
+                operator,
+                right,
+            } if operator.preserves(expected)
+                && (left.is_literal_tree() || right.is_literal_tree()) =>
+            {
+                self.check_value_as(left, written)?;
+                if operator.is_shift() {
+                    self.check_shift_amount(operator, right)?;
+                } else {
+                    self.check_value_as(right, written)?;
+                }
+                Ok(expected.clone())
+            }
+            Expr::Literal(Nodes::Float(_)) if expected.is_float() => Ok(expected.clone()),
+            Expr::Literal(Nodes::String(_)) if expected.is_c_string() => Ok(expected.clone()),
+            Expr::Literal(Nodes::Null)
+                if matches!(expected, Types::Pointer(_)) && !expected.is_dyn_pointer() =>
+            {
+                Ok(expected.clone())
+            }
+            _ => {
+                let found = match (expr, expected) {
+                    (
+                        Expr::StructLiteral { identifier, fields },
+                        Types::Generic(name, arguments),
+                    ) if identifier == name => self.check_generic_struct_literal(
+                        expr,
+                        identifier,
+                        fields,
+                        Some(arguments),
+                    )?,
+                    (
+                        Expr::Closure {
+                            parameters,
+                            return_type,
+                            body,
+                            ..
+                        },
+                        _,
+                    ) => self.check_closure(expr, parameters, return_type, body, Some(expected))?,
+                    (Expr::Variant { variant, value }, _) => {
+                        self.check_variant(*variant, value, Some(expected))?
+                    }
+                    (Expr::BoxNew(value), _) => self.check_box_new(value, Some(expected))?,
+                    (Expr::Tuple(elements), Types::Tuple(element_types))
+                        if elements.len() == element_types.len() =>
+                    {
+                        for (element, element_type) in elements.iter().zip(element_types) {
+                            self.check_value_as(element, element_type)?;
+                        }
+                        expected.clone()
+                    }
+                    _ => self.check_value(expr)?,
+                };
+                if self.coerces_to_dyn(&found, expected) {
+                    return Ok(expected.clone());
+                }
+                if found.is_integer()
+                    && expected.is_integer()
+                    && found.is_signed() != expected.is_signed()
+                {
+                    return Err(CheckError::MixedSignedness(written.clone(), found));
+                }
+                if &found != expected {
+                    return Err(CheckError::MismatchedTypes(written.clone(), found));
+                }
+                self.move_value(expr);
+                Ok(found)
+            }
+        }
+    }
+
+    pub fn check_literal(&mut self, node: &Nodes) -> Result<Types, CheckError> {
+        match node {
+            Nodes::Integer(value) => {
+                check_integer_literal(*value, false, &Types::I32)?;
+                Ok(Types::I32)
+            }
+            Nodes::Float(_) => Ok(Types::F64),
+            Nodes::String(_) => Ok(Types::String),
+            Nodes::Char(_) => Ok(Types::Char),
+            Nodes::Byte(_) => Ok(Types::U8),
+            Nodes::Boolean(_) => Ok(Types::Bool),
+            Nodes::Null => Ok(Types::Pointer(Box::new(Types::Void))),
+            Nodes::Identifier(name) if self.is_variable(name) => {
+                self.check_live(name)?;
+                self.lookup_variable(name)
+            }
+            Nodes::Identifier(name) if self.statics.contains_key(name) => {
+                self.check_static_value(name)
+            }
+            Nodes::Identifier(name) => self.check_function_value(name),
+        }
+    }
+}
+
+/// Checks that an integer literal, negated if `negative`, is in the range of
+/// the integer type `t` it is given, rather than being truncated to fit.
+pub fn check_integer_literal(value: u64, negative: bool, t: &Types) -> Result<(), CheckError> {
+    let bits = t.integer_bits().unwrap_or(64);
+    let limit = match (t.is_signed(), negative) {
+        (true, true) => 1u128 << (bits - 1),
+        (true, false) => (1u128 << (bits - 1)) - 1,
+        (false, true) => 0,
+        (false, false) => (1u128 << bits) - 1,
+    };
+
+    if value as u128 > limit {
+        let sign = if negative { "-" } else { "" };
+        return Err(CheckError::IntegerOutOfRange(
+            format!("{}{}", sign, value),
+            t.clone(),
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,5 @@
+pub mod consts;
+pub mod expr;
+pub mod operators;
+pub mod release;
+pub mod types;
@@ -0,0 +1,96 @@
+use cog_parser::parser::core::{
+    expr::{Expr, TraitMethod},
+    nodes::Nodes,
+    ops::{BinaryOp, UnaryOp},
+    types::Types,
+};
+
+/// The built-in trait a struct implements to overload an operator, and the
+/// method the operator calls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OperatorTrait {
+    pub name: &'static str,
+    pub method: &'static str,
+}
+
+const ADD: OperatorTrait = OperatorTrait::new("Add", "add");
+const SUB: OperatorTrait = OperatorTrait::new("Sub", "sub");
+const MUL: OperatorTrait = OperatorTrait::new("Mul", "mul");
+const DIV: OperatorTrait = OperatorTrait::new("Div", "div");
+const REM: OperatorTrait = OperatorTrait::new("Rem", "rem");
+const NEG: OperatorTrait = OperatorTrait::new("Neg", "neg");
+const EQ: OperatorTrait = OperatorTrait::new("Eq", "eq");
+const ORD: OperatorTrait = OperatorTrait::new("Ord", "cmp");
+
+impl OperatorTrait {
+    const fn new(name: &'static str, method: &'static str) -> Self {
+        Self { name, method }
+    }
+
+    /// The trait behind a binary operator. `==` and `!=` share `Eq`, and the
+    /// orderings share `Ord`, whose `cmp` returns a negative, zero or positive
+    /// `i32`.
+    pub fn binary(operator: &BinaryOp) -> Option<Self> {
+        match operator {
+            BinaryOp::Add => Some(ADD),
+            BinaryOp::Subtract => Some(SUB),
+            BinaryOp::Multiply => Some(MUL),
+            BinaryOp::Divide => Some(DIV),
+            BinaryOp::Modulo => Some(REM),
+            BinaryOp::Equal | BinaryOp::NotEqual => Some(EQ),
+            BinaryOp::Less | BinaryOp::LessEqual | BinaryOp::Greater | BinaryOp::GreaterEqual => {
+                Some(ORD)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn unary(operator: &UnaryOp) -> Option<Self> {
+        match operator {
+            UnaryOp::Minus => Some(NEG),
+            _ => None,
+        }
+    }
+
+    pub fn is_comparison(&self) -> bool {
+        matches!(*self, EQ | ORD)
+    }
+
+    /// The type the method returns for an implementing type.
+    pub fn return_type(&self, self_type: &Types) -> Types {
+        match *self {
+            EQ => Types::Bool,
+            ORD => Types::I32,
+            _ => self_type.clone(),
+        }
+    }
+
+    /// The declaration of the trait, which takes its operands by value.
+    pub fn declaration(&self) -> Vec<TraitMethod> {
+        let self_type = Types::Param("Self".into());
+        let mut parameters = vec![parameter("self", &self_type)];
+        if *self != NEG {
+            parameters.push(parameter("other", &self_type));
+        }
+
+        vec![TraitMethod {
+            identifier: self.method.to_string(),
+            parameters,
+            return_type: Some(self.return_type(&self_type)),
+            body: None,
+            doc: None,
+        }]
+    }
+
+    pub fn all() -> [Self; 8] {
+        [ADD, SUB, MUL, DIV, REM, NEG, EQ, ORD]
+    }
+}
+
+fn parameter(identifier: &str, var_type: &Types) -> Expr {
+    Expr::Declaration {
+        identifier: identifier.to_string(),
+        var_type: Some(var_type.clone()),
+        value: Box::new(Expr::Literal(Nodes::Identifier("placeholder".to_string()))),
+    }
+}
@@ -0,0 +1,9 @@
+/// Why a variable no longer owns the memory it points to, as far as the
+/// checker can tell within one function.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Release {
+    /// The pointer was passed to `free`.
+    Freed,
+    /// The box was moved into another variable, a call or a return value.
+    Moved,
+}
@@ -0,0 +1,70 @@
+use cog_parser::parser::core::types::Types;
+
+use crate::check::{Checker, errors::CheckError};
+
+impl Checker {
+    /// Checks that every user-defined type named by `t` has been declared.
+    pub fn check_type(&self, t: &Types) -> Result<(), CheckError> {
+        match t {
+            Types::Pointer(pointee) => match pointee.as_ref() {
+                Types::Dyn(trait_name) => {
+                    self.check_visible(trait_name)?;
+                    self.check_object_safe(trait_name)
+                }
+                _ => self.check_type(pointee),
+            },
+            Types::Dyn(_) => Err(CheckError::UnsizedType(t.clone())),
+            Types::Struct(name) => {
+                self.check_visible(name)?;
+                match self.generic_structs.get(name) {
+                    Some((type_parameters, _)) => Err(CheckError::TypeArgumentCountMismatch(
+                        name.clone(),
+                        type_parameters.len(),
+                        0,
+                    )),
+                    None => self.lookup_struct(name).map(|_| ()),
+                }
+            }
+            Types::Generic(name, arguments) => {
+                let (type_parameters, _) = self
+                    .generic_structs
+                    .get(name)
+                    .ok_or_else(|| CheckError::UndefinedType(name.clone()))?;
+                self.check_visible(name)?;
+                if type_parameters.len() != arguments.len() {
+                    return Err(CheckError::TypeArgumentCountMismatch(
+                        name.clone(),
+                        type_parameters.len(),
+                        arguments.len(),
+                    ));
+                }
+                arguments
+                    .iter()
+                    .try_for_each(|argument| self.check_type(argument))?;
+                self.check_bounds(type_parameters, arguments)
+            }
+            Types::Param(name) => Err(CheckError::UndefinedType(name.clone())),
+            Types::Alias(name) => {
+                self.check_visible(name)?;
+                self.check_type(&self.expand(t)?)
+            }
+            Types::Function(parameters, return_type) => {
+                parameters
+                    .iter()
+                    .try_for_each(|parameter| self.check_type(parameter))?;
+                self.check_type(return_type)
+            }
+            Types::Tuple(elements) => elements
+                .iter()
+                .try_for_each(|element| self.check_type(element)),
+            Types::Option(value) => self.check_type(value),
+            Types::Box(value) if **value == Types::Void => Err(CheckError::UnsizedType(t.clone())),
+            Types::Box(value) => self.check_type(value),
+            Types::Result(value, error) => {
+                self.check_type(value)?;
+                self.check_type(error)
+            }
+            _ => Ok(()),
+        }
+    }
+}
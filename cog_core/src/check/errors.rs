@@ -1,7 +1,67 @@
 use std::fmt::{self};
 
+use cog_parser::parser::core::{
+    ops::{BinaryOp, UnaryOp},
+    types::Types,
+};
+
 #[derive(PartialEq, Debug, Clone)]
-pub enum CheckError {}
+pub enum CheckError {
+    UndefinedVariable(String),
+    UndefinedFunction(String),
+    UndefinedType(String),
+    UndefinedField(String, String),
+    UndefinedMethod(String, String),
+    AmbiguousMethod(String, String),
+    DuplicateFunction(String),
+    DuplicateType(String),
+    DuplicateStatic(String),
+    ReservedName(String),
+    MissingField(String, String),
+    InvalidFieldAccess(Types),
+    NotAMethod(String, String),
+    NotCallable(String),
+    ArgumentCountMismatch(String, usize, usize),
+    PlaceholderCountMismatch(usize, usize),
+    TypeArgumentCountMismatch(String, usize, usize),
+    CannotInferType(String, String),
+    InstantiationLimit(String),
+    UndefinedTrait(String),
+    DuplicateImpl(String, String),
+    NotATraitMethod(String, String),
+    MissingTraitMethod(String, String, String),
+    TraitMethodMismatch(String, String),
+    UnsatisfiedBound(Types, String),
+    PrivateItem(String),
+    RecursiveAlias(String),
+    PrivateField(String, String),
+    NotObjectSafe(String, String),
+    UnsizedType(Types),
+    AssignToCapture(String),
+    EscapingClosure(String),
+    TupleArityMismatch(Types, usize),
+    TryMismatch(Types, Types),
+    VariadicFunctionValue(String),
+    MismatchedTypes(Types, Types),
+    MixedSignedness(Types, Types),
+    IntegerOutOfRange(String, Types),
+    InvalidBinaryOperand(BinaryOp, Types),
+    InvalidUnaryOperand(UnaryOp, Types),
+    InvalidDereference(Types),
+    InvalidCast(Types, Types),
+    RequiresUnsafe(String),
+    InvalidFree(Types),
+    DoubleFree(String),
+    UseAfterFree(String),
+    UseAfterMove(String),
+    NotAnLvalue(String),
+    AssignToStatic(String),
+    NotConstant(String),
+    ExpectedValue(String),
+    MissingReturn(String),
+    ReturnOutsideFunction,
+    ReturnInDefer,
+}
 
 impl fmt::Display for CheckError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -0,0 +1,135 @@
+use cog_parser::parser::core::{
+    expr::{Expr, StructField, TraitMethod},
+    signature::FunctionSignature,
+    types::Types,
+};
+
+use crate::check::{Checker, errors::CheckError};
+
+impl Checker {
+    /// Replaces every alias in `t` with the type it names.
+    pub fn expand(&self, t: &Types) -> Result<Types, CheckError> {
+        self.expand_within(t, &mut Vec::new())
+    }
+
+    /// `expanding` holds the aliases being expanded, so an alias that names
+    /// itself, directly or not, is reported instead of expanded forever.
+    fn expand_within(&self, t: &Types, expanding: &mut Vec<String>) -> Result<Types, CheckError> {
+        Ok(match t {
+            Types::Alias(name) => {
+                if expanding.contains(name) {
+                    return Err(CheckError::RecursiveAlias(name.clone()));
+                }
+                let aliased = self
+                    .aliases
+                    .get(name)
+                    .ok_or_else(|| CheckError::UndefinedType(name.clone()))?;
+
+                expanding.push(name.clone());
+                let expanded = self.expand_within(aliased, expanding);
+                expanding.pop();
+                expanded?
+            }
+            Types::Pointer(pointee) => {
+                Types::Pointer(Box::new(self.expand_within(pointee, expanding)?))
+            }
+            Types::Generic(name, arguments) => Types::Generic(
+                name.clone(),
+                arguments
+                    .iter()
+                    .map(|argument| self.expand_within(argument, expanding))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Types::Function(parameters, return_type) => Types::Function(
+                parameters
+                    .iter()
+                    .map(|parameter| self.expand_within(parameter, expanding))
+                    .collect::<Result<_, _>>()?,
+                Box::new(self.expand_within(return_type, expanding)?),
+            ),
+            Types::Tuple(elements) => Types::Tuple(
+                elements
+                    .iter()
+                    .map(|element| self.expand_within(element, expanding))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Types::Option(value) => Types::Option(Box::new(self.expand_within(value, expanding)?)),
+            Types::Box(value) => Types::Box(Box::new(self.expand_within(value, expanding)?)),
+            Types::Result(value, error) => Types::Result(
+                Box::new(self.expand_within(value, expanding)?),
+                Box::new(self.expand_within(error, expanding)?),
+            ),
+            _ => t.clone(),
+        })
+    }
+
+    /// The signature of a function, with its aliases expanded.
+    pub fn signature(
+        &self,
+        identifier: &str,
+        parameters: &[Expr],
+        return_type: &Option<Types>,
+        is_variadic: bool,
+    ) -> Result<FunctionSignature, CheckError> {
+        let signature = FunctionSignature::new(identifier, parameters, return_type, is_variadic);
+
+        Ok(FunctionSignature {
+            parameters: signature
+                .parameters
+                .iter()
+                .map(|param| self.expand(param))
+                .collect::<Result<_, _>>()?,
+            return_type: self.expand(&signature.return_type)?,
+            is_variadic,
+        })
+    }
+
+    pub fn expand_fields(&self, fields: &[StructField]) -> Result<Vec<StructField>, CheckError> {
+        fields
+            .iter()
+            .map(|field| {
+                Ok(StructField {
+                    field_type: self.expand(&field.field_type)?,
+                    ..field.clone()
+                })
+            })
+            .collect()
+    }
+
+    pub fn expand_trait_methods(
+        &self,
+        methods: &[TraitMethod],
+    ) -> Result<Vec<TraitMethod>, CheckError> {
+        methods
+            .iter()
+            .map(|method| {
+                let parameters = method
+                    .parameters
+                    .iter()
+                    .map(|param| match param {
+                        Expr::Declaration {
+                            identifier,
+                            var_type,
+                            value,
+                        } => Ok(Expr::Declaration {
+                            identifier: identifier.clone(),
+                            var_type: var_type.as_ref().map(|t| self.expand(t)).transpose()?,
+                            value: value.clone(),
+                        }),
+                        other => Ok(other.clone()),
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                Ok(TraitMethod {
+                    parameters,
+                    return_type: method
+                        .return_type
+                        .as_ref()
+                        .map(|t| self.expand(t))
+                        .transpose()?,
+                    ..method.clone()
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,160 @@
+use cog_parser::parser::core::{expr::Expr, nodes::Nodes, types::Types};
+
+use crate::{
+    check::{Checker, errors::CheckError},
+    mono::core::instances::{ClosureInstance, site_key},
+};
+
+impl Checker {
+    /// Checks a closure and returns its function type. Parameter and return
+    /// types the closure leaves out are taken from `expected`, and otherwise
+    /// the return type is that of the body. The result, along with the
+    /// enclosing variables the body captures, is recorded for later stages.
+    pub fn check_closure(
+        &mut self,
+        closure: &Expr,
+        parameters: &[Expr],
+        return_type: &Option<Types>,
+        body: &Expr,
+        expected: Option<&Types>,
+    ) -> Result<Types, CheckError> {
+        let (expected_parameters, expected_return) = match expected {
+            Some(Types::Function(params, return_type)) if params.len() == parameters.len() => {
+                (Some(params), Some(return_type.as_ref()))
+            }
+            _ => (None, None),
+        };
+
+        let mut declared = Vec::with_capacity(parameters.len());
+        for (index, param) in parameters.iter().enumerate() {
+            let Expr::Declaration {
+                identifier,
+                var_type,
+                ..
+            } = param
+            else {
+                continue;
+            };
+            let param_type = match (var_type, expected_parameters) {
+                (Some(t), _) => {
+                    self.check_type(t)?;
+                    self.expand(t)?
+                }
+                (None, Some(expected)) if !expected[index].has_params() => expected[index].clone(),
+                (None, _) => {
+                    return Err(CheckError::CannotInferType(
+                        "closure".into(),
+                        identifier.clone(),
+                    ));
+                }
+            };
+            declared.push((identifier, param_type));
+        }
+
+        let return_type = match (return_type, expected_return) {
+            (Some(t), _) => {
+                self.check_type(t)?;
+                Some(self.expand(t)?)
+            }
+            (None, Some(t)) if !t.has_params() => Some(t.clone()),
+            // A block body only produces a value through `return`.
+            (None, _) if matches!(body, Expr::Block(_)) => Some(Types::Void),
+            (None, _) => None,
+        };
+
+        // `return` in the body leaves the closure, not the enclosing function.
+        let outer_return_type = std::mem::replace(&mut self.return_type, return_type.clone());
+        let outer_released = std::mem::take(&mut self.released);
+        let outer_local_closures = std::mem::take(&mut self.local_closures);
+        let outer_deferred = std::mem::take(&mut self.deferred);
+        let outer_deferring = std::mem::replace(&mut self.deferring, false);
+        self.captures.push((self.scopes.len(), Vec::new()));
+        self.enter_scope();
+
+        for (identifier, param_type) in &declared {
+            self.declare_variable(identifier, param_type.clone());
+        }
+        let result = match (&return_type, body) {
+            (Some(Types::Void), _) => self.check_expr(body).map(|_| return_type.clone()),
+            (Some(_), Expr::Block(_)) => self.check_expr(body).and_then(|_| {
+                if body.always_returns() {
+                    Ok(return_type.clone())
+                } else {
+                    Err(CheckError::MissingReturn("closure".into()))
+                }
+            }),
+            (Some(t), _) => self.check_value_as(body, t).map(Some),
+            (None, _) => self.check_expr(body),
+        };
+
+        self.exit_scope();
+        let (_, captures) = self.captures.pop().unwrap_or_default();
+        self.return_type = outer_return_type;
+        self.released = outer_released;
+        self.local_closures = outer_local_closures;
+        self.deferred = outer_deferred;
+        self.deferring = outer_deferring;
+
+        let parameters: Vec<Types> = declared.into_iter().map(|(_, t)| t).collect();
+        let return_type = result?.unwrap_or(Types::Void);
+        self.instances.closures.insert(
+            site_key(closure),
+            ClosureInstance {
+                parameters: parameters.clone(),
+                return_type: return_type.clone(),
+                captures,
+            },
+        );
+
+        Ok(Types::Function(parameters, Box::new(return_type)))
+    }
+
+    /// Whether `value` holds a closure that captures variables of the
+    /// function being checked. Its environment lives in that function's
+    /// frame, so the value must not outlive it.
+    pub fn holds_local_closure(&self, value: &Expr) -> bool {
+        match value {
+            Expr::Closure { .. } => self
+                .instances
+                .closures
+                .get(&site_key(value))
+                .is_some_and(|closure| !closure.captures.is_empty()),
+            Expr::Literal(Nodes::Identifier(name)) => self.local_closures.contains(name),
+            Expr::Tuple(elements) => elements.iter().any(|e| self.holds_local_closure(e)),
+            Expr::StructLiteral { fields, .. } => fields
+                .iter()
+                .any(|(_, value)| self.holds_local_closure(value)),
+            Expr::Variant {
+                value: Some(value), ..
+            } => self.holds_local_closure(value),
+            Expr::BoxNew(value) => self.holds_local_closure(value),
+            _ => false,
+        }
+    }
+
+    /// Rejects returning a value that holds a capturing closure, or storing
+    /// one anywhere but a variable of the function that created it.
+    pub fn check_not_escaping(&self, value: &Expr) -> Result<(), CheckError> {
+        if !self.holds_local_closure(value) {
+            return Ok(());
+        }
+
+        Err(CheckError::EscapingClosure(match value {
+            Expr::Literal(Nodes::Identifier(name)) => name.clone(),
+            _ => "closure".into(),
+        }))
+    }
+
+    /// Records whether the variables just given `value` now hold a capturing
+    /// closure.
+    pub fn track_local_closure(&mut self, names: &[String], value: &Expr) {
+        let holds = self.holds_local_closure(value);
+        for name in names {
+            if holds {
+                self.local_closures.insert(name.clone());
+            } else {
+                self.local_closures.remove(name);
+            }
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use cog_parser::parser::core::{
+    expr::Expr, nodes::Nodes, signature::FunctionSignature, types::Types,
+};
+
+use crate::check::{Checker, errors::CheckError};
+
+impl Checker {
+    pub fn check_func_decl(
+        &mut self,
+        identifier: &str,
+        parameters: &[Expr],
+        body: &Expr,
+        return_type: &Option<Types>,
+    ) -> Result<(), CheckError> {
+        let signature = FunctionSignature::new(identifier, parameters, return_type, false);
+        // `main` may fall off its end, which returns 0 as in C.
+        let must_return =
+            self.expand(&signature.return_type)? != Types::Void && identifier != "main";
+        let outer_return_type = self.return_type.replace(signature.return_type);
+        let outer_released = std::mem::take(&mut self.released);
+        let outer_local_closures = std::mem::take(&mut self.local_closures);
+        let outer_deferred = std::mem::take(&mut self.deferred);
+        // A generic function may be specialized from inside a `defer`.
+        let outer_deferring = std::mem::replace(&mut self.deferring, false);
+        let outer_unsafe = std::mem::replace(&mut self.in_unsafe, false);
+        self.enter_scope();
+
+        for param in parameters {
+            if let Expr::Declaration {
+                identifier,
+                var_type: Some(var_type),
+                ..
+            } = param
+            {
+                self.check_type(var_type)?;
+                let var_type = self.expand(var_type)?;
+                self.declare_variable(identifier, var_type);
+            }
+        }
+
+        let result = self.check_expr(body);
+
+        self.exit_scope();
+        self.return_type = outer_return_type;
+        self.released = outer_released;
+        self.local_closures = outer_local_closures;
+        self.deferred = outer_deferred;
+        self.deferring = outer_deferring;
+        self.in_unsafe = outer_unsafe;
+        result?;
+
+        if must_return && !body.always_returns() {
+            return Err(CheckError::MissingReturn(identifier.to_string()));
+        }
+
+        Ok(())
+    }
+
+    pub fn check_call(
+        &mut self,
+        callee: &Expr,
+        arguments: &[Expr],
+    ) -> Result<Option<Types>, CheckError> {
+        let name = match callee {
+            Expr::Literal(Nodes::Identifier(name)) if !self.is_variable(name) => name.clone(),
+            Expr::Path(segments) => segments.join("::"),
+            _ => return self.check_value_call(callee, arguments),
+        };
+        self.check_visible(&name)?;
+
+        if self.generic_functions.contains_key(&name) {
+            return self.check_generic_call(callee, &name, arguments);
+        }
+
+        // `Type::method` may name a method from one of the type's trait impls.
+        let name = match name.rsplit_once("::") {
+            Some((type_name, method))
+                if !self.functions.contains_key(&name) && self.structs.contains_key(type_name) =>
+            {
+                self.resolve_method(type_name, method)?
+            }
+            _ => name,
+        };
+
+        let signature = self.lookup_function(&name)?;
+        self.check_arguments(&name, &signature, &signature.parameters, arguments)
+    }
+
+    /// Checks a call through a function value, such as a closure held in a
+    /// variable.
+    fn check_value_call(
+        &mut self,
+        callee: &Expr,
+        arguments: &[Expr],
+    ) -> Result<Option<Types>, CheckError> {
+        let Types::Function(parameters, return_type) = self.check_value(callee)? else {
+            return Err(CheckError::NotCallable(format!("{:?}", callee)));
+        };
+        let name = match callee {
+            Expr::Literal(Nodes::Identifier(name)) => name.clone(),
+            _ => format!("{:?}", callee),
+        };
+
+        let signature = FunctionSignature::from_function_type(&parameters, &return_type);
+        self.check_arguments(&name, &signature, &parameters, arguments)
+    }
+
+    /// Checks a named function used as a value rather than called.
+    pub fn check_function_value(&self, name: &str) -> Result<Types, CheckError> {
+        self.check_visible(name)?;
+        if let Some((type_parameters, _)) = self.generic_functions.get(name) {
+            return Err(CheckError::CannotInferType(
+                name.to_string(),
+                type_parameters[0].identifier.clone(),
+            ));
+        }
+
+        match self.functions.get(name) {
+            Some(signature) if signature.is_variadic => {
+                Err(CheckError::VariadicFunctionValue(name.to_string()))
+            }
+            Some(signature) => self.expand(&signature.function_type()),
+            None if name.contains("::") => Err(CheckError::UndefinedFunction(name.to_string())),
+            None => Err(CheckError::UndefinedVariable(name.to_string())),
+        }
+    }
+
+    /// Checks call arguments against `parameters` and returns the call's result type.
+    pub fn check_arguments(
+        &mut self,
+        name: &str,
+        signature: &FunctionSignature,
+        parameters: &[Types],
+        arguments: &[Expr],
+    ) -> Result<Option<Types>, CheckError> {
+        let count_matches = if signature.is_variadic {
+            arguments.len() >= parameters.len()
+        } else {
+            arguments.len() == parameters.len()
+        };
+
+        if !count_matches {
+            return Err(CheckError::ArgumentCountMismatch(
+                name.to_string(),
+                parameters.len(),
+                arguments.len(),
+            ));
+        }
+
+        for (index, argument) in arguments.iter().enumerate() {
+            match parameters.get(index) {
+                Some(param_type) => self.check_value_as(argument, param_type)?,
+                None => self.check_value(argument)?,
+            };
+        }
+
+        match self.expand(&signature.return_type)? {
+            Types::Void => Ok(None),
+            return_type => Ok(Some(return_type)),
+        }
+    }
+
+    /// A bare `return;` is only allowed in a function returning void.
+    pub fn check_return(&mut self, value: &Option<Box<Expr>>) -> Result<(), CheckError> {
+        if self.deferring {
+            return Err(CheckError::ReturnInDefer);
+        }
+        let return_type = self
+            .return_type
+            .clone()
+            .ok_or(CheckError::ReturnOutsideFunction)?;
+
+        match value {
+            Some(value) => {
+                self.check_value_as(value, &return_type)?;
+                self.check_not_escaping(value)?;
+            }
+            None if self.expand(&return_type)? == Types::Void => {}
+            None => return Err(CheckError::MismatchedTypes(return_type, Types::Void)),
+        }
+
+        Ok(())
+    }
+
+    /// What a deferred expression frees or moves out is only released when
+    /// it runs, as its scope is left.
+    pub fn check_defer(&mut self, deferred: &Expr) -> Result<(), CheckError> {
+        let before = self.released.clone();
+        let outer_deferring = std::mem::replace(&mut self.deferring, true);
+        let result = self.check_expr(deferred);
+        self.deferring = outer_deferring;
+        let after = std::mem::replace(&mut self.released, before);
+        result?;
+
+        if let Some(pending) = self.deferred.last_mut() {
+            pending.extend(
+                after
+                    .into_iter()
+                    .filter(|(name, release)| self.released.get(name) != Some(release)),
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn check_block(&mut self, exprs: &[Expr]) -> Result<(), CheckError> {
+        self.enter_scope();
+
+        let result = exprs
+            .iter()
+            .try_for_each(|expr| self.check_expr(expr).map(|_| ()));
+
+        self.exit_scope();
+
+        result
+    }
+}
@@ -0,0 +1,332 @@
+use std::{collections::HashMap, rc::Rc};
+
+use cog_parser::parser::core::{
+    expr::{Expr, StructField, TypeParameter},
+    nodes::Nodes,
+    types::Types,
+};
+
+use crate::{
+    check::{Checker, errors::CheckError, impls::visibility::module_of},
+    codegen::core::mangle::mangle_instance,
+    mono::core::{
+        instances::site_key,
+        rebuild::Rebuild,
+        subst::{Substitution, substitute},
+    },
+};
+
+/// How deeply specializations may trigger further specializations before the
+/// checker assumes they never end, as in `fn f<T>(x: T) { f(&x); }`.
+const MAX_INSTANTIATION_DEPTH: usize = 64;
+
+impl Checker {
+    /// Checks a call to a generic function. The type arguments are inferred
+    /// from the arguments, and the function body is checked for them the first
+    /// time they are used.
+    pub fn check_generic_call(
+        &mut self,
+        callee: &Expr,
+        name: &str,
+        arguments: &[Expr],
+    ) -> Result<Option<Types>, CheckError> {
+        let (type_parameters, item) = self.generic_functions[name].clone();
+        let Expr::FunctionDeclaration {
+            parameters,
+            return_type,
+            ..
+        } = item.as_ref()
+        else {
+            return Err(CheckError::NotCallable(name.to_string()));
+        };
+        let signature = self.signature(name, parameters, return_type, false)?;
+
+        if arguments.len() != signature.parameters.len() {
+            return Err(CheckError::ArgumentCountMismatch(
+                name.to_string(),
+                signature.parameters.len(),
+                arguments.len(),
+            ));
+        }
+
+        let mut bindings = HashMap::new();
+        let arguments: Vec<&Expr> = arguments.iter().collect();
+        self.infer_type_arguments(&signature.parameters, &arguments, &mut bindings)?;
+        let type_arguments = bound_arguments(name, &type_parameters, &bindings)?;
+
+        self.instantiate_function(name, &type_parameters, &item, &type_arguments)?;
+        self.instances
+            .sites
+            .insert(site_key(callee), type_arguments);
+
+        match substitute(&signature.return_type, &bindings) {
+            Types::Void => Ok(None),
+            return_type => Ok(Some(return_type)),
+        }
+    }
+
+    /// Checks a literal of a generic struct. Type arguments come from
+    /// `expected` when the literal's type is already known, and are otherwise
+    /// inferred from the field values.
+    pub fn check_generic_struct_literal(
+        &mut self,
+        literal: &Expr,
+        identifier: &str,
+        fields: &[(String, Expr)],
+        expected: Option<&[Types]>,
+    ) -> Result<Types, CheckError> {
+        let (type_parameters, declared) = self.generic_structs[identifier].clone();
+
+        let mut bindings: HashMap<String, Types> = match expected {
+            Some(arguments) => type_parameters
+                .iter()
+                .map(|param| param.identifier.clone())
+                .zip(arguments.iter().cloned())
+                .collect(),
+            None => HashMap::new(),
+        };
+
+        let mut parameters = Vec::with_capacity(fields.len());
+        for (name, _) in fields {
+            parameters.push(self.declared_field_type(identifier, &declared, name)?);
+        }
+        self.check_fields_complete(identifier, &declared, fields)?;
+
+        let values: Vec<&Expr> = fields.iter().map(|(_, value)| value).collect();
+        self.infer_type_arguments(&parameters, &values, &mut bindings)?;
+        let type_arguments = bound_arguments(identifier, &type_parameters, &bindings)?;
+
+        let struct_type = Types::Generic(identifier.to_string(), type_arguments.clone());
+        self.check_type(&struct_type)?;
+        self.instances
+            .sites
+            .insert(site_key(literal), type_arguments);
+
+        Ok(struct_type)
+    }
+
+    /// The fields of `name<arguments>`, with the type arguments filled in.
+    pub fn generic_struct_fields(
+        &self,
+        name: &str,
+        arguments: &[Types],
+    ) -> Result<Vec<StructField>, CheckError> {
+        let (type_parameters, fields) = self
+            .generic_structs
+            .get(name)
+            .ok_or_else(|| CheckError::UndefinedType(name.to_string()))?;
+        let bindings: HashMap<String, Types> = type_parameters
+            .iter()
+            .map(|param| param.identifier.clone())
+            .zip(arguments.iter().cloned())
+            .collect();
+
+        Ok(fields
+            .iter()
+            .map(|field| StructField {
+                identifier: field.identifier.clone(),
+                field_type: substitute(&field.field_type, &bindings),
+                doc: field.doc.clone(),
+                public: field.public,
+            })
+            .collect())
+    }
+
+    /// Checks `arguments` against `parameters`, binding the type parameters
+    /// they mention. Numeric literals are checked after the other arguments so
+    /// they take the type those settle on, as in `max(x, 1)` with an `i64` `x`,
+    /// and closures come last so their parameter types are known.
+    fn infer_type_arguments(
+        &mut self,
+        parameters: &[Types],
+        arguments: &[&Expr],
+        bindings: &mut HashMap<String, Types>,
+    ) -> Result<(), CheckError> {
+        let is_numeric_literal = |expr: &Expr| {
+            matches!(
+                expr,
+                Expr::Literal(Nodes::Integer(_)) | Expr::Literal(Nodes::Float(_))
+            )
+        };
+        let (literals, others): (Vec<usize>, Vec<usize>) =
+            (0..arguments.len()).partition(|&index| is_numeric_literal(arguments[index]));
+        let (closures, others): (Vec<usize>, Vec<usize>) = others
+            .into_iter()
+            .partition(|&index| matches!(arguments[index], Expr::Closure { .. }));
+
+        for index in others.into_iter().chain(literals).chain(closures) {
+            let expected = substitute(&parameters[index], bindings);
+            match arguments[index] {
+                closure @ Expr::Closure {
+                    parameters,
+                    return_type,
+                    body,
+                    ..
+                } if expected.has_params() => {
+                    let found = self.check_closure(
+                        closure,
+                        parameters,
+                        return_type,
+                        body,
+                        Some(&expected),
+                    )?;
+                    unify(&expected, &found, bindings)?;
+                }
+                argument if expected.has_params() => {
+                    let found = self.check_value(argument)?;
+                    unify(&expected, &found, bindings)?;
+                }
+                argument => {
+                    self.check_value_as(argument, &expected)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the body of `name<type_arguments>` unless it has been checked
+    /// already, and records it as an instance.
+    fn instantiate_function(
+        &mut self,
+        name: &str,
+        type_parameters: &[TypeParameter],
+        item: &Expr,
+        type_arguments: &[Types],
+    ) -> Result<(), CheckError> {
+        for argument in type_arguments {
+            self.check_type(argument)?;
+        }
+        self.check_bounds(type_parameters, type_arguments)?;
+
+        let symbol = mangle_instance(name, type_arguments);
+        if self.instances.functions.contains_key(&symbol) {
+            return Ok(());
+        }
+        if self.instantiation_depth >= MAX_INSTANTIATION_DEPTH {
+            return Err(CheckError::InstantiationLimit(symbol));
+        }
+
+        let bindings: HashMap<String, Types> = type_parameters
+            .iter()
+            .map(|param| param.identifier.clone())
+            .zip(type_arguments.iter().cloned())
+            .collect();
+        let instance = match Substitution::new(&bindings).rebuild_expr(item) {
+            Expr::FunctionDeclaration {
+                parameters,
+                body,
+                return_type,
+                ..
+            } => Expr::FunctionDeclaration {
+                identifier: symbol.clone(),
+                parameters,
+                body,
+                return_type,
+            },
+            other => other,
+        };
+
+        // Recorded before the body is checked so recursive calls find it.
+        let instance = Rc::new(instance);
+        self.instances
+            .functions
+            .insert(symbol, Rc::clone(&instance));
+
+        let Expr::FunctionDeclaration {
+            identifier,
+            parameters,
+            body,
+            return_type,
+        } = instance.as_ref()
+        else {
+            return Ok(());
+        };
+
+        // The body sees globals but not the locals of the call site, and is
+        // not part of any closure the call is in. It has the privacy of the
+        // module that declares the function.
+        let locals = self.scopes.split_off(1);
+        let captures = std::mem::take(&mut self.captures);
+        let module = std::mem::replace(&mut self.module, module_of(name).to_string());
+        self.instantiation_depth += 1;
+        let result = self.check_func_decl(identifier, parameters, body, return_type);
+        self.instantiation_depth -= 1;
+        self.module = module;
+        self.captures = captures;
+        self.scopes.extend(locals);
+
+        result
+    }
+}
+
+/// Binds the type parameters in `expected` so that it matches `found`.
+/// `expected` must already have its bound parameters substituted.
+fn unify(
+    expected: &Types,
+    found: &Types,
+    bindings: &mut HashMap<String, Types>,
+) -> Result<(), CheckError> {
+    match (expected, found) {
+        (Types::Param(name), _) => {
+            bindings.insert(name.clone(), found.clone());
+            Ok(())
+        }
+        (Types::Pointer(expected), Types::Pointer(found)) => unify(expected, found, bindings),
+        (
+            Types::Function(expected_params, expected_return),
+            Types::Function(found_params, found_return),
+        ) if expected_params.len() == found_params.len() => {
+            for (expected, found) in expected_params.iter().zip(found_params) {
+                unify(&substitute(expected, bindings), found, bindings)?;
+            }
+            unify(
+                &substitute(expected_return, bindings),
+                found_return,
+                bindings,
+            )
+        }
+        (Types::Option(expected), Types::Option(found))
+        | (Types::Box(expected), Types::Box(found)) => unify(expected, found, bindings),
+        (
+            Types::Result(expected_value, expected_error),
+            Types::Result(found_value, found_error),
+        ) => {
+            unify(expected_value, found_value, bindings)?;
+            unify(&substitute(expected_error, bindings), found_error, bindings)
+        }
+        (Types::Tuple(expected_elements), Types::Tuple(found_elements))
+            if expected_elements.len() == found_elements.len() =>
+        {
+            for (expected, found) in expected_elements.iter().zip(found_elements) {
+                unify(&substitute(expected, bindings), found, bindings)?;
+            }
+            Ok(())
+        }
+        (Types::Generic(expected_name, expected_args), Types::Generic(found_name, found_args))
+            if expected_name == found_name && expected_args.len() == found_args.len() =>
+        {
+            for (expected, found) in expected_args.iter().zip(found_args) {
+                unify(&substitute(expected, bindings), found, bindings)?;
+            }
+            Ok(())
+        }
+        _ if expected == found => Ok(()),
+        _ => Err(CheckError::MismatchedTypes(expected.clone(), found.clone())),
+    }
+}
+
+fn bound_arguments(
+    name: &str,
+    type_parameters: &[TypeParameter],
+    bindings: &HashMap<String, Types>,
+) -> Result<Vec<Types>, CheckError> {
+    type_parameters
+        .iter()
+        .map(|param| {
+            bindings.get(&param.identifier).cloned().ok_or_else(|| {
+                CheckError::CannotInferType(name.to_string(), param.identifier.clone())
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,111 @@
+use cog_parser::parser::core::{expr::Expr, nodes::Nodes, types::Types};
+
+use crate::check::{Checker, core::release::Release, errors::CheckError};
+
+impl Checker {
+    /// `alloc<T>()` gives a pointer to uninitialized memory for one `T`.
+    pub fn check_alloc(&self, t: &Types) -> Result<Types, CheckError> {
+        if *t == Types::Void {
+            return Err(CheckError::UnsizedType(t.clone()));
+        }
+        self.check_type(t)?;
+
+        Ok(Types::Pointer(Box::new(self.expand(t)?)))
+    }
+
+    /// `free(ptr)` takes a pointer to heap memory. Freeing a variable makes it
+    /// unusable until it is assigned again. A variable a `defer` will free
+    /// may not be freed again.
+    pub fn check_free(&mut self, operand: &Expr) -> Result<(), CheckError> {
+        let variable = variable_name(operand);
+        if let Some(name) = variable
+            && (self.released.get(name) == Some(&Release::Freed)
+                || self
+                    .deferred
+                    .iter()
+                    .any(|pending| pending.get(name) == Some(&Release::Freed)))
+        {
+            return Err(CheckError::DoubleFree(name.to_string()));
+        }
+
+        match self.check_value(operand)? {
+            Types::Pointer(pointee) if !matches!(*pointee, Types::Dyn(_)) => {}
+            other => return Err(CheckError::InvalidFree(other)),
+        }
+
+        if let Some(name) = variable {
+            self.released.insert(name.to_string(), Release::Freed);
+        }
+
+        Ok(())
+    }
+
+    /// `Box::new(value)`, with `value` checked against the boxed type when
+    /// it is known.
+    pub fn check_box_new(
+        &mut self,
+        value: &Expr,
+        expected: Option<&Types>,
+    ) -> Result<Types, CheckError> {
+        let value_type = match expected {
+            Some(Types::Box(expected)) => self.check_value_as(value, expected)?,
+            _ => self.check_value(value)?,
+        };
+
+        Ok(Types::Box(Box::new(value_type)))
+    }
+
+    /// Records a box variable used by value as moved out, leaving the new
+    /// owner to free it.
+    pub fn move_value(&mut self, expr: &Expr) {
+        if let Some(name) = variable_name(expr)
+            && matches!(self.variable_type(name), Some(Types::Box(_)))
+        {
+            self.released.insert(name.to_string(), Release::Moved);
+        }
+    }
+
+    /// Fails if `name` was freed or moved out earlier in the function.
+    pub fn check_live(&self, name: &str) -> Result<(), CheckError> {
+        match self.released.get(name) {
+            Some(Release::Freed) => Err(CheckError::UseAfterFree(name.to_string())),
+            Some(Release::Moved) => Err(CheckError::UseAfterMove(name.to_string())),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks both branches of an `if` from the same starting point. After
+    /// the `if`, a variable counts as released when a branch that can fall
+    /// through released it.
+    pub fn check_branches(
+        &mut self,
+        then_branch: &Expr,
+        else_branch: &Option<Box<Expr>>,
+    ) -> Result<(), CheckError> {
+        let before = self.released.clone();
+        self.check_expr(then_branch)?;
+        let after_then = std::mem::replace(&mut self.released, before);
+        if let Some(else_branch) = else_branch {
+            self.check_expr(else_branch)?;
+        }
+
+        let else_returns = else_branch
+            .as_ref()
+            .is_some_and(|else_branch| else_branch.always_returns());
+        if else_returns {
+            self.released = after_then;
+        } else if !then_branch.always_returns() {
+            self.released.extend(after_then);
+        }
+
+        Ok(())
+    }
+}
+
+/// The variable `expr` names, if it is a bare identifier.
+fn variable_name(expr: &Expr) -> Option<&str> {
+    match expr {
+        Expr::Literal(Nodes::Identifier(name)) => Some(name),
+        _ => None,
+    }
+}
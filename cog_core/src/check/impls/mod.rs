@@ -0,0 +1,15 @@
+pub mod aliases;
+pub mod closures;
+pub mod funcs;
+pub mod generics;
+pub mod heap;
+pub mod operators;
+pub mod ops;
+pub mod pointer_ops;
+pub mod print;
+pub mod statics;
+pub mod structs;
+pub mod traits;
+pub mod variants;
+pub mod vars;
+pub mod visibility;
@@ -0,0 +1,42 @@
+use cog_parser::parser::core::{
+    ops::{BinaryOp, UnaryOp},
+    types::Types,
+};
+
+use crate::check::{Checker, core::operators::OperatorTrait, errors::CheckError};
+
+impl Checker {
+    /// The type of a binary operator applied to structs, which is allowed when
+    /// the struct implements the operator's trait.
+    pub fn check_binary_overload(
+        &self,
+        operator: &BinaryOp,
+        operand_type: Types,
+    ) -> Result<Types, CheckError> {
+        match OperatorTrait::binary(operator) {
+            Some(op) if self.implements(&operand_type, op.name) => Ok(if op.is_comparison() {
+                Types::Bool
+            } else {
+                operand_type
+            }),
+            _ => Err(CheckError::InvalidBinaryOperand(
+                operator.clone(),
+                operand_type,
+            )),
+        }
+    }
+
+    pub fn check_unary_overload(
+        &self,
+        operator: &UnaryOp,
+        operand_type: Types,
+    ) -> Result<Types, CheckError> {
+        match OperatorTrait::unary(operator) {
+            Some(op) if self.implements(&operand_type, op.name) => Ok(operand_type),
+            _ => Err(CheckError::InvalidUnaryOperand(
+                operator.clone(),
+                operand_type,
+            )),
+        }
+    }
+}
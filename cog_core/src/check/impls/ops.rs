@@ -0,0 +1,150 @@
+use cog_parser::parser::core::{
+    expr::Expr,
+    nodes::Nodes,
+    ops::{BinaryOp, UnaryOp},
+    types::Types,
+};
+
+use crate::check::{Checker, core::expr::check_integer_literal, errors::CheckError};
+
+impl Checker {
+    /// Both operands of a binary expression take the type of the one with a
+    /// type of its own, so literals on the left take the type of the right
+    /// side.
+    pub fn check_binary(
+        &mut self,
+        left: &Expr,
+        operator: &BinaryOp,
+        right: &Expr,
+    ) -> Result<Types, CheckError> {
+        let operand_type = if operator.is_shift() {
+            let left_type = self.check_value(left)?;
+            self.check_shift_amount(operator, right)?;
+            left_type
+        } else if left.is_literal_tree() {
+            let right_type = self.check_value(right)?;
+            self.check_value_as(left, &right_type)?
+        } else {
+            let left_type = self.check_value(left)?;
+            if operator.is_offset() && matches!(left_type, Types::Pointer(_)) {
+                return self.check_pointer_arithmetic(operator, left_type, right);
+            }
+            self.check_value_as(right, &left_type)?
+        };
+
+        self.check_binary_operator(operator, operand_type)
+    }
+
+    /// Shift amounts may be of any integer type, independent of the shifted value.
+    pub fn check_shift_amount(
+        &mut self,
+        operator: &BinaryOp,
+        amount: &Expr,
+    ) -> Result<(), CheckError> {
+        if matches!(amount, Expr::Literal(Nodes::Integer(_))) {
+            return Ok(());
+        }
+
+        let amount_type = self.check_value(amount)?;
+        if !amount_type.is_integer() {
+            return Err(CheckError::InvalidBinaryOperand(
+                operator.clone(),
+                amount_type,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that `operator` applies to operands of `operand_type` and returns
+    /// the type of the result.
+    pub fn check_binary_operator(
+        &self,
+        operator: &BinaryOp,
+        operand_type: Types,
+    ) -> Result<Types, CheckError> {
+        if self.impl_target(&operand_type).is_some() {
+            return self.check_binary_overload(operator, operand_type);
+        }
+
+        let valid = match operator {
+            BinaryOp::Add
+            | BinaryOp::Subtract
+            | BinaryOp::Multiply
+            | BinaryOp::Divide
+            | BinaryOp::Modulo => operand_type.is_numeric(),
+            BinaryOp::Less | BinaryOp::LessEqual | BinaryOp::Greater | BinaryOp::GreaterEqual => {
+                operand_type.is_numeric()
+                    || operand_type == Types::Char
+                    || (matches!(operand_type, Types::Pointer(_)) && !operand_type.is_dyn_pointer())
+            }
+            BinaryOp::Equal | BinaryOp::NotEqual => {
+                operand_type.is_numeric()
+                    || (matches!(operand_type, Types::Bool | Types::Char | Types::Pointer(_))
+                        && !operand_type.is_dyn_pointer())
+            }
+            BinaryOp::And | BinaryOp::Or => operand_type == Types::Bool,
+            BinaryOp::BitAnd | BinaryOp::BitOr | BinaryOp::BitXor => {
+                operand_type.is_integer() || operand_type == Types::Bool
+            }
+            BinaryOp::ShiftLeft | BinaryOp::ShiftRight => operand_type.is_integer(),
+        };
+
+        if !valid {
+            return Err(CheckError::InvalidBinaryOperand(
+                operator.clone(),
+                operand_type,
+            ));
+        }
+
+        match operator {
+            BinaryOp::Add
+            | BinaryOp::Subtract
+            | BinaryOp::Multiply
+            | BinaryOp::Divide
+            | BinaryOp::Modulo
+            | BinaryOp::BitAnd
+            | BinaryOp::BitOr
+            | BinaryOp::BitXor
+            | BinaryOp::ShiftLeft
+            | BinaryOp::ShiftRight => Ok(operand_type),
+            _ => Ok(Types::Bool),
+        }
+    }
+
+    pub fn check_unary(&mut self, operator: &UnaryOp, operand: &Expr) -> Result<Types, CheckError> {
+        // A negated literal is in range down to the minimum of `i32`.
+        if let (UnaryOp::Minus, Expr::Literal(Nodes::Integer(value))) = (operator, operand) {
+            check_integer_literal(*value, true, &Types::I32)?;
+            return Ok(Types::I32);
+        }
+
+        let operand_type = match operator {
+            UnaryOp::PreIncrement
+            | UnaryOp::PreDecrement
+            | UnaryOp::PostIncrement
+            | UnaryOp::PostDecrement => self.check_assignable(operand)?,
+            _ => self.check_value(operand)?,
+        };
+
+        if self.impl_target(&operand_type).is_some() {
+            return self.check_unary_overload(operator, operand_type);
+        }
+
+        let valid = match operator {
+            UnaryOp::Not => operand_type == Types::Bool,
+            UnaryOp::BitNot => operand_type.is_integer(),
+            UnaryOp::Minus => operand_type.is_signed() || operand_type.is_float(),
+            _ => operand_type.is_numeric(),
+        };
+
+        if !valid {
+            return Err(CheckError::InvalidUnaryOperand(
+                operator.clone(),
+                operand_type,
+            ));
+        }
+
+        Ok(operand_type)
+    }
+}
@@ -0,0 +1,84 @@
+use cog_parser::parser::core::{expr::Expr, nodes::Nodes, ops::BinaryOp, types::Types};
+
+use crate::check::{Checker, core::expr::check_integer_literal, errors::CheckError};
+
+impl Checker {
+    pub fn check_address_of(&mut self, operand: &Expr) -> Result<Types, CheckError> {
+        let pointee = self.check_place(operand)?;
+
+        Ok(Types::Pointer(Box::new(pointee)))
+    }
+
+    pub fn check_dereference(&mut self, operand: &Expr) -> Result<Types, CheckError> {
+        match self.check_value(operand)? {
+            Types::Pointer(pointee) if *pointee != Types::Void => Ok(*pointee),
+            Types::Box(pointee) => Ok(*pointee),
+            other => Err(CheckError::InvalidDereference(other)),
+        }
+    }
+
+    /// `ptr + n` and `ptr - n` move a pointer by `n` of its pointee, and
+    /// `ptr - ptr` counts the pointees between two pointers of the same type.
+    pub fn check_pointer_arithmetic(
+        &mut self,
+        operator: &BinaryOp,
+        pointer_type: Types,
+        offset: &Expr,
+    ) -> Result<Types, CheckError> {
+        self.check_unsafe("pointer arithmetic")?;
+        if pointer_type.is_dyn_pointer() || pointer_type == Types::Pointer(Box::new(Types::Void)) {
+            return Err(CheckError::InvalidBinaryOperand(
+                operator.clone(),
+                pointer_type,
+            ));
+        }
+
+        if let Expr::Literal(Nodes::Integer(value)) = offset {
+            check_integer_literal(*value, false, &Types::Isize)?;
+            return Ok(pointer_type);
+        }
+
+        match self.check_value(offset)? {
+            offset_type if offset_type.is_integer() => Ok(pointer_type),
+            offset_type if *operator == BinaryOp::Subtract && offset_type == pointer_type => {
+                Ok(Types::Isize)
+            }
+            offset_type => Err(CheckError::InvalidBinaryOperand(
+                operator.clone(),
+                offset_type,
+            )),
+        }
+    }
+
+    /// Only casts between pointers are allowed. They change what the pointer
+    /// is read as, not the address it holds.
+    pub fn check_cast(&mut self, value: &Expr, target: &Types) -> Result<Types, CheckError> {
+        self.check_unsafe("pointer cast")?;
+        self.check_type(target)?;
+        let target = self.expand(target)?;
+        let found = self.check_value(value)?;
+
+        let is_thin_pointer = |t: &Types| matches!(t, Types::Pointer(_)) && !t.is_dyn_pointer();
+        if !is_thin_pointer(&found) || !is_thin_pointer(&target) {
+            return Err(CheckError::InvalidCast(found, target));
+        }
+
+        Ok(target)
+    }
+
+    pub fn check_unsafe_block(&mut self, block: &Expr) -> Result<(), CheckError> {
+        let outer_unsafe = std::mem::replace(&mut self.in_unsafe, true);
+        let result = self.check_expr(block);
+        self.in_unsafe = outer_unsafe;
+
+        result.map(|_| ())
+    }
+
+    fn check_unsafe(&self, operation: &str) -> Result<(), CheckError> {
+        if self.in_unsafe {
+            Ok(())
+        } else {
+            Err(CheckError::RequiresUnsafe(operation.to_string()))
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use cog_parser::parser::core::expr::Expr;
+
+use crate::check::{Checker, errors::CheckError};
+
+impl Checker {
+    /// Checks `print` and `println`: every placeholder needs an argument, and
+    /// every argument a value. Values of any type can be printed.
+    pub fn check_print(&mut self, pieces: &[String], arguments: &[Expr]) -> Result<(), CheckError> {
+        let placeholders = pieces.len() - 1;
+        if placeholders != arguments.len() {
+            return Err(CheckError::PlaceholderCountMismatch(
+                placeholders,
+                arguments.len(),
+            ));
+        }
+
+        for argument in arguments {
+            self.check_value(argument)?;
+        }
+
+        Ok(())
+    }
+}
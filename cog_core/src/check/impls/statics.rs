@@ -0,0 +1,59 @@
+use cog_parser::parser::core::{expr::Expr, nodes::Nodes, types::Types};
+
+use crate::check::{Checker, core::consts::eval_const, errors::CheckError};
+
+impl Checker {
+    /// A static is initialized before the program runs, so its value must be
+    /// worked out at compile time.
+    pub fn check_static(
+        &mut self,
+        identifier: &str,
+        var_type: &Types,
+        value: &Expr,
+    ) -> Result<(), CheckError> {
+        self.check_type(var_type)?;
+        let static_type = self.statics[identifier].0.clone();
+        self.check_value_as(value, var_type)?;
+        eval_const(value, &static_type, &|name| {
+            self.structs
+                .get(name)
+                .and_then(|fields| self.expand_fields(fields).ok())
+        })
+        .map_err(CheckError::NotConstant)?;
+
+        Ok(())
+    }
+
+    pub fn check_static_value(&self, name: &str) -> Result<Types, CheckError> {
+        self.check_visible(name)?;
+
+        Ok(self.statics[name].0.clone())
+    }
+
+    /// The static `expr` names, unless a variable shadows it.
+    pub fn static_name(&self, expr: &Expr) -> Option<String> {
+        let name = match expr {
+            Expr::Literal(Nodes::Identifier(name)) if !self.is_variable(name) => name.clone(),
+            Expr::Path(segments) => segments.join("::"),
+            _ => return None,
+        };
+
+        self.statics.contains_key(&name).then_some(name)
+    }
+
+    /// Checks a place that is written to, which may be inside a `static mut`
+    /// but not inside any other static.
+    pub fn check_assignable(&mut self, expr: &Expr) -> Result<Types, CheckError> {
+        let mut root = expr;
+        while let Expr::FieldAccess { object, .. } = root {
+            root = object;
+        }
+        if let Some(name) = self.static_name(root)
+            && !self.statics[&name].1
+        {
+            return Err(CheckError::AssignToStatic(name));
+        }
+
+        self.check_place(expr)
+    }
+}
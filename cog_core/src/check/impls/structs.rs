@@ -0,0 +1,167 @@
+use cog_parser::parser::core::{
+    expr::{Expr, StructField},
+    types::Types,
+};
+
+use crate::check::{Checker, errors::CheckError};
+
+impl Checker {
+    pub fn check_struct_literal(
+        &mut self,
+        identifier: &str,
+        fields: &[(String, Expr)],
+    ) -> Result<Types, CheckError> {
+        self.check_visible(identifier)?;
+        let declared = self.lookup_struct(identifier)?;
+
+        for (name, value) in fields {
+            let field_type = self.declared_field_type(identifier, &declared, name)?;
+            self.check_value_as(value, &field_type)?;
+        }
+
+        self.check_fields_complete(identifier, &declared, fields)?;
+
+        Ok(Types::Struct(identifier.to_string()))
+    }
+
+    pub fn declared_field_type(
+        &self,
+        identifier: &str,
+        declared: &[StructField],
+        name: &str,
+    ) -> Result<Types, CheckError> {
+        declared
+            .iter()
+            .find(|field| field.identifier == name)
+            .map(|field| field.field_type.clone())
+            .ok_or_else(|| CheckError::UndefinedField(identifier.to_string(), name.to_string()))
+    }
+
+    /// Checks that a struct literal sets every declared field exactly once.
+    pub fn check_fields_complete(
+        &self,
+        identifier: &str,
+        declared: &[StructField],
+        fields: &[(String, Expr)],
+    ) -> Result<(), CheckError> {
+        for field in declared {
+            self.check_field_visible(identifier, field)?;
+            let count = fields
+                .iter()
+                .filter(|(name, _)| name == &field.identifier)
+                .count();
+            if count != 1 {
+                return Err(CheckError::MissingField(
+                    identifier.to_string(),
+                    field.identifier.clone(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Field access looks through one level of pointer or box, so `self.x`
+    /// works when `self` is a `*Type`. Tuple elements are fields named by
+    /// their index.
+    pub fn check_field_access(&mut self, object: &Expr, field: &str) -> Result<Types, CheckError> {
+        let object_type = self.check_value(object)?;
+        let struct_type = match &object_type {
+            Types::Pointer(pointee) | Types::Box(pointee) => pointee.as_ref(),
+            other => other,
+        };
+        if let Types::Tuple(elements) = struct_type {
+            return field
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| elements.get(index))
+                .cloned()
+                .ok_or_else(|| {
+                    CheckError::UndefinedField(format!("{:?}", struct_type), field.to_string())
+                });
+        }
+        let (struct_name, fields) = match struct_type {
+            Types::Struct(name) => (name, self.lookup_struct(name)?),
+            Types::Generic(name, arguments) => (name, self.generic_struct_fields(name, arguments)?),
+            _ => return Err(CheckError::InvalidFieldAccess(object_type)),
+        };
+
+        let field = fields
+            .into_iter()
+            .find(|f| f.identifier == field)
+            .ok_or_else(|| CheckError::UndefinedField(struct_name.clone(), field.to_string()))?;
+        self.check_field_visible(struct_name, &field)?;
+
+        self.expand(&field.field_type)
+    }
+
+    pub fn check_impl_block(
+        &mut self,
+        type_name: &str,
+        methods: &[Expr],
+    ) -> Result<(), CheckError> {
+        self.lookup_struct(type_name)?;
+
+        for method in methods {
+            if let Expr::FunctionDeclaration {
+                identifier,
+                parameters,
+                body,
+                return_type,
+            } = method.item()
+            {
+                let name = format!("{}::{}", type_name, identifier);
+                self.check_func_decl(&name, parameters, body, return_type)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks `receiver.method(args)`. The receiver is passed as the method's
+    /// `self` parameter, taking its address when `self` is a pointer.
+    pub fn check_method_call(
+        &mut self,
+        receiver: &Expr,
+        method: &str,
+        arguments: &[Expr],
+    ) -> Result<Option<Types>, CheckError> {
+        let receiver_type = self.check_value(receiver)?;
+        if matches!(receiver_type, Types::Option(_) | Types::Result(..)) {
+            return self.check_variant_method(&receiver_type, method, arguments);
+        }
+        if let Types::Pointer(pointee) = &receiver_type
+            && let Types::Dyn(trait_name) = pointee.as_ref()
+        {
+            return self.check_dyn_method_call(trait_name, method, arguments);
+        }
+
+        let type_name = match &receiver_type {
+            Types::Struct(name) => name.clone(),
+            Types::Pointer(pointee) | Types::Box(pointee) => match pointee.as_ref() {
+                Types::Struct(name) => name.clone(),
+                _ => return Err(CheckError::InvalidFieldAccess(receiver_type)),
+            },
+            _ => return Err(CheckError::InvalidFieldAccess(receiver_type)),
+        };
+
+        let name = self.resolve_method(&type_name, method)?;
+        let signature = self.lookup_function(&name)?;
+        self.check_visible(&name)?;
+
+        let self_struct = Types::Struct(type_name.clone());
+        let self_type = signature
+            .parameters
+            .first()
+            .map(|param| self.expand(param))
+            .transpose()?;
+        let takes_self = self_type.is_some_and(|param| {
+            param == self_struct || param == Types::Pointer(Box::new(self_struct.clone()))
+        });
+        if !takes_self {
+            return Err(CheckError::NotAMethod(type_name, method.to_string()));
+        }
+
+        self.check_arguments(&name, &signature, &signature.parameters[1..], arguments)
+    }
+}
@@ -0,0 +1,332 @@
+use std::{collections::HashMap, rc::Rc};
+
+use cog_parser::parser::core::{
+    expr::{Expr, TraitMethod, TypeParameter},
+    signature::FunctionSignature,
+    types::Types,
+};
+
+use crate::{
+    check::{Checker, errors::CheckError},
+    codegen::core::mangle::{mangle_instance, trait_method_name},
+    mono::core::{
+        rebuild::Rebuild,
+        subst::{Substitution, substitute},
+    },
+};
+
+impl Checker {
+    /// Checks `impl Trait for Type` against the trait and declares its methods
+    /// as `Type::Trait::method`. Default methods the impl does not override are copied
+    /// in with `Self` replaced by the implementing type.
+    pub fn declare_trait_impl(
+        &mut self,
+        trait_name: &str,
+        type_name: &str,
+        methods: &[Expr],
+    ) -> Result<(), CheckError> {
+        let required = self.lookup_trait(trait_name)?;
+        self.lookup_struct(type_name)?;
+
+        if !self
+            .trait_impls
+            .insert((trait_name.to_string(), type_name.to_string()))
+        {
+            return Err(CheckError::DuplicateImpl(
+                trait_name.to_string(),
+                type_name.to_string(),
+            ));
+        }
+
+        let bindings = HashMap::from([("Self".to_string(), Types::Struct(type_name.to_string()))]);
+
+        for method in methods {
+            let Expr::FunctionDeclaration {
+                identifier,
+                parameters,
+                return_type,
+                ..
+            } = method.item()
+            else {
+                continue;
+            };
+
+            let trait_method = required
+                .iter()
+                .find(|m| &m.identifier == identifier)
+                .ok_or_else(|| {
+                    CheckError::NotATraitMethod(trait_name.to_string(), identifier.clone())
+                })?;
+
+            let signature = self.signature(identifier, parameters, return_type, false)?;
+            if signature != trait_signature(trait_method, &bindings) {
+                return Err(CheckError::TraitMethodMismatch(
+                    trait_name.to_string(),
+                    identifier.clone(),
+                ));
+            }
+
+            self.declare_function(
+                trait_method_name(type_name, trait_name, identifier),
+                FunctionSignature::new(identifier, parameters, return_type, false),
+            )?;
+        }
+
+        for trait_method in &required {
+            if overrides(methods, &trait_method.identifier) {
+                continue;
+            }
+
+            let Some(body) = &trait_method.body else {
+                return Err(CheckError::MissingTraitMethod(
+                    trait_name.to_string(),
+                    type_name.to_string(),
+                    trait_method.identifier.clone(),
+                ));
+            };
+
+            let name = trait_method_name(type_name, trait_name, &trait_method.identifier);
+            let default = Expr::FunctionDeclaration {
+                identifier: name.clone(),
+                parameters: trait_method.parameters.clone(),
+                body: body.clone(),
+                return_type: trait_method.return_type.clone(),
+            };
+
+            self.declare_function(name.clone(), trait_signature(trait_method, &bindings))?;
+            self.instances.functions.insert(
+                name,
+                Rc::new(Substitution::new(&bindings).rebuild_expr(&default)),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Checks the types a trait's methods mention. Types involving `Self` are
+    /// checked for each implementation instead.
+    pub fn check_trait_declaration(&mut self, methods: &[TraitMethod]) -> Result<(), CheckError> {
+        for method in methods {
+            let signature = FunctionSignature::new(
+                &method.identifier,
+                &method.parameters,
+                &method.return_type,
+                false,
+            );
+            for t in signature.parameters.iter().chain([&signature.return_type]) {
+                if !t.has_params() {
+                    self.check_type(t)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn check_trait_impl(
+        &mut self,
+        trait_name: &str,
+        type_name: &str,
+        methods: &[Expr],
+    ) -> Result<(), CheckError> {
+        self.check_visible(trait_name)?;
+        for method in methods {
+            if let Expr::FunctionDeclaration {
+                identifier,
+                parameters,
+                body,
+                return_type,
+            } = method.item()
+            {
+                let name = trait_method_name(type_name, trait_name, identifier);
+                self.check_func_decl(&name, parameters, body, return_type)?;
+            }
+        }
+
+        for trait_method in self.lookup_trait(trait_name)? {
+            if overrides(methods, &trait_method.identifier) {
+                continue;
+            }
+
+            let name = trait_method_name(type_name, trait_name, &trait_method.identifier);
+            if let Some(default) = self.instances.functions.get(&name).cloned()
+                && let Expr::FunctionDeclaration {
+                    parameters,
+                    body,
+                    return_type,
+                    ..
+                } = default.as_ref()
+            {
+                self.check_func_decl(&name, parameters, body, return_type)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that each type argument implements the traits its parameter is
+    /// bounded by.
+    pub fn check_bounds(
+        &self,
+        type_parameters: &[TypeParameter],
+        type_arguments: &[Types],
+    ) -> Result<(), CheckError> {
+        for (param, argument) in type_parameters.iter().zip(type_arguments) {
+            for bound in &param.bounds {
+                self.lookup_trait(bound)?;
+                if !self.implements(argument, bound) {
+                    return Err(CheckError::UnsatisfiedBound(
+                        argument.clone(),
+                        bound.clone(),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// A trait can be used as `dyn Trait` when each of its methods takes
+    /// `self: *Self` and mentions `Self` nowhere else, so every method can be
+    /// called through a vtable without knowing the concrete type.
+    pub fn check_object_safe(&self, trait_name: &str) -> Result<(), CheckError> {
+        let self_pointer = Types::Pointer(Box::new(Types::Param("Self".into())));
+
+        for method in self.lookup_trait(trait_name)? {
+            let signature = FunctionSignature::new(
+                &method.identifier,
+                &method.parameters,
+                &method.return_type,
+                false,
+            );
+            let safe = signature.parameters.first() == Some(&self_pointer)
+                && !signature.parameters[1..].iter().any(Types::has_params)
+                && !signature.return_type.has_params();
+            if !safe {
+                return Err(CheckError::NotObjectSafe(
+                    trait_name.to_string(),
+                    method.identifier,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether a value of type `found` may be used where `expected` is a
+    /// `*dyn Trait`: a pointer to a type implementing the trait.
+    pub fn coerces_to_dyn(&self, found: &Types, expected: &Types) -> bool {
+        match (found, expected) {
+            (Types::Pointer(pointee), Types::Pointer(target)) => match target.as_ref() {
+                Types::Dyn(trait_name) => self.implements(pointee, trait_name),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    pub fn check_dyn_method_call(
+        &mut self,
+        trait_name: &str,
+        method: &str,
+        arguments: &[Expr],
+    ) -> Result<Option<Types>, CheckError> {
+        self.check_object_safe(trait_name)?;
+
+        let trait_method = self
+            .lookup_trait(trait_name)?
+            .into_iter()
+            .find(|m| m.identifier == method)
+            .ok_or_else(|| {
+                CheckError::UndefinedMethod(trait_name.to_string(), method.to_string())
+            })?;
+
+        let signature = FunctionSignature::new(
+            &trait_method.identifier,
+            &trait_method.parameters,
+            &trait_method.return_type,
+            false,
+        );
+        let name = format!("{}::{}", trait_name, method);
+        self.check_arguments(&name, &signature, &signature.parameters[1..], arguments)
+    }
+
+    /// The function `method` names on the struct `type_name`: its own method
+    /// if it has one, otherwise the method of the one trait it implements
+    /// that provides it.
+    pub fn resolve_method(&self, type_name: &str, method: &str) -> Result<String, CheckError> {
+        let inherent = format!("{}::{}", type_name, method);
+        if self.functions.contains_key(&inherent) {
+            return Ok(inherent);
+        }
+
+        let mut candidates = self
+            .trait_impls
+            .iter()
+            .filter(|(trait_name, implementor)| {
+                implementor == type_name
+                    && self.traits[trait_name]
+                        .iter()
+                        .any(|m| m.identifier == method)
+            })
+            .map(|(trait_name, _)| trait_method_name(type_name, trait_name, method));
+
+        match (candidates.next(), candidates.next()) {
+            (Some(name), None) => Ok(name),
+            (Some(_), Some(_)) => Err(CheckError::AmbiguousMethod(
+                type_name.to_string(),
+                method.to_string(),
+            )),
+            (None, _) => Err(CheckError::UndefinedMethod(
+                type_name.to_string(),
+                method.to_string(),
+            )),
+        }
+    }
+
+    pub fn implements(&self, t: &Types, trait_name: &str) -> bool {
+        self.impl_target(t)
+            .is_some_and(|name| self.trait_impls.contains(&(trait_name.to_string(), name)))
+    }
+
+    /// The name an impl for `t` is declared under: aliases are expanded and
+    /// a generic struct is named by its specialization, as after mono.
+    /// `None` for types that cannot have impls.
+    pub fn impl_target(&self, t: &Types) -> Option<String> {
+        match self.expand(t).ok()? {
+            Types::Struct(name) => Some(name),
+            Types::Generic(name, arguments) => Some(mangle_instance(&name, &arguments)),
+            _ => None,
+        }
+    }
+}
+
+/// The signature a trait method must have in an impl for the type `Self` is
+/// bound to.
+fn trait_signature(method: &TraitMethod, bindings: &HashMap<String, Types>) -> FunctionSignature {
+    let signature = FunctionSignature::new(
+        &method.identifier,
+        &method.parameters,
+        &method.return_type,
+        false,
+    );
+
+    FunctionSignature {
+        parameters: signature
+            .parameters
+            .iter()
+            .map(|param| substitute(param, bindings))
+            .collect(),
+        return_type: substitute(&signature.return_type, bindings),
+        is_variadic: false,
+    }
+}
+
+fn overrides(methods: &[Expr], name: &str) -> bool {
+    methods.iter().any(|method| {
+        matches!(
+            method.item(),
+            Expr::FunctionDeclaration { identifier, .. } if identifier == name
+        )
+    })
+}
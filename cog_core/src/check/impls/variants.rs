@@ -0,0 +1,106 @@
+use cog_parser::parser::core::{
+    expr::{Expr, Variant},
+    signature::FunctionSignature,
+    types::Types,
+};
+
+use crate::check::{Checker, errors::CheckError};
+
+impl Checker {
+    /// Checks an `Option` or `Result` constructor. `Some(x)` has the type of
+    /// `x` to go on; the others need the type they are expected to have.
+    pub fn check_variant(
+        &mut self,
+        variant: Variant,
+        value: &Option<Box<Expr>>,
+        expected: Option<&Types>,
+    ) -> Result<Types, CheckError> {
+        let payload_type = match (variant, expected) {
+            (Variant::Some, Some(Types::Option(value_type)))
+            | (Variant::Ok, Some(Types::Result(value_type, _)))
+            | (Variant::Err, Some(Types::Result(_, value_type))) => Some(value_type.as_ref()),
+            (Variant::None, Some(t @ Types::Option(_))) => return Ok(t.clone()),
+            _ => None,
+        };
+
+        match (value, payload_type, expected) {
+            (Some(value), Some(payload_type), Some(expected)) => {
+                self.check_value_as(value, payload_type)?;
+                Ok(expected.clone())
+            }
+            (Some(value), _, _) if variant == Variant::Some => {
+                Ok(Types::Option(Box::new(self.check_value(value)?)))
+            }
+            _ => {
+                let missing = match variant {
+                    Variant::Ok => "E",
+                    _ => "T",
+                };
+                Err(CheckError::CannotInferType(
+                    format!("{:?}", variant),
+                    missing.to_string(),
+                ))
+            }
+        }
+    }
+
+    /// Checks `operand?`. The enclosing function must return the same kind of
+    /// type, and for a `Result` the same error type, so the failure can be
+    /// returned as is.
+    pub fn check_try(&mut self, operand: &Expr) -> Result<Types, CheckError> {
+        let operand_type = self.check_value(operand)?;
+        if self.deferring {
+            return Err(CheckError::ReturnInDefer);
+        }
+        let return_type = self
+            .return_type
+            .as_ref()
+            .map(|t| self.expand(t))
+            .transpose()?
+            .ok_or(CheckError::ReturnOutsideFunction)?;
+
+        match (&operand_type, &return_type) {
+            (Types::Option(value), Types::Option(_)) => Ok(value.as_ref().clone()),
+            (Types::Result(value, error), Types::Result(_, return_error))
+                if error == return_error =>
+            {
+                Ok(value.as_ref().clone())
+            }
+            _ => Err(CheckError::TryMismatch(operand_type, return_type)),
+        }
+    }
+
+    /// Checks a call to one of the methods built into `Option` and `Result`.
+    pub fn check_variant_method(
+        &mut self,
+        receiver_type: &Types,
+        method: &str,
+        arguments: &[Expr],
+    ) -> Result<Option<Types>, CheckError> {
+        let signature = variant_method(receiver_type, method).ok_or_else(|| {
+            CheckError::UndefinedMethod(format!("{:?}", receiver_type), method.to_string())
+        })?;
+
+        self.check_arguments(method, &signature, &signature.parameters, arguments)
+    }
+}
+
+/// The signature of a built-in `Option` or `Result` method, without `self`.
+pub fn variant_method(receiver_type: &Types, method: &str) -> Option<FunctionSignature> {
+    let (parameters, return_type) = match (receiver_type, method) {
+        (Types::Option(_), "is_some" | "is_none") | (Types::Result(..), "is_ok" | "is_err") => {
+            (vec![], Types::Bool)
+        }
+        (Types::Option(value) | Types::Result(value, _), "unwrap") => (vec![], *value.clone()),
+        (Types::Option(value) | Types::Result(value, _), "unwrap_or") => {
+            (vec![*value.clone()], *value.clone())
+        }
+        (Types::Result(_, error), "unwrap_err") => (vec![], *error.clone()),
+        _ => return None,
+    };
+
+    Some(FunctionSignature::from_function_type(
+        &parameters,
+        &return_type,
+    ))
+}
@@ -0,0 +1,139 @@
+use cog_parser::parser::core::{expr::Expr, nodes::Nodes, ops::BinaryOp, types::Types};
+
+use crate::check::{Checker, errors::CheckError};
+
+impl Checker {
+    /// Assigning to a whole variable gives it a new value, so a freed or
+    /// moved variable may be assigned and then used again. A capturing
+    /// closure may only be stored in a variable.
+    pub fn check_assignment(&mut self, target: &Expr, value: &Expr) -> Result<(), CheckError> {
+        let Expr::Literal(Nodes::Identifier(name)) = target else {
+            let target_type = self.check_assignable(target)?;
+            self.check_value_as(value, &target_type)?;
+            return self.check_not_escaping(value);
+        };
+
+        let released = self.released.remove(name);
+        let target_type = self.check_assignable(target);
+        if let Some(released) = released {
+            self.released.insert(name.clone(), released);
+        }
+        self.check_value_as(value, &target_type?)?;
+        self.released.remove(name);
+        if self.static_name(target).is_some() {
+            return self.check_not_escaping(value);
+        }
+        self.track_local_closure(std::slice::from_ref(name), value);
+
+        Ok(())
+    }
+
+    pub fn check_compound_assignment(
+        &mut self,
+        target: &Expr,
+        operator: &BinaryOp,
+        value: &Expr,
+    ) -> Result<(), CheckError> {
+        let target_type = self.check_assignable(target)?;
+        let result_type = if operator.is_offset() && matches!(target_type, Types::Pointer(_)) {
+            self.check_pointer_arithmetic(operator, target_type.clone(), value)?
+        } else {
+            if operator.is_shift() {
+                self.check_shift_amount(operator, value)?;
+            } else {
+                self.check_value_as(value, &target_type)?;
+            }
+            self.check_binary_operator(operator, target_type.clone())?
+        };
+        if result_type != target_type {
+            return Err(CheckError::MismatchedTypes(target_type, result_type));
+        }
+
+        Ok(())
+    }
+
+    pub fn check_declaration(
+        &mut self,
+        identifier: &str,
+        var_type: &Option<Types>,
+        value: &Expr,
+    ) -> Result<(), CheckError> {
+        let var_type = match var_type {
+            Some(t) => {
+                self.check_type(t)?;
+                self.check_value_as(value, t)?
+            }
+            None => {
+                let value_type = self.check_value(value)?;
+                self.move_value(value);
+                value_type
+            }
+        };
+
+        self.declare_variable(identifier, var_type);
+        self.track_local_closure(&[identifier.to_string()], value);
+
+        Ok(())
+    }
+
+    pub fn check_tuple_declaration(
+        &mut self,
+        identifiers: &[String],
+        var_type: &Option<Types>,
+        value: &Expr,
+    ) -> Result<(), CheckError> {
+        let value_type = match var_type {
+            Some(t) => {
+                self.check_type(t)?;
+                self.check_value_as(value, t)?
+            }
+            None => self.check_value(value)?,
+        };
+
+        let Types::Tuple(element_types) = &value_type else {
+            return Err(CheckError::TupleArityMismatch(
+                value_type,
+                identifiers.len(),
+            ));
+        };
+        if element_types.len() != identifiers.len() {
+            return Err(CheckError::TupleArityMismatch(
+                value_type.clone(),
+                identifiers.len(),
+            ));
+        }
+
+        for (identifier, element_type) in identifiers.iter().zip(element_types) {
+            self.declare_variable(identifier, element_type.clone());
+        }
+        self.track_local_closure(identifiers, value);
+
+        Ok(())
+    }
+
+    /// Checks that `expr` is an assignable place and returns its type.
+    pub fn check_place(&mut self, expr: &Expr) -> Result<Types, CheckError> {
+        if !expr.is_place() {
+            return Err(CheckError::NotAnLvalue(format!("{:?}", expr)));
+        }
+
+        // Closures hold copies of what they capture, so writing to a capture
+        // would not be seen outside. Writing through a captured pointer is fine.
+        let mut root = expr;
+        while let Expr::FieldAccess { object, .. } = root {
+            root = object;
+        }
+        if let Expr::Path(segments) = root
+            && self.static_name(root).is_none()
+        {
+            return Err(CheckError::NotAnLvalue(segments.join("::")));
+        }
+        if let Expr::Literal(Nodes::Identifier(name)) = root
+            && self.is_captured(name)
+        {
+            return Err(CheckError::AssignToCapture(name.clone()));
+        }
+
+        self.check_value(expr)
+    }
+}
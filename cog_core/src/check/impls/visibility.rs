@@ -0,0 +1,68 @@
+use cog_parser::parser::core::expr::{Expr, StructField};
+
+use crate::check::{Checker, errors::CheckError};
+
+impl Checker {
+    /// Records an item that is not `pub`, so that only code in `module` and
+    /// its descendants may use it. Items of the root module are visible
+    /// everywhere.
+    pub fn declare_visibility(&mut self, name: &str, public: bool, module: &str) {
+        if !public && !module.is_empty() {
+            self.private.insert(name.to_string(), module.to_string());
+        }
+    }
+
+    pub fn check_visible(&self, name: &str) -> Result<(), CheckError> {
+        match self.private.get(name) {
+            Some(module) if !self.can_see(module) => Err(CheckError::PrivateItem(name.to_string())),
+            _ => Ok(()),
+        }
+    }
+
+    /// Fields are private to the module of their struct unless marked `pub`.
+    pub fn check_field_visible(
+        &self,
+        struct_name: &str,
+        field: &StructField,
+    ) -> Result<(), CheckError> {
+        if field.public || self.can_see(module_of(struct_name)) {
+            Ok(())
+        } else {
+            Err(CheckError::PrivateField(
+                struct_name.to_string(),
+                field.identifier.clone(),
+            ))
+        }
+    }
+
+    fn can_see(&self, module: &str) -> bool {
+        module.is_empty()
+            || self.module == module
+            || self
+                .module
+                .strip_prefix(module)
+                .is_some_and(|rest| rest.starts_with("::"))
+    }
+}
+
+/// The module an item is declared in, from its linked name: `geo::Point` is
+/// declared in `geo`, and `Point` in the root.
+pub fn module_of(name: &str) -> &str {
+    name.rsplit_once("::").map_or("", |(module, _)| module)
+}
+
+/// The module of the code in a top-level statement. Methods belong to the
+/// module of the type they are implemented for.
+pub fn item_module(stmt: &Expr) -> &str {
+    match stmt.item() {
+        Expr::FunctionDeclaration { identifier, .. }
+        | Expr::StructDeclaration { identifier, .. }
+        | Expr::TraitDeclaration { identifier, .. }
+        | Expr::StaticDeclaration { identifier, .. } => module_of(identifier),
+        Expr::ImplBlock { type_name, .. } | Expr::TraitImpl { type_name, .. } => {
+            module_of(type_name)
+        }
+        Expr::Generic { item, .. } => item_module(item),
+        _ => "",
+    }
+}
@@ -1 +1,423 @@
+use std::{
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use cog_parser::parser::{
+    core::{
+        expr::{Expr, StructField, TraitMethod, TypeParameter},
+        signature::FunctionSignature,
+        types::Types,
+    },
+    impls::primary::BUILTIN_FUNCTIONS,
+};
+
+use crate::{
+    check::{
+        core::{operators::OperatorTrait, release::Release},
+        errors::CheckError,
+        impls::visibility::{item_module, module_of},
+    },
+    codegen::core::mangle::RUNTIME_FUNCTIONS,
+    mono::core::instances::Instances,
+};
+pub mod core;
 pub mod errors;
+pub mod impls;
+
+pub struct Checker {
+    scopes: Vec<HashMap<String, Types>>,
+    /// Function signatures and struct fields keep their types as written,
+    /// so diagnostics name an alias rather than what it expands to.
+    functions: HashMap<String, FunctionSignature>,
+    structs: HashMap<String, Vec<StructField>>,
+    generic_functions: HashMap<String, (Vec<TypeParameter>, Rc<Expr>)>,
+    generic_structs: HashMap<String, (Vec<TypeParameter>, Vec<StructField>)>,
+    /// The type of each static, and whether it is `static mut`.
+    statics: HashMap<String, (Types, bool)>,
+    /// Declared traits, starting with the built-in operator traits.
+    traits: HashMap<String, Vec<TraitMethod>>,
+    /// The type each alias names, as written.
+    aliases: HashMap<String, Types>,
+    /// `(trait, type)` pairs with an `impl Trait for Type`.
+    trait_impls: HashSet<(String, String)>,
+    instances: Instances,
+    instantiation_depth: usize,
+    /// The return type of the function being checked, as written.
+    return_type: Option<Types>,
+    /// For each closure being checked, innermost last: the number of scopes
+    /// outside it and the enclosing variables its body uses.
+    captures: Vec<(usize, Vec<String>)>,
+    /// Items that are not `pub`, with the module that declares them.
+    private: HashMap<String, String>,
+    /// The module of the code being checked, such as `geo::shapes`. Empty
+    /// for the root module.
+    module: String,
+    /// Variables of the function being checked that were freed or moved out,
+    /// and so may not be used until they are assigned again.
+    released: HashMap<String, Release>,
+    /// Variables of the function being checked holding a closure whose
+    /// environment lives in its frame, and so may not outlive it.
+    local_closures: HashSet<String>,
+    /// For each open scope of the function being checked, what its deferred
+    /// expressions release when they run as the scope is left.
+    deferred: Vec<HashMap<String, Release>>,
+    /// Whether a deferred expression is being checked. It runs as its block
+    /// is left, so it may not leave the function itself.
+    deferring: bool,
+    /// Whether the code being checked is inside an `unsafe` block.
+    in_unsafe: bool,
+}
+
+impl Default for Checker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Checker {
+    pub fn new() -> Self {
+        Self {
+            scopes: vec![HashMap::new()],
+            functions: HashMap::new(),
+            structs: HashMap::new(),
+            generic_functions: HashMap::new(),
+            generic_structs: HashMap::new(),
+            statics: HashMap::new(),
+            traits: OperatorTrait::all()
+                .into_iter()
+                .map(|op| (op.name.to_string(), op.declaration()))
+                .collect(),
+            aliases: HashMap::new(),
+            trait_impls: HashSet::new(),
+            instances: Instances::default(),
+            instantiation_depth: 0,
+            return_type: None,
+            captures: Vec::new(),
+            private: HashMap::new(),
+            module: String::new(),
+            released: HashMap::new(),
+            local_closures: HashSet::new(),
+            deferred: Vec::new(),
+            deferring: false,
+            in_unsafe: false,
+        }
+    }
+
+    pub fn check(&mut self, statements: &[Expr]) -> Result<(), CheckError> {
+        self.declare_items(statements)?;
+
+        for stmt in statements {
+            self.module = item_module(stmt).to_string();
+            self.check_expr(stmt)?;
+        }
+
+        Ok(())
+    }
+
+    /// The generic specializations used by the checked program, for the
+    /// monomorphization stage.
+    pub fn instances(&self) -> &Instances {
+        &self.instances
+    }
+}
+
+impl Checker {
+    fn enter_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+        self.deferred.push(HashMap::new());
+    }
+
+    fn exit_scope(&mut self) {
+        if let Some(scope) = self.scopes.pop() {
+            self.released.retain(|name, _| !scope.contains_key(name));
+            self.local_closures.retain(|name| !scope.contains_key(name));
+        }
+
+        // The deferred expressions of the scope run as it is left.
+        let deferred = self.deferred.pop().unwrap_or_default();
+        let deferred: Vec<_> = deferred
+            .into_iter()
+            .filter(|(name, _)| self.is_variable(name))
+            .collect();
+        self.released.extend(deferred);
+    }
+
+    fn declare_variable(&mut self, name: &str, var_type: Types) {
+        self.released.remove(name);
+        self.local_closures.remove(name);
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name.to_string(), var_type);
+        }
+    }
+
+    /// Registers every top-level type, function and static up front so they
+    /// may be used before their declaration.
+    fn declare_items(&mut self, statements: &[Expr]) -> Result<(), CheckError> {
+        self.declare_aliases(statements)?;
+
+        for stmt in statements {
+            match stmt.item() {
+                Expr::StructDeclaration { identifier, fields } => {
+                    self.claim_type_name(identifier)?;
+                    self.declare_visibility(identifier, stmt.is_public(), module_of(identifier));
+                    self.structs.insert(identifier.clone(), fields.clone());
+                }
+                Expr::Generic {
+                    type_parameters,
+                    item,
+                } => {
+                    if let Expr::StructDeclaration { identifier, fields } = item.as_ref() {
+                        self.claim_type_name(identifier)?;
+                        self.declare_visibility(
+                            identifier,
+                            stmt.is_public(),
+                            module_of(identifier),
+                        );
+                        let fields = self.expand_fields(fields)?;
+                        self.generic_structs
+                            .insert(identifier.clone(), (type_parameters.clone(), fields));
+                    }
+                }
+                Expr::TraitDeclaration {
+                    identifier,
+                    methods,
+                } => {
+                    self.claim_type_name(identifier)?;
+                    self.declare_visibility(identifier, stmt.is_public(), module_of(identifier));
+                    let methods = self.expand_trait_methods(methods)?;
+                    self.traits.insert(identifier.clone(), methods);
+                }
+                _ => {}
+            }
+        }
+
+        for stmt in statements {
+            match stmt.item() {
+                Expr::FunctionDeclaration {
+                    identifier,
+                    parameters,
+                    return_type,
+                    ..
+                } => {
+                    self.claim_function_name(identifier, true)?;
+                    let signature =
+                        FunctionSignature::new(identifier, parameters, return_type, false);
+                    self.declare_visibility(identifier, stmt.is_public(), module_of(identifier));
+                    self.declare_function(identifier.clone(), signature)?;
+                }
+                Expr::StaticDeclaration {
+                    identifier,
+                    var_type,
+                    mutable,
+                    ..
+                } => {
+                    if self.statics.contains_key(identifier)
+                        || self.functions.contains_key(identifier)
+                        || self.generic_functions.contains_key(identifier)
+                    {
+                        return Err(CheckError::DuplicateStatic(identifier.clone()));
+                    }
+                    self.declare_visibility(identifier, stmt.is_public(), module_of(identifier));
+                    let var_type = self.expand(var_type)?;
+                    self.statics
+                        .insert(identifier.clone(), (var_type, *mutable));
+                }
+                Expr::ExternFunctionDeclaration {
+                    identifier,
+                    parameters,
+                    return_type,
+                    is_variadic,
+                } => {
+                    self.claim_function_name(identifier, false)?;
+                    let signature =
+                        FunctionSignature::new(identifier, parameters, return_type, *is_variadic);
+                    self.declare_function(identifier.clone(), signature)?;
+                }
+                Expr::ImplBlock { type_name, methods } => {
+                    for method in methods {
+                        if let Expr::FunctionDeclaration {
+                            identifier,
+                            parameters,
+                            return_type,
+                            ..
+                        } = method.item()
+                        {
+                            let signature =
+                                FunctionSignature::new(identifier, parameters, return_type, false);
+                            let name = format!("{}::{}", type_name, identifier);
+                            self.declare_visibility(
+                                &name,
+                                method.is_public(),
+                                module_of(type_name),
+                            );
+                            self.declare_function(name, signature)?;
+                        }
+                    }
+                }
+                Expr::TraitImpl {
+                    trait_name,
+                    type_name,
+                    methods,
+                } => self.declare_trait_impl(trait_name, type_name, methods)?,
+                Expr::Generic {
+                    type_parameters,
+                    item,
+                } => {
+                    if let Expr::FunctionDeclaration { identifier, .. } = item.as_ref() {
+                        self.claim_function_name(identifier, false)?;
+                        if self.functions.contains_key(identifier)
+                            || self.generic_functions.contains_key(identifier)
+                        {
+                            return Err(CheckError::DuplicateFunction(identifier.clone()));
+                        }
+                        self.declare_visibility(
+                            identifier,
+                            stmt.is_public(),
+                            module_of(identifier),
+                        );
+                        self.generic_functions.insert(
+                            identifier.clone(),
+                            (type_parameters.clone(), Rc::new(item.as_ref().clone())),
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Aliases are declared before everything else, as the types of other
+    /// items are expanded as they are declared. They are recorded expanded
+    /// for the later stages.
+    fn declare_aliases(&mut self, statements: &[Expr]) -> Result<(), CheckError> {
+        for stmt in statements {
+            if let Expr::TypeAlias {
+                identifier,
+                aliased,
+            } = stmt.item()
+            {
+                self.claim_type_name(identifier)?;
+                self.declare_visibility(identifier, stmt.is_public(), module_of(identifier));
+                self.aliases.insert(identifier.clone(), aliased.clone());
+            }
+        }
+
+        for stmt in statements {
+            if let Expr::TypeAlias { identifier, .. } = stmt.item() {
+                let expanded = self.expand(&Types::Alias(identifier.clone()))?;
+                self.instances.aliases.insert(identifier.clone(), expanded);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Built-ins are parsed by name wherever they are called, so no function
+    /// may take one of their names. A function defined in the root module
+    /// keeps its name as its symbol, so when `defines_symbol` it may not take
+    /// the name of a libc function the runtime calls either.
+    fn claim_function_name(&self, name: &str, defines_symbol: bool) -> Result<(), CheckError> {
+        let unqualified = name.rsplit("::").next().unwrap_or(name);
+        if BUILTIN_FUNCTIONS.contains(&unqualified)
+            || (defines_symbol && RUNTIME_FUNCTIONS.contains(&name))
+        {
+            return Err(CheckError::ReservedName(name.to_string()));
+        }
+
+        Ok(())
+    }
+
+    fn claim_type_name(&self, name: &str) -> Result<(), CheckError> {
+        if matches!(name, "Option" | "Result")
+            || self.structs.contains_key(name)
+            || self.generic_structs.contains_key(name)
+            || self.traits.contains_key(name)
+            || self.aliases.contains_key(name)
+        {
+            return Err(CheckError::DuplicateType(name.to_string()));
+        }
+
+        Ok(())
+    }
+
+    fn declare_function(
+        &mut self,
+        name: String,
+        signature: FunctionSignature,
+    ) -> Result<(), CheckError> {
+        if self.functions.contains_key(&name)
+            || self.generic_functions.contains_key(&name)
+            || self.statics.contains_key(&name)
+        {
+            return Err(CheckError::DuplicateFunction(name));
+        }
+
+        self.functions.insert(name, signature);
+
+        Ok(())
+    }
+
+    fn lookup_function(&self, name: &str) -> Result<FunctionSignature, CheckError> {
+        self.functions
+            .get(name)
+            .cloned()
+            .ok_or_else(|| CheckError::UndefinedFunction(name.to_string()))
+    }
+
+    fn lookup_trait(&self, name: &str) -> Result<Vec<TraitMethod>, CheckError> {
+        self.traits
+            .get(name)
+            .cloned()
+            .ok_or_else(|| CheckError::UndefinedTrait(name.to_string()))
+    }
+
+    fn lookup_struct(&self, name: &str) -> Result<Vec<StructField>, CheckError> {
+        self.structs
+            .get(name)
+            .cloned()
+            .ok_or_else(|| CheckError::UndefinedType(name.to_string()))
+    }
+
+    /// Looks up a variable the program reads, recording it as a capture of
+    /// every closure it is declared outside of.
+    fn lookup_variable(&mut self, name: &str) -> Result<Types, CheckError> {
+        let depth = self
+            .scopes
+            .iter()
+            .rposition(|scope| scope.contains_key(name))
+            .ok_or_else(|| CheckError::UndefinedVariable(name.to_string()))?;
+
+        for (outer_scopes, captures) in &mut self.captures {
+            if depth < *outer_scopes && !captures.iter().any(|capture| capture == name) {
+                captures.push(name.to_string());
+            }
+        }
+
+        Ok(self.scopes[depth][name].clone())
+    }
+
+    /// Whether `name` is a variable declared outside the innermost closure
+    /// being checked.
+    fn is_captured(&self, name: &str) -> bool {
+        let Some((outer_scopes, _)) = self.captures.last() else {
+            return false;
+        };
+
+        self.scopes
+            .iter()
+            .rposition(|scope| scope.contains_key(name))
+            .is_some_and(|depth| depth < *outer_scopes)
+    }
+
+    /// The type of the variable `name`, without counting it as used.
+    fn variable_type(&self, name: &str) -> Option<&Types> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    fn is_variable(&self, name: &str) -> bool {
+        self.scopes.iter().any(|scope| scope.contains_key(name))
+    }
+}
@@ -1,10 +1,39 @@
-use cog_parser::parser::core::expr::Expr;
+use cog_parser::parser::core::{expr::Expr, nodes::Nodes, ops::UnaryOp, types::Types};
 
-use crate::codegen::{CodeGen, errors::CodeGenError};
+use inkwell::{AddressSpace, module::Linkage, values::PointerValue};
+
+use crate::codegen::{CodeGen, core::value::TypedValue, errors::CodeGenError};
 
 impl<'ctx> CodeGen<'ctx> {
-    pub fn compile_expr(&mut self, expr: &Expr) -> Result<(), CodeGenError> {
+    pub fn compile_expr(&mut self, expr: &Expr) -> Result<Option<TypedValue<'ctx>>, CodeGenError> {
         match expr {
+            Expr::Literal(node) => Ok(Some(self.compile_literal(node)?)),
+            Expr::Binary {
+                left,
+                operator,
+                right,
+            } => Ok(Some(self.compile_binary(left, operator, right)?)),
+            Expr::Unary { operator, operand } => Ok(Some(self.compile_unary(operator, operand)?)),
+            Expr::Assignment { target, value } => {
+                self.compile_assignment(target, value)?;
+                Ok(None)
+            }
+            Expr::CompoundAssignment {
+                target,
+                operator,
+                value,
+            } => {
+                self.compile_compound_assignment(target, operator, value)?;
+                Ok(None)
+            }
+            Expr::Declaration {
+                identifier,
+                var_type,
+                value,
+            } => {
+                self.compile_declaration(identifier, var_type, value)?;
+                Ok(None)
+            }
             Expr::FunctionDeclaration {
                 identifier,
                 parameters,
@@ -12,12 +41,270 @@ impl<'ctx> CodeGen<'ctx> {
                 return_type,
             } => {
                 self.compile_func_decl(identifier, parameters, body, return_type)?;
+                Ok(None)
             }
+            Expr::ExternFunctionDeclaration {
+                identifier,
+                parameters,
+                return_type,
+                is_variadic,
+            } => {
+                self.compile_extern_decl(identifier, parameters, return_type, *is_variadic);
+                Ok(None)
+            }
+            Expr::Call { callee, arguments } => self.compile_call(callee, arguments),
             Expr::Return { value } => {
                 self.build_return(value)?;
+                Ok(None)
+            }
+            Expr::Defer(expr) => {
+                self.compile_defer(expr)?;
+                Ok(None)
+            }
+            Expr::Block(exprs) => {
+                self.compile_block(exprs)?;
+                Ok(None)
+            }
+            Expr::AddressOf(operand) => Ok(Some(self.compile_address_of(operand)?)),
+            Expr::Cast { value, target } => Ok(Some(self.compile_cast(value, target)?)),
+            Expr::Unsafe(block) => {
+                self.compile_expr(block)?;
+                Ok(None)
+            }
+            Expr::Dereference(operand) => Ok(Some(self.compile_dereference(operand)?)),
+            // Generic items only produce code through their specializations.
+            Expr::StructDeclaration { .. }
+            | Expr::Generic { .. }
+            | Expr::TraitDeclaration { .. }
+            | Expr::ModuleDeclaration { .. }
+            | Expr::Import { .. }
+            | Expr::TypeAlias { .. } => Ok(None),
+            // Statics are emitted as globals before any code.
+            Expr::StaticDeclaration { .. } => Ok(None),
+            Expr::StructLiteral { identifier, fields } => {
+                Ok(Some(self.compile_struct_literal(identifier, fields)?))
+            }
+            Expr::FieldAccess { .. } => {
+                let (ptr, field_type) = self.compile_place(expr)?;
+                let value =
+                    self.llvm_builder
+                        .build_load(self.get_llvm_type(&field_type), ptr, "field")?;
+                Ok(Some(TypedValue::new(value, field_type)))
+            }
+            Expr::ImplBlock { type_name, methods } => {
+                self.compile_impl_block(type_name, None, methods)?;
+                Ok(None)
+            }
+            // Trait methods are resolved statically, so they compile like any
+            // other method, under `Type::Trait::method`.
+            Expr::TraitImpl {
+                trait_name,
+                type_name,
+                methods,
+            } => {
+                self.compile_impl_block(type_name, Some(trait_name), methods)?;
+                Ok(None)
+            }
+            Expr::MethodCall {
+                receiver,
+                method,
+                arguments,
+            } => self.compile_method_call(receiver, method, arguments),
+            Expr::Path(segments) => {
+                let name = segments.join("::");
+                if self.statics.contains_key(&name) {
+                    return Ok(Some(self.load_variable(&name)?));
+                }
+                Ok(Some(self.compile_function_value(&name)?))
+            }
+            Expr::IfElse {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                self.compile_if_else(condition, then_branch, else_branch)?;
+                Ok(None)
+            }
+            Expr::TupleDeclaration {
+                identifiers,
+                var_type,
+                value,
+            } => {
+                self.compile_tuple_declaration(identifiers, var_type, value)?;
+                Ok(None)
+            }
+            Expr::Variant { variant, value } => {
+                Ok(Some(self.compile_variant(*variant, value, None)?))
+            }
+            Expr::Try(operand) => Ok(Some(self.compile_try(operand)?)),
+            Expr::Alloc(t) => Ok(Some(self.compile_alloc(t)?)),
+            Expr::Free(operand) => {
+                self.compile_free(operand)?;
+                Ok(None)
+            }
+            Expr::BoxNew(value) => Ok(Some(self.compile_box_new(value, None)?)),
+            Expr::Tuple(elements) => {
+                let mut values = Vec::with_capacity(elements.len());
+                for element in elements {
+                    values.push(self.compile_value(element)?);
+                    self.move_value(element)?;
+                }
+                Ok(Some(self.build_tuple(values)?))
             }
-            _ => todo!(),
+            Expr::Print {
+                pieces,
+                arguments,
+                newline,
+            } => {
+                self.compile_print(pieces, arguments, *newline)?;
+                Ok(None)
+            }
+            Expr::Documented { item, .. } | Expr::Public { item } => self.compile_expr(item),
+            Expr::Closure {
+                parameters,
+                return_type,
+                body,
+                captures,
+            } => Ok(Some(self.compile_closure(
+                parameters,
+                return_type,
+                body,
+                captures,
+            )?)),
         }
-        Ok(())
+    }
+
+    /// Compiles an expression that must produce a value.
+    pub fn compile_value(&mut self, expr: &Expr) -> Result<TypedValue<'ctx>, CodeGenError> {
+        self.compile_expr(expr)?
+            .ok_or_else(|| CodeGenError::ExpectedValue(format!("{:?}", expr)))
+    }
+
+    /// Compiles an expression for a known destination type, letting numeric
+    /// literals adopt the expected width and string literals decay to `*i8`.
+    /// A negated literal adopts a signed or float type like a plain one.
+    /// Tuple elements are compiled for their expected types in turn, and
+    /// `None`, `Ok` and `Err` take their type from the destination. A box
+    /// variable compiled this way is moved into the destination.
+    pub fn compile_value_as(
+        &mut self,
+        expr: &Expr,
+        expected: &Types,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        match (expr, expected) {
+            (Expr::Literal(Nodes::Integer(v)), _) if expected.is_integer() => {
+                let int_type = self.get_llvm_type(expected).into_int_type();
+                Ok(TypedValue::new(
+                    int_type.const_int(*v, true),
+                    expected.clone(),
+                ))
+            }
+            (Expr::Unary { operator, operand }, _)
+                if **operator == UnaryOp::Minus
+                    && (expected.is_signed() || expected.is_float()) =>
+            {
+                let value = self.compile_value_as(operand, expected)?;
+                self.build_neg(value)
+            }
+            (Expr::Unary { operator, operand }, _)
+                if **operator == UnaryOp::BitNot && expected.is_integer() =>
+            {
+                let value = self.compile_value_as(operand, expected)?;
+                self.build_not(value)
+            }
+            (
+                Expr::Binary {
+                    left,
+                    operator,
+                    right,
+                },
+                _,
+            ) if operator.preserves(expected)
+                && (left.is_literal_tree() || right.is_literal_tree()) =>
+            {
+                let lhs = self.compile_value_as(left, expected)?;
+                let rhs = self.compile_value_as(right, expected)?;
+                self.build_binary(lhs, operator, rhs)
+            }
+            (Expr::Literal(Nodes::Float(v)), _) if expected.is_float() => {
+                let float_type = self.get_llvm_type(expected).into_float_type();
+                Ok(TypedValue::new(
+                    float_type.const_float(*v),
+                    expected.clone(),
+                ))
+            }
+            (Expr::Literal(Nodes::String(v)), _) if expected.is_c_string() => Ok(TypedValue::new(
+                self.build_string_literal(v),
+                expected.clone(),
+            )),
+            (Expr::Variant { variant, value }, Types::Option(_) | Types::Result(..)) => {
+                self.compile_variant(*variant, value, Some(expected))
+            }
+            (Expr::BoxNew(value), Types::Box(_)) => self.compile_box_new(value, Some(expected)),
+            (Expr::Literal(Nodes::Null), Types::Pointer(_)) => Ok(TypedValue::new(
+                self.llvm_ctx.ptr_type(AddressSpace::default()).const_null(),
+                expected.clone(),
+            )),
+            (Expr::Tuple(elements), Types::Tuple(element_types)) => {
+                let mut values = Vec::with_capacity(elements.len());
+                for (element, element_type) in elements.iter().zip(element_types) {
+                    values.push(self.compile_value_as(element, element_type)?);
+                }
+                self.build_tuple(values)
+            }
+            _ => {
+                let value = self.compile_value(expr)?;
+                self.move_value(expr)?;
+                self.coerce_to_dyn(value, expected)
+            }
+        }
+    }
+
+    pub fn compile_literal(&mut self, node: &Nodes) -> Result<TypedValue<'ctx>, CodeGenError> {
+        match node {
+            Nodes::Integer(v) => Ok(TypedValue::new(
+                self.llvm_ctx.i32_type().const_int(*v, true),
+                Types::I32,
+            )),
+            Nodes::Float(v) => Ok(TypedValue::new(
+                self.llvm_ctx.f64_type().const_float(*v),
+                Types::F64,
+            )),
+            Nodes::Boolean(v) => Ok(TypedValue::new(
+                self.llvm_ctx.bool_type().const_int(*v as u64, false),
+                Types::Bool,
+            )),
+            Nodes::String(v) => Ok(TypedValue::new(self.build_string_literal(v), Types::String)),
+            Nodes::Char(v) => Ok(TypedValue::new(
+                self.llvm_ctx.i32_type().const_int(*v as u64, false),
+                Types::Char,
+            )),
+            Nodes::Byte(v) => Ok(TypedValue::new(
+                self.llvm_ctx.i8_type().const_int(*v as u64, false),
+                Types::U8,
+            )),
+            Nodes::Null => Ok(TypedValue::new(
+                self.llvm_ctx.ptr_type(AddressSpace::default()).const_null(),
+                Types::Pointer(Box::new(Types::Void)),
+            )),
+            Nodes::Identifier(name)
+                if !self.variables.contains_key(name) && !self.statics.contains_key(name) =>
+            {
+                self.compile_function_value(name)
+            }
+            Nodes::Identifier(name) => self.load_variable(name),
+        }
+    }
+
+    /// Emits a string literal as a private constant with a trailing NUL. The
+    /// bytes are copied as is, so a `\0` escape stays in the data.
+    pub fn build_string_literal(&self, value: &str) -> PointerValue<'ctx> {
+        let data = self.llvm_ctx.const_string(value.as_bytes(), true);
+        let global = self.lvvm_module.add_global(data.get_type(), None, "str");
+        global.set_initializer(&data);
+        global.set_constant(true);
+        global.set_linkage(Linkage::Private);
+        global.set_unnamed_addr(true);
+        global.as_pointer_value()
     }
 }
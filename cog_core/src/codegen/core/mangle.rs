@@ -0,0 +1,178 @@
+use cog_parser::parser::core::types::Types;
+
+/// The libc functions the compiled program calls on its own. Plain names are
+/// not mangled, so a program may not define a function named like one.
+pub const RUNTIME_FUNCTIONS: &[&str] = &["write", "dprintf", "malloc", "free"];
+
+/// Produces the symbol name for a function. Plain names are left alone so
+/// `main` and extern declarations keep their C names; qualified names such as
+/// `Point::new` are mangled into `_CN5Point3newE`.
+pub fn mangle_name(name: &str) -> String {
+    if !name.contains("::") {
+        return name.to_string();
+    }
+
+    let mut mangled = String::from("_CN");
+    for segment in name.split("::") {
+        push_identifier(&mut mangled, segment);
+    }
+    mangled.push('E');
+
+    mangled
+}
+
+/// Produces the name of one specialization of a generic item, such as
+/// `_CN3maxIiEE` for `max<i32>`. The same item and type arguments always give
+/// the same name.
+pub fn mangle_instance(name: &str, type_arguments: &[Types]) -> String {
+    let mut mangled = String::from("_CN");
+    for segment in name.split("::") {
+        push_identifier(&mut mangled, segment);
+    }
+    push_type_arguments(&mut mangled, type_arguments);
+    mangled.push('E');
+
+    mangled
+}
+
+/// The name a method from `impl Trait for Type` is declared under,
+/// `Type::Trait::method`, so traits sharing a method name do not collide with
+/// each other or with the type's own methods.
+pub fn trait_method_name(type_name: &str, trait_name: &str, method: &str) -> String {
+    format!("{}::{}::{}", type_name, trait_name, method)
+}
+
+/// The name a struct was declared with, for display. Specializations show
+/// the name of their generic item, so `_CN4PairIiEE` is shown as `Pair`.
+pub fn display_name(symbol: &str) -> String {
+    let Some(mut rest) = symbol.strip_prefix("_CN") else {
+        return symbol.to_string();
+    };
+
+    let mut segments = Vec::new();
+    loop {
+        let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        let Ok(len) = rest[..digits].parse::<usize>() else {
+            break;
+        };
+        let Some(segment) = rest.get(digits..digits + len) else {
+            break;
+        };
+        segments.push(segment);
+        rest = &rest[digits + len..];
+    }
+
+    segments.join("::")
+}
+
+/// Produces the name of the vtable for `type_name` used as `dyn trait_name`,
+/// such as `_CTV6Circle5ShapeE`.
+pub fn mangle_vtable(type_name: &str, trait_name: &str) -> String {
+    let mut mangled = String::from("_CTV");
+    push_name(&mut mangled, type_name);
+    push_name(&mut mangled, trait_name);
+    mangled.push('E');
+
+    mangled
+}
+
+/// Produces the name of the adapter that lets the function `name` be called
+/// through a function value, such as `_CTF6doubleE`.
+pub fn mangle_thunk(name: &str) -> String {
+    let mut mangled = String::from("_CTF");
+    for segment in name.split("::") {
+        push_identifier(&mut mangled, segment);
+    }
+    mangled.push('E');
+
+    mangled
+}
+
+fn push_identifier(mangled: &mut String, identifier: &str) {
+    mangled.push_str(&identifier.len().to_string());
+    mangled.push_str(identifier);
+}
+
+/// Pushes the name of a type or trait. Names from a module, such as
+/// `geo::Point`, are nested as `N3geo5PointE`.
+fn push_name(mangled: &mut String, name: &str) {
+    if name.contains("::") {
+        mangled.push('N');
+        for segment in name.split("::") {
+            push_identifier(mangled, segment);
+        }
+        mangled.push('E');
+    } else {
+        push_identifier(mangled, name);
+    }
+}
+
+fn push_type_arguments(mangled: &mut String, type_arguments: &[Types]) {
+    mangled.push('I');
+    for argument in type_arguments {
+        push_type(mangled, argument);
+    }
+    mangled.push('E');
+}
+
+fn push_type(mangled: &mut String, t: &Types) {
+    match t {
+        Types::I8 => mangled.push('a'),
+        Types::I16 => mangled.push('r'),
+        Types::I32 => mangled.push('i'),
+        Types::I64 => mangled.push('x'),
+        Types::Isize => mangled.push('l'),
+        Types::U8 => mangled.push('h'),
+        Types::U16 => mangled.push('t'),
+        Types::U32 => mangled.push('j'),
+        Types::U64 => mangled.push('y'),
+        Types::Usize => mangled.push('m'),
+        Types::Char => mangled.push('w'),
+        Types::F32 => mangled.push('f'),
+        Types::F64 => mangled.push('d'),
+        Types::Bool => mangled.push('b'),
+        Types::String => mangled.push('s'),
+        Types::Void => mangled.push('v'),
+        Types::Pointer(pointee) => {
+            mangled.push('P');
+            push_type(mangled, pointee);
+        }
+        Types::Struct(name) | Types::Param(name) | Types::Alias(name) => push_name(mangled, name),
+        Types::Dyn(name) => {
+            mangled.push('D');
+            push_name(mangled, name);
+        }
+        Types::Generic(name, arguments) => {
+            push_name(mangled, name);
+            push_type_arguments(mangled, arguments);
+        }
+        Types::Function(parameters, return_type) => {
+            mangled.push('F');
+            push_type(mangled, return_type);
+            for parameter in parameters {
+                push_type(mangled, parameter);
+            }
+            mangled.push('E');
+        }
+        Types::Option(value) => {
+            mangled.push('O');
+            push_type(mangled, value);
+        }
+        Types::Box(value) => {
+            mangled.push('B');
+            push_type(mangled, value);
+        }
+        Types::Result(value, error) => {
+            mangled.push('R');
+            push_type(mangled, value);
+            push_type(mangled, error);
+        }
+        Types::Tuple(elements) => {
+            mangled.push('T');
+            for element in elements {
+                push_type(mangled, element);
+            }
+            mangled.push('E');
+        }
+    }
+}
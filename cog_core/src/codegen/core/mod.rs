@@ -1,2 +1,4 @@
 pub mod expr;
+pub mod mangle;
 pub mod types;
+pub mod value;
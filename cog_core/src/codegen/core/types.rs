@@ -1,7 +1,8 @@
-use cog_parser::parser::core::types::Types;
+use cog_parser::parser::core::{signature::FunctionSignature, types::Types};
 use inkwell::{
     AddressSpace,
-    types::{self},
+    targets::TargetData,
+    types::{self, BasicType},
 };
 
 use crate::codegen::CodeGen;
@@ -9,14 +10,115 @@ use crate::codegen::CodeGen;
 impl<'ctx> CodeGen<'ctx> {
     pub fn get_llvm_type(&self, parser_type: &Types) -> types::BasicTypeEnum<'ctx> {
         match parser_type {
-            Types::I32 => self.llvm_ctx.i32_type().into(),
-            Types::I64 => self.llvm_ctx.i64_type().into(),
+            Types::I8 | Types::U8 => self.llvm_ctx.i8_type().into(),
+            Types::I16 | Types::U16 => self.llvm_ctx.i16_type().into(),
+            Types::I32 | Types::U32 | Types::Char => self.llvm_ctx.i32_type().into(),
+            Types::I64 | Types::U64 => self.llvm_ctx.i64_type().into(),
+            Types::Isize | Types::Usize => self
+                .llvm_ctx
+                .ptr_sized_int_type(&self.target_data(), None)
+                .into(),
             Types::F32 => self.llvm_ctx.f32_type().into(),
             Types::F64 => self.llvm_ctx.f64_type().into(),
             Types::Bool => self.llvm_ctx.bool_type().into(),
-            Types::Pointer(_) | Types::String => {
+            // Trait objects pair the data with a vtable, and function values
+            // pair the code with its environment.
+            Types::Pointer(pointee) if matches!(**pointee, Types::Dyn(_)) => {
+                self.pointer_pair_type().into()
+            }
+            Types::Function(..) => self.pointer_pair_type().into(),
+            Types::Tuple(elements) => {
+                let element_types: Vec<types::BasicTypeEnum<'ctx>> = elements
+                    .iter()
+                    .map(|element| self.get_llvm_type(element))
+                    .collect();
+                self.llvm_ctx.struct_type(&element_types, false).into()
+            }
+            // Both are a tag followed by the payload; the payloads of a
+            // `Result` share storage sized and aligned for the larger of the two.
+            Types::Option(value) => {
+                let tag_type = self.llvm_ctx.bool_type().into();
+                let value_type = self.get_llvm_type(value);
+                self.llvm_ctx
+                    .struct_type(&[tag_type, value_type], false)
+                    .into()
+            }
+            Types::Result(value, error) => {
+                let tag_type = self.llvm_ctx.bool_type().into();
+                let payload_type = self
+                    .union_type(&[self.get_llvm_type(value), self.get_llvm_type(error)])
+                    .into();
+                self.llvm_ctx
+                    .struct_type(&[tag_type, payload_type], false)
+                    .into()
+            }
+            Types::Pointer(_) | Types::Box(_) | Types::String => {
                 self.llvm_ctx.ptr_type(AddressSpace::default()).into()
             }
+            // `void` has no storage of its own; like C compilers, treat it as a byte.
+            Types::Void => self.llvm_ctx.i8_type().into(),
+            Types::Struct(name) => self
+                .llvm_ctx
+                .get_struct_type(name)
+                .unwrap_or_else(|| self.llvm_ctx.opaque_struct_type(name))
+                .into(),
+            Types::Generic(..) | Types::Param(_) | Types::Alias(_) => {
+                unreachable!("generic types and aliases are replaced before codegen")
+            }
+            Types::Dyn(_) => unreachable!("`dyn` types only exist behind pointers"),
+        }
+    }
+
+    /// Sizes and alignments as given by the module's data layout, which
+    /// `set_target` fills in.
+    fn target_data(&self) -> TargetData {
+        let data_layout = self.lvvm_module.get_data_layout();
+        TargetData::create(data_layout.as_str().to_str().unwrap_or_default())
+    }
+
+    /// An array of integers as wide as the strictest alignment among
+    /// `members`, with enough elements to hold the largest of them.
+    fn union_type(&self, members: &[types::BasicTypeEnum<'ctx>]) -> types::ArrayType<'ctx> {
+        let target_data = self.target_data();
+
+        let align = members
+            .iter()
+            .map(|member| target_data.get_abi_alignment(member))
+            .max()
+            .unwrap_or(1)
+            .max(1);
+        let size = members
+            .iter()
+            .map(|member| target_data.get_abi_size(member))
+            .max()
+            .unwrap_or(0);
+
+        self.llvm_ctx
+            .custom_width_int_type(align * 8)
+            .array_type(size.div_ceil(align as u64) as u32)
+    }
+
+    fn pointer_pair_type(&self) -> types::StructType<'ctx> {
+        let ptr_type = self.llvm_ctx.ptr_type(AddressSpace::default());
+        self.llvm_ctx
+            .struct_type(&[ptr_type.into(), ptr_type.into()], false)
+    }
+
+    pub fn get_llvm_fn_type(&self, signature: &FunctionSignature) -> types::FunctionType<'ctx> {
+        let params: Vec<types::BasicMetadataTypeEnum<'ctx>> = signature
+            .parameters
+            .iter()
+            .map(|param| self.get_llvm_type(param).into())
+            .collect();
+
+        match signature.return_type {
+            Types::Void => self
+                .llvm_ctx
+                .void_type()
+                .fn_type(&params, signature.is_variadic),
+            ref return_type => self
+                .get_llvm_type(return_type)
+                .fn_type(&params, signature.is_variadic),
         }
     }
 }
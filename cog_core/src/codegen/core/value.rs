@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+
+use cog_parser::parser::core::{expr::Expr, types::Types};
+use inkwell::values::{BasicValue, BasicValueEnum, PointerValue};
+
+/// An LLVM value paired with the Cog type it was produced from.
+#[derive(Debug, Clone)]
+pub struct TypedValue<'ctx> {
+    pub value: BasicValueEnum<'ctx>,
+    pub value_type: Types,
+}
+
+impl<'ctx> TypedValue<'ctx> {
+    pub fn new(value: impl BasicValue<'ctx>, value_type: Types) -> Self {
+        Self {
+            value: value.as_basic_value_enum(),
+            value_type,
+        }
+    }
+}
+
+/// A box held in a local variable. The box is freed when the variable's
+/// scope ends, unless it was moved out first; `flag` holds whether the
+/// variable still owns it.
+#[derive(Debug, Clone)]
+pub struct OwnedBox<'ctx> {
+    pub slot: PointerValue<'ctx>,
+    pub flag: PointerValue<'ctx>,
+    pub box_type: Types,
+}
+
+/// Work to do when a scope is left, whether by reaching its end or by a
+/// `return`. A scope's cleanups run latest first.
+#[derive(Debug, Clone)]
+pub enum Cleanup<'ctx> {
+    /// Frees a box its variable still owns.
+    Drop(OwnedBox<'ctx>),
+    /// A deferred expression, compiled with the variables that were visible
+    /// where it was deferred.
+    Defer(Expr, HashMap<String, (PointerValue<'ctx>, Types)>),
+}
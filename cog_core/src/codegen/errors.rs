@@ -1,10 +1,19 @@
 use std::fmt::{self};
 
 use cog_parser::parser::core::types::Types;
+use inkwell::builder::BuilderError;
 
 #[derive(PartialEq, Debug, Clone)]
 pub enum CodeGenError {
     UnknownType(Types),
+    UndefinedVariable(String),
+    UndefinedFunction(String),
+    MismatchedRuntimeFunction(String),
+    UndefinedField(String, String),
+    InvalidOperand(String),
+    NotAnLvalue(String),
+    ExpectedValue(String),
+    BuilderError(String),
     NotImplemented(String),
 }
 
@@ -15,3 +24,9 @@ impl fmt::Display for CodeGenError {
 }
 
 impl std::error::Error for CodeGenError {}
+
+impl From<BuilderError> for CodeGenError {
+    fn from(err: BuilderError) -> Self {
+        CodeGenError::BuilderError(err.to_string())
+    }
+}
@@ -0,0 +1,29 @@
+use inkwell::{intrinsics::Intrinsic, values::IntValue};
+
+use crate::codegen::{CodeGen, errors::CodeGenError};
+
+impl<'ctx> CodeGen<'ctx> {
+    /// Emits a branch to `llvm.trap` taken when `condition` is true, leaving the
+    /// builder positioned in the continuation block.
+    pub fn build_trap_if(&self, condition: IntValue<'ctx>) -> Result<(), CodeGenError> {
+        let func = self
+            .main_func
+            .ok_or_else(|| CodeGenError::NotImplemented("checks outside of a function".into()))?;
+
+        let trap_block = self.llvm_ctx.append_basic_block(func, "trap");
+        let cont_block = self.llvm_ctx.append_basic_block(func, "cont");
+        self.llvm_builder
+            .build_conditional_branch(condition, trap_block, cont_block)?;
+
+        self.llvm_builder.position_at_end(trap_block);
+        let trap = Intrinsic::find("llvm.trap")
+            .and_then(|intrinsic| intrinsic.get_declaration(&self.lvvm_module, &[]))
+            .ok_or_else(|| CodeGenError::NotImplemented("llvm.trap".into()))?;
+        self.llvm_builder.build_call(trap, &[], "")?;
+        self.llvm_builder.build_unreachable()?;
+
+        self.llvm_builder.position_at_end(cont_block);
+
+        Ok(())
+    }
+}
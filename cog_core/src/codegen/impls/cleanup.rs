@@ -0,0 +1,54 @@
+use cog_parser::parser::core::expr::Expr;
+
+use crate::codegen::{CodeGen, core::value::Cleanup, errors::CodeGenError};
+
+impl<'ctx> CodeGen<'ctx> {
+    /// Registers `expr` to run when the innermost scope is left.
+    pub fn compile_defer(&mut self, expr: &Expr) -> Result<(), CodeGenError> {
+        if let Some(scope) = self.cleanups.last_mut() {
+            scope.push(Cleanup::Defer(expr.clone(), self.variables.clone()));
+        }
+
+        Ok(())
+    }
+
+    pub fn enter_cleanup_scope(&mut self) {
+        self.cleanups.push(Vec::new());
+    }
+
+    /// Runs the cleanups of the innermost scope, unless the current block has
+    /// already ended.
+    pub fn exit_cleanup_scope(&mut self) -> Result<(), CodeGenError> {
+        let scope = self.cleanups.pop().unwrap_or_default();
+        if self.current_block_is_open() {
+            for cleanup in scope.iter().rev() {
+                self.build_cleanup(cleanup)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Runs the cleanups of every open scope of the current function, before
+    /// it returns.
+    pub fn build_cleanups_for_return(&mut self) -> Result<(), CodeGenError> {
+        for cleanup in self.cleanups.clone().iter().flatten().rev() {
+            self.build_cleanup(cleanup)?;
+        }
+
+        Ok(())
+    }
+
+    fn build_cleanup(&mut self, cleanup: &Cleanup<'ctx>) -> Result<(), CodeGenError> {
+        match cleanup {
+            Cleanup::Drop(owned) => self.build_drop(owned),
+            Cleanup::Defer(expr, variables) => {
+                let current = std::mem::replace(&mut self.variables, variables.clone());
+                let result = self.compile_expr(expr);
+                self.variables = current;
+
+                result.map(|_| ())
+            }
+        }
+    }
+}
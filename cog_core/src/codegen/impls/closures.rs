@@ -0,0 +1,230 @@
+use cog_parser::parser::core::{expr::Expr, signature::FunctionSignature, types::Types};
+use inkwell::{
+    AddressSpace,
+    module::Linkage,
+    types::StructType,
+    values::{FunctionValue, PointerValue},
+};
+
+use crate::codegen::{
+    CodeGen,
+    core::{mangle::mangle_thunk, value::TypedValue},
+    errors::CodeGenError,
+};
+
+/// The signature of the code behind a function value: it takes a pointer to
+/// its environment before the declared parameters.
+pub fn closure_signature(signature: &FunctionSignature) -> FunctionSignature {
+    let mut parameters = vec![Types::Pointer(Box::new(Types::Void))];
+    parameters.extend(signature.parameters.iter().cloned());
+
+    FunctionSignature {
+        parameters,
+        return_type: signature.return_type.clone(),
+        is_variadic: false,
+    }
+}
+
+impl<'ctx> CodeGen<'ctx> {
+    /// Compiles a closure into a function of its own and returns it paired
+    /// with its environment: a copy of each captured variable, stored in the
+    /// frame of the function creating the closure. The checker keeps a
+    /// capturing closure from outliving that frame.
+    pub fn compile_closure(
+        &mut self,
+        parameters: &[Expr],
+        return_type: &Option<Types>,
+        body: &Expr,
+        captures: &[String],
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let signature = FunctionSignature::new("", parameters, return_type, false);
+        let mut captured = Vec::with_capacity(captures.len());
+        for name in captures {
+            let (ptr, var_type) = self.lookup_variable(name)?;
+            captured.push((name, ptr, var_type));
+        }
+
+        let field_types: Vec<_> = captured
+            .iter()
+            .map(|(_, _, var_type)| self.get_llvm_type(var_type))
+            .collect();
+        let env_type = self.llvm_ctx.struct_type(&field_types, false);
+        let ptr_type = self.llvm_ctx.ptr_type(AddressSpace::default());
+
+        let env = if captured.is_empty() {
+            ptr_type.const_null()
+        } else {
+            let env = self.build_entry_alloca(env_type.into(), "env")?;
+            for (index, (name, ptr, var_type)) in captured.iter().enumerate() {
+                let value =
+                    self.llvm_builder
+                        .build_load(self.get_llvm_type(var_type), *ptr, name)?;
+                let field =
+                    self.llvm_builder
+                        .build_struct_gep(env_type, env, index as u32, name)?;
+                self.llvm_builder.build_store(field, value)?;
+            }
+            env
+        };
+
+        let fn_type = self.get_llvm_fn_type(&closure_signature(&signature));
+        let func = self
+            .lvvm_module
+            .add_function("closure", fn_type, Some(Linkage::Internal));
+
+        let outer_block = self.llvm_builder.get_insert_block();
+        let outer_func = self.main_func.replace(func);
+        let outer_return_type = self.return_type.replace(signature.return_type.clone());
+        let outer_variables = std::mem::take(&mut self.variables);
+        let outer_cleanups = std::mem::take(&mut self.cleanups);
+
+        let entry = self.llvm_ctx.append_basic_block(func, "entry");
+        self.llvm_builder.position_at_end(entry);
+        let result = self.compile_closure_body(func, env_type, &captured, parameters, body);
+
+        self.variables = outer_variables;
+        self.cleanups = outer_cleanups;
+        self.return_type = outer_return_type;
+        self.main_func = outer_func;
+        if let Some(block) = outer_block {
+            self.llvm_builder.position_at_end(block);
+        }
+        result?;
+
+        self.build_function_value(func, env, signature.function_type())
+    }
+
+    /// Emits the body of a closure into `func`. Captured variables are read
+    /// from the environment in place.
+    fn compile_closure_body(
+        &mut self,
+        func: FunctionValue<'ctx>,
+        env_type: StructType<'ctx>,
+        captured: &[(&String, PointerValue<'ctx>, Types)],
+        parameters: &[Expr],
+        body: &Expr,
+    ) -> Result<(), CodeGenError> {
+        let env = func
+            .get_nth_param(0)
+            .ok_or_else(|| CodeGenError::UndefinedVariable("env".into()))?
+            .into_pointer_value();
+        env.set_name("env");
+        for (index, (name, _, var_type)) in captured.iter().enumerate() {
+            let field = self
+                .llvm_builder
+                .build_struct_gep(env_type, env, index as u32, name)?;
+            self.variables
+                .insert(name.to_string(), (field, var_type.clone()));
+        }
+
+        self.enter_cleanup_scope();
+        for (index, param) in parameters.iter().enumerate() {
+            if let Expr::Declaration {
+                identifier,
+                var_type: Some(var_type),
+                ..
+            } = param
+            {
+                let value = func
+                    .get_nth_param(index as u32 + 1)
+                    .ok_or_else(|| CodeGenError::UndefinedVariable(identifier.clone()))?;
+                value.set_name(identifier);
+
+                let ptr = self.build_entry_alloca(self.get_llvm_type(var_type), identifier)?;
+                self.llvm_builder.build_store(ptr, value)?;
+                if let Types::Box(_) = var_type {
+                    self.declare_owned_box(ptr, var_type)?;
+                }
+                self.variables
+                    .insert(identifier.clone(), (ptr, var_type.clone()));
+            }
+        }
+
+        let return_type = self.return_type.clone().unwrap_or(Types::Void);
+        match (body, &return_type) {
+            (Expr::Block(_), _) | (_, Types::Void) => {
+                self.compile_body(body)?;
+            }
+            (_, return_type) => {
+                let value = self.compile_value_as(body, return_type)?;
+                self.build_cleanups_for_return()?;
+                self.llvm_builder.build_return(Some(&value.value))?;
+            }
+        }
+        self.exit_cleanup_scope()?;
+
+        self.build_implicit_return(&return_type, false)
+    }
+
+    /// A named function used as a value. It is paired with no environment,
+    /// and called through a thunk that takes and ignores one.
+    pub fn compile_function_value(&mut self, name: &str) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let (func, signature) = self
+            .functions
+            .get(name)
+            .cloned()
+            .ok_or_else(|| CodeGenError::UndefinedVariable(name.to_string()))?;
+
+        let thunk = self.get_thunk(name, func, &signature)?;
+        let env = self.llvm_ctx.ptr_type(AddressSpace::default()).const_null();
+
+        self.build_function_value(thunk, env, signature.function_type())
+    }
+
+    /// Returns the thunk for `func`, emitting it on first use.
+    fn get_thunk(
+        &mut self,
+        name: &str,
+        func: FunctionValue<'ctx>,
+        signature: &FunctionSignature,
+    ) -> Result<FunctionValue<'ctx>, CodeGenError> {
+        let thunk_name = mangle_thunk(name);
+        if let Some(thunk) = self.lvvm_module.get_function(&thunk_name) {
+            return Ok(thunk);
+        }
+
+        let fn_type = self.get_llvm_fn_type(&closure_signature(signature));
+        let thunk = self
+            .lvvm_module
+            .add_function(&thunk_name, fn_type, Some(Linkage::Internal));
+
+        let outer_block = self.llvm_builder.get_insert_block();
+        let entry = self.llvm_ctx.append_basic_block(thunk, "entry");
+        self.llvm_builder.position_at_end(entry);
+
+        let args: Vec<_> = thunk
+            .get_param_iter()
+            .skip(1)
+            .map(|param| param.into())
+            .collect();
+        let call = self.llvm_builder.build_call(func, &args, "call")?;
+        match call.try_as_basic_value().left() {
+            Some(value) => self.llvm_builder.build_return(Some(&value))?,
+            None => self.llvm_builder.build_return(None)?,
+        };
+
+        if let Some(block) = outer_block {
+            self.llvm_builder.position_at_end(block);
+        }
+
+        Ok(thunk)
+    }
+
+    fn build_function_value(
+        &mut self,
+        code: FunctionValue<'ctx>,
+        env: PointerValue<'ctx>,
+        function_type: Types,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let pair_type = self.get_llvm_type(&function_type).into_struct_type();
+        let pair = self.llvm_builder.build_insert_value(
+            pair_type.get_undef(),
+            code.as_global_value().as_pointer_value(),
+            0,
+            "fn",
+        )?;
+        let pair = self.llvm_builder.build_insert_value(pair, env, 1, "fn")?;
+
+        Ok(TypedValue::new(pair.into_struct_value(), function_type))
+    }
+}
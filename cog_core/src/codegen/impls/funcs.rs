@@ -1,59 +1,402 @@
-use cog_parser::parser::core::{expr::Expr, types::Types};
-use inkwell::types::BasicType;
+use cog_parser::parser::core::{
+    expr::Expr, nodes::Nodes, signature::FunctionSignature, types::Types,
+};
+use inkwell::{
+    module::Linkage,
+    types::FunctionType,
+    values::{BasicMetadataValueEnum, FunctionValue},
+};
 
-use crate::codegen::{CodeGen, errors::CodeGenError};
+use crate::codegen::{
+    CodeGen,
+    core::{mangle::mangle_name, value::TypedValue},
+    errors::CodeGenError,
+    impls::{closures::closure_signature, structs::method_name},
+};
 
 impl<'ctx> CodeGen<'ctx> {
+    /// Adds every top-level function to the module up front so calls may
+    /// precede declarations. Only `pub` functions and `main` are exported;
+    /// the rest get internal linkage, so unused ones can be dropped.
+    pub fn declare_functions(&mut self, statements: &[Expr]) {
+        for stmt in statements {
+            match stmt.item() {
+                Expr::FunctionDeclaration {
+                    identifier,
+                    parameters,
+                    return_type,
+                    ..
+                } => {
+                    let signature =
+                        FunctionSignature::new(identifier, parameters, return_type, false);
+                    let linkage = function_linkage(identifier, stmt.is_public());
+                    self.declare_function(identifier, signature, Some(linkage));
+                }
+                Expr::ExternFunctionDeclaration {
+                    identifier,
+                    parameters,
+                    return_type,
+                    is_variadic,
+                } => {
+                    let signature =
+                        FunctionSignature::new(identifier, parameters, return_type, *is_variadic);
+                    self.declare_function(identifier, signature, Some(Linkage::External));
+                }
+                Expr::ImplBlock { type_name, methods } => {
+                    self.declare_methods(type_name, None, methods)
+                }
+                Expr::TraitImpl {
+                    trait_name,
+                    type_name,
+                    methods,
+                } => self.declare_methods(type_name, Some(trait_name), methods),
+                _ => {}
+            }
+        }
+    }
+
+    /// Declares the methods of `impl Type`, or of `impl Trait for Type` when
+    /// `trait_name` is given.
+    fn declare_methods(&mut self, type_name: &str, trait_name: Option<&str>, methods: &[Expr]) {
+        for method in methods {
+            if let Expr::FunctionDeclaration {
+                identifier,
+                parameters,
+                return_type,
+                ..
+            } = method.item()
+            {
+                let signature = FunctionSignature::new(identifier, parameters, return_type, false);
+                let name = method_name(type_name, trait_name, identifier);
+                let linkage = function_linkage(&name, method.is_public());
+                self.declare_function(&name, signature, Some(linkage));
+            }
+        }
+    }
+
+    pub fn declare_function(
+        &mut self,
+        name: &str,
+        signature: FunctionSignature,
+        linkage: Option<Linkage>,
+    ) -> FunctionValue<'ctx> {
+        if let Some((func, _)) = self.functions.get(name) {
+            return *func;
+        }
+
+        let fn_type = self.get_llvm_fn_type(&signature);
+        let func = self
+            .lvvm_module
+            .add_function(&mangle_name(name), fn_type, linkage);
+        self.functions.insert(name.to_string(), (func, signature));
+
+        func
+    }
+
+    /// The libc function `name`, declared on first use unless the program
+    /// already declares it. The checker keeps programs from defining these
+    /// names, so an existing function is an extern declaration, which must
+    /// have the type the runtime calls it with.
+    pub fn libc_function(
+        &self,
+        name: &str,
+        fn_type: FunctionType<'ctx>,
+    ) -> Result<FunctionValue<'ctx>, CodeGenError> {
+        match self.lvvm_module.get_function(name) {
+            Some(func) if func.get_type() == fn_type => Ok(func),
+            Some(_) => Err(CodeGenError::MismatchedRuntimeFunction(name.to_string())),
+            None => Ok(self
+                .lvvm_module
+                .add_function(name, fn_type, Some(Linkage::External))),
+        }
+    }
+
     pub fn compile_func_decl(
         &mut self,
         name: &str,
-        _args: &[Expr],
+        args: &[Expr],
         body: &Expr,
         ret_type: &Option<Types>,
     ) -> Result<(), CodeGenError> {
-        let ret_type = if let Some(t) = ret_type {
-            self.get_llvm_type(t)
-        } else {
-            self.get_llvm_type(&Types::I32)
-        };
+        let signature = FunctionSignature::new(name, args, ret_type, false);
+        let ret_type = signature.return_type.clone();
+        let main_func = self.declare_function(name, signature, None);
 
-        let fn_type = ret_type.fn_type(&[], false); //TODO: Support var args and params later.
-
-        let main_func = self.lvvm_module.add_function(name, fn_type, None);
         let entry = self.llvm_ctx.append_basic_block(main_func, "entry");
         self.llvm_builder.position_at_end(entry);
         self.main_func = Some(main_func);
+        self.return_type = Some(ret_type.clone());
+
+        let outer_variables = std::mem::take(&mut self.variables);
+        let outer_cleanups = std::mem::take(&mut self.cleanups);
+        self.enter_cleanup_scope();
+
+        for (index, arg) in args.iter().enumerate() {
+            if let Expr::Declaration {
+                identifier,
+                var_type: Some(var_type),
+                ..
+            } = arg
+            {
+                let value = main_func
+                    .get_nth_param(index as u32)
+                    .ok_or_else(|| CodeGenError::UndefinedVariable(identifier.clone()))?;
+                value.set_name(identifier);
+
+                let ptr = self.build_entry_alloca(self.get_llvm_type(var_type), identifier)?;
+                self.llvm_builder.build_store(ptr, value)?;
+                if let Types::Box(_) = var_type {
+                    self.declare_owned_box(ptr, var_type)?;
+                }
+                self.variables
+                    .insert(identifier.clone(), (ptr, var_type.clone()));
+            }
+        }
 
         self.compile_body(body)?;
+        self.exit_cleanup_scope()?;
+        self.variables = outer_variables;
+        self.cleanups = outer_cleanups;
+        self.build_implicit_return(&ret_type, name == "main")
+    }
+
+    /// Falling off the end of `main` returns 0, as in C. The checker rejects
+    /// any other function that can fall off its end without a value, so there
+    /// the end is unreachable.
+    pub fn build_implicit_return(
+        &self,
+        ret_type: &Types,
+        is_main: bool,
+    ) -> Result<(), CodeGenError> {
+        if self.current_block_is_open() {
+            match ret_type {
+                Types::Void => self.llvm_builder.build_return(None)?,
+                _ if is_main => {
+                    let zero = self.get_llvm_type(ret_type).const_zero();
+                    self.llvm_builder.build_return(Some(&zero))?
+                }
+                _ => self.llvm_builder.build_unreachable()?,
+            };
+        }
 
         Ok(())
     }
 
+    pub fn compile_extern_decl(
+        &mut self,
+        name: &str,
+        args: &[Expr],
+        ret_type: &Option<Types>,
+        is_variadic: bool,
+    ) {
+        let signature = FunctionSignature::new(name, args, ret_type, is_variadic);
+        self.declare_function(name, signature, Some(Linkage::External));
+    }
+
+    pub fn compile_call(
+        &mut self,
+        callee: &Expr,
+        arguments: &[Expr],
+    ) -> Result<Option<TypedValue<'ctx>>, CodeGenError> {
+        let name = match callee {
+            Expr::Literal(Nodes::Identifier(name)) if !self.variables.contains_key(name) => {
+                name.clone()
+            }
+            Expr::Path(segments) => segments.join("::"),
+            _ => return self.compile_value_call(callee, arguments),
+        };
+        let name = match name.rsplit_once("::") {
+            Some((type_name, method))
+                if !self.functions.contains_key(&name) && self.structs.contains_key(type_name) =>
+            {
+                self.resolve_method(type_name, method)?
+            }
+            _ => name,
+        };
+
+        let (func, signature) = self
+            .functions
+            .get(&name)
+            .cloned()
+            .ok_or_else(|| CodeGenError::UndefinedFunction(name.clone()))?;
+
+        self.build_call_with(func, &signature, Vec::new(), arguments)
+    }
+
+    /// Emits a call through a function value: its code is called with its
+    /// environment followed by `arguments`.
+    fn compile_value_call(
+        &mut self,
+        callee: &Expr,
+        arguments: &[Expr],
+    ) -> Result<Option<TypedValue<'ctx>>, CodeGenError> {
+        let callee_value = self.compile_value(callee)?;
+        let Types::Function(parameters, return_type) = &callee_value.value_type else {
+            return Err(CodeGenError::InvalidOperand(format!(
+                "`{:?}` is not callable",
+                callee
+            )));
+        };
+        let signature = closure_signature(&FunctionSignature::from_function_type(
+            parameters,
+            return_type,
+        ));
+
+        let pair = callee_value.value.into_struct_value();
+        let code = self
+            .llvm_builder
+            .build_extract_value(pair, 0, "code")?
+            .into_pointer_value();
+        let env = self.llvm_builder.build_extract_value(pair, 1, "env")?;
+
+        let args = self.compile_arguments(&signature, vec![env.into()], arguments)?;
+        let call = self.llvm_builder.build_indirect_call(
+            self.get_llvm_fn_type(&signature),
+            code,
+            &args,
+            "call",
+        )?;
+
+        Ok(call
+            .try_as_basic_value()
+            .left()
+            .map(|value| TypedValue::new(value, signature.return_type.clone())))
+    }
+
+    /// Emits a direct call to `func` with `leading` followed by `arguments`.
+    pub fn build_call_with(
+        &mut self,
+        func: FunctionValue<'ctx>,
+        signature: &FunctionSignature,
+        leading: Vec<BasicMetadataValueEnum<'ctx>>,
+        arguments: &[Expr],
+    ) -> Result<Option<TypedValue<'ctx>>, CodeGenError> {
+        let args = self.compile_arguments(signature, leading, arguments)?;
+        let call = self.llvm_builder.build_call(func, &args, "call")?;
+
+        Ok(call
+            .try_as_basic_value()
+            .left()
+            .map(|value| TypedValue::new(value, signature.return_type.clone())))
+    }
+
+    /// Compiles `arguments` for the parameters of `signature` that follow the
+    /// already-compiled `leading` arguments.
+    pub fn compile_arguments(
+        &mut self,
+        signature: &FunctionSignature,
+        leading: Vec<BasicMetadataValueEnum<'ctx>>,
+        arguments: &[Expr],
+    ) -> Result<Vec<BasicMetadataValueEnum<'ctx>>, CodeGenError> {
+        let parameters = &signature.parameters[leading.len()..];
+
+        let mut args = leading;
+        for (index, argument) in arguments.iter().enumerate() {
+            let value = match parameters.get(index) {
+                Some(param_type) => self.compile_value_as(argument, param_type)?,
+                None => {
+                    let value = self.compile_value(argument)?;
+                    self.promote_variadic_argument(value)?
+                }
+            };
+            args.push(value.value.into());
+        }
+
+        Ok(args)
+    }
+
+    /// Applies C's default argument promotions to an argument passed through `...`:
+    /// floats widen to `double` and integers narrower than `int` widen to `int`.
+    fn promote_variadic_argument(
+        &self,
+        value: TypedValue<'ctx>,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        match value.value_type {
+            Types::F32 => {
+                let widened = self.llvm_builder.build_float_ext(
+                    value.value.into_float_value(),
+                    self.llvm_ctx.f64_type(),
+                    "vararg_promote",
+                )?;
+                Ok(TypedValue::new(widened, Types::F64))
+            }
+            Types::Bool | Types::I8 | Types::I16 | Types::U8 | Types::U16 => {
+                let widened = self.llvm_builder.build_int_cast_sign_flag(
+                    value.value.into_int_value(),
+                    self.llvm_ctx.i32_type(),
+                    value.value_type.is_signed(),
+                    "vararg_promote",
+                )?;
+                Ok(TypedValue::new(widened, Types::I32))
+            }
+            _ => Ok(value),
+        }
+    }
+
     pub fn compile_body(&mut self, body: &Expr) -> Result<(), CodeGenError> {
         match body {
-            Expr::Block(exprs) => {
-                for expr in exprs {
-                    self.compile_expr(expr)?;
-                }
+            Expr::Block(exprs) => self.compile_block(exprs)?,
+            _ => {
+                self.compile_expr(body)?;
             }
-            _ => self.compile_expr(body)?,
         }
 
         Ok(())
     }
 
-    pub fn build_return(&mut self, value: &Expr) -> Result<(), CodeGenError> {
-        if let Expr::Literal(val) = value {
-            if let cog_parser::parser::core::nodes::Nodes::Integer(v) = val {
-                let i32_val = self.llvm_ctx.i32_type().const_int(*v as u64, true);
-                let _ = self.llvm_builder.build_return(Some(&i32_val));
-            } else {
-                todo!();
+    pub fn compile_block(&mut self, exprs: &[Expr]) -> Result<(), CodeGenError> {
+        let outer_variables = self.variables.clone();
+        self.enter_cleanup_scope();
+
+        for expr in exprs {
+            self.compile_expr(expr)?;
+        }
+
+        self.exit_cleanup_scope()?;
+        self.variables = outer_variables;
+
+        Ok(())
+    }
+
+    pub fn build_return(&mut self, value: &Option<Box<Expr>>) -> Result<(), CodeGenError> {
+        let ret_type = self
+            .return_type
+            .clone()
+            .ok_or_else(|| CodeGenError::NotImplemented("return outside of a function".into()))?;
+
+        match value {
+            Some(value) => {
+                let value = self.compile_value_as(value, &ret_type)?;
+                self.build_cleanups_for_return()?;
+                self.llvm_builder.build_return(Some(&value.value))?;
             }
-        } else {
-            todo!();
+            None => {
+                self.build_cleanups_for_return()?;
+                self.llvm_builder.build_return(None)?;
+            }
+        }
+
+        // Statements after a `return` are dead, but still need a block to be emitted into.
+        if let Some(func) = self.main_func {
+            let after_return = self.llvm_ctx.append_basic_block(func, "after_return");
+            self.llvm_builder.position_at_end(after_return);
         }
 
         Ok(())
     }
+
+    /// Whether the builder is positioned in a block that has no terminator yet.
+    pub fn current_block_is_open(&self) -> bool {
+        self.llvm_builder
+            .get_insert_block()
+            .is_some_and(|block| block.get_terminator().is_none())
+    }
+}
+
+fn function_linkage(name: &str, public: bool) -> Linkage {
+    if public || name == "main" {
+        Linkage::External
+    } else {
+        Linkage::Internal
+    }
 }
@@ -0,0 +1,194 @@
+use cog_parser::parser::core::{expr::Expr, nodes::Nodes, types::Types};
+use inkwell::{AddressSpace, types::BasicType, values::PointerValue};
+
+use crate::codegen::{
+    CodeGen,
+    core::value::{Cleanup, OwnedBox, TypedValue},
+    errors::CodeGenError,
+};
+
+impl<'ctx> CodeGen<'ctx> {
+    /// Compiles `alloc<T>()` to a `malloc` of the size of `T`.
+    pub fn compile_alloc(&mut self, t: &Types) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let ptr = self.build_malloc(t)?;
+
+        Ok(TypedValue::new(ptr, Types::Pointer(Box::new(t.clone()))))
+    }
+
+    pub fn compile_free(&mut self, operand: &Expr) -> Result<(), CodeGenError> {
+        let pointer = self.compile_value(operand)?;
+        self.build_free(pointer.value.into_pointer_value())
+    }
+
+    /// Compiles `Box::new(value)`: the value is evaluated, then copied into
+    /// memory from `malloc`.
+    pub fn compile_box_new(
+        &mut self,
+        value: &Expr,
+        expected: Option<&Types>,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let value = match expected {
+            Some(Types::Box(expected)) => self.compile_value_as(value, expected)?,
+            _ => self.compile_value(value)?,
+        };
+
+        let ptr = self.build_malloc(&value.value_type)?;
+        self.llvm_builder.build_store(ptr, value.value)?;
+
+        Ok(TypedValue::new(ptr, Types::Box(Box::new(value.value_type))))
+    }
+
+    /// Makes the variable stored at `slot` the owner of the box in it.
+    pub fn declare_owned_box(
+        &mut self,
+        slot: PointerValue<'ctx>,
+        box_type: &Types,
+    ) -> Result<(), CodeGenError> {
+        let flag = self.build_entry_alloca(self.llvm_ctx.bool_type().into(), "owns")?;
+        self.llvm_builder
+            .build_store(flag, self.llvm_ctx.bool_type().const_int(1, false))?;
+
+        if let Some(scope) = self.cleanups.last_mut() {
+            scope.push(Cleanup::Drop(OwnedBox {
+                slot,
+                flag,
+                box_type: box_type.clone(),
+            }));
+        }
+
+        Ok(())
+    }
+
+    /// The owned box stored in the variable `expr` names, if any.
+    fn owned_box(&self, expr: &Expr) -> Option<OwnedBox<'ctx>> {
+        let Expr::Literal(Nodes::Identifier(name)) = expr else {
+            return None;
+        };
+        let (slot, _) = self.variables.get(name)?;
+
+        self.cleanups
+            .iter()
+            .flatten()
+            .rev()
+            .find_map(|cleanup| match cleanup {
+                Cleanup::Drop(owned) if owned.slot == *slot => Some(owned.clone()),
+                _ => None,
+            })
+    }
+
+    /// Clears the drop flag of a box variable used by value, as its new owner
+    /// frees it instead.
+    pub fn move_value(&self, expr: &Expr) -> Result<(), CodeGenError> {
+        if let Some(owned) = self.owned_box(expr) {
+            self.llvm_builder
+                .build_store(owned.flag, self.llvm_ctx.bool_type().const_int(0, false))?;
+        }
+
+        Ok(())
+    }
+
+    /// Stores a new box in a box variable, freeing the one it owned before.
+    /// Returns false if `target` is not a box variable.
+    pub fn assign_owned_box(
+        &mut self,
+        target: &Expr,
+        value: &TypedValue<'ctx>,
+    ) -> Result<bool, CodeGenError> {
+        let Some(owned) = self.owned_box(target) else {
+            return Ok(false);
+        };
+
+        self.build_drop(&owned)?;
+        self.llvm_builder.build_store(owned.slot, value.value)?;
+        self.llvm_builder
+            .build_store(owned.flag, self.llvm_ctx.bool_type().const_int(1, false))?;
+
+        Ok(true)
+    }
+
+    /// Frees the box in `owned` if its variable still owns it.
+    pub fn build_drop(&self, owned: &OwnedBox<'ctx>) -> Result<(), CodeGenError> {
+        let func = self
+            .main_func
+            .ok_or_else(|| CodeGenError::NotImplemented("boxes outside of a function".into()))?;
+
+        let flag = self
+            .llvm_builder
+            .build_load(self.llvm_ctx.bool_type(), owned.flag, "owns")?
+            .into_int_value();
+        let drop_block = self.llvm_ctx.append_basic_block(func, "drop");
+        let cont_block = self.llvm_ctx.append_basic_block(func, "drop_cont");
+        self.llvm_builder
+            .build_conditional_branch(flag, drop_block, cont_block)?;
+
+        self.llvm_builder.position_at_end(drop_block);
+        let ptr = self
+            .llvm_builder
+            .build_load(
+                self.llvm_ctx.ptr_type(AddressSpace::default()),
+                owned.slot,
+                "box",
+            )?
+            .into_pointer_value();
+        self.build_free_box(ptr, &owned.box_type)?;
+        self.llvm_builder.build_unconditional_branch(cont_block)?;
+
+        self.llvm_builder.position_at_end(cont_block);
+
+        Ok(())
+    }
+
+    /// Frees a box, and first the box inside it, if it holds one.
+    fn build_free_box(
+        &self,
+        ptr: PointerValue<'ctx>,
+        box_type: &Types,
+    ) -> Result<(), CodeGenError> {
+        if let Types::Box(inner) = box_type
+            && matches!(inner.as_ref(), Types::Box(_))
+        {
+            let inner_ptr = self
+                .llvm_builder
+                .build_load(
+                    self.llvm_ctx.ptr_type(AddressSpace::default()),
+                    ptr,
+                    "inner",
+                )?
+                .into_pointer_value();
+            self.build_free_box(inner_ptr, inner)?;
+        }
+
+        self.build_free(ptr)
+    }
+
+    fn build_malloc(&self, t: &Types) -> Result<PointerValue<'ctx>, CodeGenError> {
+        let size_type = self.get_llvm_type(&Types::Usize).into_int_type();
+        let ptr_type = self.llvm_ctx.ptr_type(AddressSpace::default());
+        let malloc = self.libc_function("malloc", ptr_type.fn_type(&[size_type.into()], false))?;
+
+        let size = self
+            .get_llvm_type(t)
+            .size_of()
+            .ok_or_else(|| CodeGenError::UnknownType(t.clone()))?;
+        let size = self.llvm_builder.build_int_cast(size, size_type, "size")?;
+        let ptr = self
+            .llvm_builder
+            .build_call(malloc, &[size.into()], "heap")?
+            .try_as_basic_value()
+            .left()
+            .ok_or_else(|| CodeGenError::ExpectedValue("malloc".into()))?;
+
+        Ok(ptr.into_pointer_value())
+    }
+
+    fn build_free(&self, ptr: PointerValue<'ctx>) -> Result<(), CodeGenError> {
+        let ptr_type = self.llvm_ctx.ptr_type(AddressSpace::default());
+        let free = self.libc_function(
+            "free",
+            self.llvm_ctx.void_type().fn_type(&[ptr_type.into()], false),
+        )?;
+        self.llvm_builder.build_call(free, &[ptr.into()], "")?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use cog_parser::parser::core::{expr::Expr, types::Types};
+
+use crate::codegen::{CodeGen, errors::CodeGenError};
+
+impl<'ctx> CodeGen<'ctx> {
+    /// Lowers `if`/`else` to a conditional branch. `else if` chains arrive as a
+    /// nested `IfElse` in the else branch and are lowered recursively.
+    pub fn compile_if_else(
+        &mut self,
+        condition: &Expr,
+        then_branch: &Expr,
+        else_branch: &Option<Box<Expr>>,
+    ) -> Result<(), CodeGenError> {
+        let func = self
+            .main_func
+            .ok_or_else(|| CodeGenError::NotImplemented("if-else outside of a function".into()))?;
+
+        let condition = self
+            .compile_value_as(condition, &Types::Bool)?
+            .value
+            .into_int_value();
+
+        let then_block = self.llvm_ctx.append_basic_block(func, "then");
+        let else_block = else_branch
+            .as_ref()
+            .map(|_| self.llvm_ctx.append_basic_block(func, "else"));
+        let merge_block = self.llvm_ctx.append_basic_block(func, "if_merge");
+
+        self.llvm_builder.build_conditional_branch(
+            condition,
+            then_block,
+            else_block.unwrap_or(merge_block),
+        )?;
+
+        self.llvm_builder.position_at_end(then_block);
+        self.compile_expr(then_branch)?;
+        if self.current_block_is_open() {
+            self.llvm_builder.build_unconditional_branch(merge_block)?;
+        }
+
+        if let (Some(else_branch), Some(else_block)) = (else_branch, else_block) {
+            self.llvm_builder.position_at_end(else_block);
+            self.compile_expr(else_branch)?;
+            if self.current_block_is_open() {
+                self.llvm_builder.build_unconditional_branch(merge_block)?;
+            }
+        }
+
+        self.llvm_builder.position_at_end(merge_block);
+
+        Ok(())
+    }
+}
@@ -1 +1,15 @@
+pub mod checks;
+pub mod cleanup;
+pub mod closures;
 pub mod funcs;
+pub mod heap;
+pub mod if_else;
+pub mod operators;
+pub mod ops;
+pub mod pointer_ops;
+pub mod print;
+pub mod statics;
+pub mod structs;
+pub mod traits;
+pub mod variants;
+pub mod vars;
@@ -0,0 +1,92 @@
+use cog_parser::parser::core::{
+    ops::{BinaryOp, UnaryOp},
+    types::Types,
+};
+use inkwell::{
+    IntPredicate,
+    values::{BasicMetadataValueEnum, BasicValueEnum},
+};
+
+use crate::{
+    check::core::operators::OperatorTrait,
+    codegen::{
+        CodeGen,
+        core::{mangle::trait_method_name, value::TypedValue},
+        errors::CodeGenError,
+    },
+};
+
+impl<'ctx> CodeGen<'ctx> {
+    /// Lowers a binary operator on structs to a call to the method of its
+    /// operator trait. `!=` negates `eq`, and the orderings compare the result
+    /// of `cmp` with zero.
+    pub fn build_binary_overload(
+        &self,
+        lhs: TypedValue<'ctx>,
+        operator: &BinaryOp,
+        rhs: TypedValue<'ctx>,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let operand_type = lhs.value_type.clone();
+        let op = OperatorTrait::binary(operator).ok_or_else(|| {
+            CodeGenError::InvalidOperand(format!("`{:?}` on `{:?}`", operator, operand_type))
+        })?;
+        let result =
+            self.build_operator_call(&operand_type, op, &[lhs.value.into(), rhs.value.into()])?;
+
+        let predicate = match operator {
+            BinaryOp::Equal => return Ok(TypedValue::new(result, Types::Bool)),
+            BinaryOp::NotEqual => {
+                let value = self.llvm_builder.build_not(result.into_int_value(), "ne")?;
+                return Ok(TypedValue::new(value, Types::Bool));
+            }
+            BinaryOp::Less => IntPredicate::SLT,
+            BinaryOp::LessEqual => IntPredicate::SLE,
+            BinaryOp::Greater => IntPredicate::SGT,
+            BinaryOp::GreaterEqual => IntPredicate::SGE,
+            _ => return Ok(TypedValue::new(result, operand_type)),
+        };
+
+        let ordering = result.into_int_value();
+        self.build_int_cmp(predicate, ordering, ordering.get_type().const_zero())
+    }
+
+    pub fn build_unary_overload(
+        &self,
+        operator: &UnaryOp,
+        value: TypedValue<'ctx>,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let op = OperatorTrait::unary(operator).ok_or_else(|| {
+            CodeGenError::InvalidOperand(format!("`{:?}` on `{:?}`", operator, value.value_type))
+        })?;
+        let result = self.build_operator_call(&value.value_type, op, &[value.value.into()])?;
+
+        Ok(TypedValue::new(result, value.value_type))
+    }
+
+    /// Calls the implementation of `op` for `operand_type`.
+    fn build_operator_call(
+        &self,
+        operand_type: &Types,
+        op: OperatorTrait,
+        args: &[BasicMetadataValueEnum<'ctx>],
+    ) -> Result<BasicValueEnum<'ctx>, CodeGenError> {
+        let Types::Struct(type_name) = operand_type else {
+            return Err(CodeGenError::InvalidOperand(format!(
+                "`{}` on `{:?}`",
+                op.name, operand_type
+            )));
+        };
+
+        let name = trait_method_name(type_name, op.name, op.method);
+        let (func, _) = self
+            .functions
+            .get(&name)
+            .ok_or_else(|| CodeGenError::UndefinedFunction(name.clone()))?;
+
+        self.llvm_builder
+            .build_call(*func, args, op.method)?
+            .try_as_basic_value()
+            .left()
+            .ok_or(CodeGenError::ExpectedValue(name))
+    }
+}
@@ -0,0 +1,367 @@
+use cog_parser::parser::core::{
+    expr::Expr,
+    ops::{BinaryOp, UnaryOp},
+    types::Types,
+};
+use inkwell::{FloatPredicate, IntPredicate, values::BasicValueEnum};
+
+use crate::codegen::{CodeGen, core::value::TypedValue, errors::CodeGenError};
+
+impl<'ctx> CodeGen<'ctx> {
+    pub fn compile_binary(
+        &mut self,
+        left: &Expr,
+        operator: &BinaryOp,
+        right: &Expr,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        if matches!(operator, BinaryOp::And | BinaryOp::Or) {
+            return self.compile_logical(left, operator, right);
+        }
+
+        let (lhs, rhs) = if operator.is_shift() {
+            let lhs = self.compile_value(left)?;
+            let rhs = self.compile_value_as(right, &lhs.value_type)?;
+            (lhs, rhs)
+        } else {
+            self.compile_operands(left, operator, right)?
+        };
+
+        self.build_binary(lhs, operator, rhs)
+    }
+
+    /// Applies a non-short-circuiting binary operator to two values of the same type.
+    pub fn build_binary(
+        &self,
+        lhs: TypedValue<'ctx>,
+        operator: &BinaryOp,
+        rhs: TypedValue<'ctx>,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let operand_type = lhs.value_type.clone();
+
+        if matches!(operand_type, Types::Struct(_)) {
+            return self.build_binary_overload(lhs, operator, rhs);
+        }
+
+        if operator.is_shift() {
+            return self.build_shift(lhs, operator, rhs);
+        }
+
+        if operand_type.is_float() {
+            return self.build_float_binary(lhs.value, operator, rhs.value, operand_type);
+        }
+
+        if matches!(operand_type, Types::Pointer(_)) {
+            return self.build_pointer_binary(lhs, operator, rhs);
+        }
+
+        let (l, r) = match (lhs.value, rhs.value) {
+            (BasicValueEnum::IntValue(l), BasicValueEnum::IntValue(r)) => (l, r),
+            _ => {
+                return Err(CodeGenError::InvalidOperand(format!(
+                    "`{:?}` on `{:?}`",
+                    operator, operand_type
+                )));
+            }
+        };
+
+        let signed = operand_type.is_signed();
+        let builder = &self.llvm_builder;
+        let value = match operator {
+            BinaryOp::Add => builder.build_int_add(l, r, "add")?,
+            BinaryOp::Subtract => builder.build_int_sub(l, r, "sub")?,
+            BinaryOp::Multiply => builder.build_int_mul(l, r, "mul")?,
+            BinaryOp::Divide if signed => builder.build_int_signed_div(l, r, "div")?,
+            BinaryOp::Divide => builder.build_int_unsigned_div(l, r, "div")?,
+            BinaryOp::Modulo if signed => builder.build_int_signed_rem(l, r, "rem")?,
+            BinaryOp::Modulo => builder.build_int_unsigned_rem(l, r, "rem")?,
+            BinaryOp::Equal => return self.build_int_cmp(IntPredicate::EQ, l, r),
+            BinaryOp::NotEqual => return self.build_int_cmp(IntPredicate::NE, l, r),
+            BinaryOp::Less if signed => return self.build_int_cmp(IntPredicate::SLT, l, r),
+            BinaryOp::Less => return self.build_int_cmp(IntPredicate::ULT, l, r),
+            BinaryOp::LessEqual if signed => return self.build_int_cmp(IntPredicate::SLE, l, r),
+            BinaryOp::LessEqual => return self.build_int_cmp(IntPredicate::ULE, l, r),
+            BinaryOp::Greater if signed => return self.build_int_cmp(IntPredicate::SGT, l, r),
+            BinaryOp::Greater => return self.build_int_cmp(IntPredicate::UGT, l, r),
+            BinaryOp::GreaterEqual if signed => {
+                return self.build_int_cmp(IntPredicate::SGE, l, r);
+            }
+            BinaryOp::GreaterEqual => return self.build_int_cmp(IntPredicate::UGE, l, r),
+            BinaryOp::And | BinaryOp::BitAnd => builder.build_and(l, r, "and")?,
+            BinaryOp::Or | BinaryOp::BitOr => builder.build_or(l, r, "or")?,
+            BinaryOp::BitXor => builder.build_xor(l, r, "xor")?,
+            BinaryOp::ShiftLeft | BinaryOp::ShiftRight => unreachable!("handled by build_shift"),
+        };
+
+        Ok(TypedValue::new(value, operand_type))
+    }
+
+    /// Compiles both operands of a binary expression. A literal operand takes the
+    /// type of the other side, except for the offset added to a pointer.
+    fn compile_operands(
+        &mut self,
+        left: &Expr,
+        operator: &BinaryOp,
+        right: &Expr,
+    ) -> Result<(TypedValue<'ctx>, TypedValue<'ctx>), CodeGenError> {
+        if left.is_literal_tree() {
+            let rhs = self.compile_value(right)?;
+            let lhs = self.compile_value_as(left, &rhs.value_type)?;
+            Ok((lhs, rhs))
+        } else {
+            let lhs = self.compile_value(left)?;
+            let rhs = match &lhs.value_type {
+                Types::Pointer(_) if operator.is_offset() => self.compile_offset(right)?,
+                operand_type => self.compile_value_as(right, operand_type)?,
+            };
+            Ok((lhs, rhs))
+        }
+    }
+
+    pub fn build_int_cmp(
+        &self,
+        predicate: IntPredicate,
+        l: inkwell::values::IntValue<'ctx>,
+        r: inkwell::values::IntValue<'ctx>,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let value = self
+            .llvm_builder
+            .build_int_compare(predicate, l, r, "cmp")?;
+        Ok(TypedValue::new(value, Types::Bool))
+    }
+
+    fn build_float_binary(
+        &self,
+        lhs: BasicValueEnum<'ctx>,
+        operator: &BinaryOp,
+        rhs: BasicValueEnum<'ctx>,
+        operand_type: Types,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let (l, r) = (lhs.into_float_value(), rhs.into_float_value());
+        let builder = &self.llvm_builder;
+
+        let predicate = match operator {
+            BinaryOp::Add => {
+                return Ok(TypedValue::new(
+                    builder.build_float_add(l, r, "fadd")?,
+                    operand_type,
+                ));
+            }
+            BinaryOp::Subtract => {
+                return Ok(TypedValue::new(
+                    builder.build_float_sub(l, r, "fsub")?,
+                    operand_type,
+                ));
+            }
+            BinaryOp::Multiply => {
+                return Ok(TypedValue::new(
+                    builder.build_float_mul(l, r, "fmul")?,
+                    operand_type,
+                ));
+            }
+            BinaryOp::Divide => {
+                return Ok(TypedValue::new(
+                    builder.build_float_div(l, r, "fdiv")?,
+                    operand_type,
+                ));
+            }
+            BinaryOp::Modulo => {
+                return Ok(TypedValue::new(
+                    builder.build_float_rem(l, r, "frem")?,
+                    operand_type,
+                ));
+            }
+            BinaryOp::Equal => FloatPredicate::OEQ,
+            BinaryOp::NotEqual => FloatPredicate::UNE,
+            BinaryOp::Less => FloatPredicate::OLT,
+            BinaryOp::LessEqual => FloatPredicate::OLE,
+            BinaryOp::Greater => FloatPredicate::OGT,
+            BinaryOp::GreaterEqual => FloatPredicate::OGE,
+            BinaryOp::And
+            | BinaryOp::Or
+            | BinaryOp::BitAnd
+            | BinaryOp::BitOr
+            | BinaryOp::BitXor
+            | BinaryOp::ShiftLeft
+            | BinaryOp::ShiftRight => {
+                return Err(CodeGenError::InvalidOperand(format!(
+                    "`{:?}` on `{:?}`",
+                    operator, operand_type
+                )));
+            }
+        };
+
+        let value = builder.build_float_compare(predicate, l, r, "fcmp")?;
+        Ok(TypedValue::new(value, Types::Bool))
+    }
+
+    /// Shifts use an arithmetic right shift for signed values and a logical one
+    /// otherwise. The amount may be any integer type; in debug builds an amount
+    /// of at least the bit width of the shifted value traps.
+    fn build_shift(
+        &self,
+        lhs: TypedValue<'ctx>,
+        operator: &BinaryOp,
+        rhs: TypedValue<'ctx>,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let (l, r) = (lhs.value.into_int_value(), rhs.value.into_int_value());
+
+        if self.debug_checks {
+            let width = r
+                .get_type()
+                .const_int(l.get_type().get_bit_width() as u64, false);
+            let overflow = self.llvm_builder.build_int_compare(
+                IntPredicate::UGE,
+                r,
+                width,
+                "shift_overflow",
+            )?;
+            self.build_trap_if(overflow)?;
+        }
+
+        let amount =
+            self.llvm_builder
+                .build_int_cast_sign_flag(r, l.get_type(), false, "shift_amount")?;
+
+        let value = match operator {
+            BinaryOp::ShiftLeft => self.llvm_builder.build_left_shift(l, amount, "shl")?,
+            _ => {
+                self.llvm_builder
+                    .build_right_shift(l, amount, lhs.value_type.is_signed(), "shr")?
+            }
+        };
+
+        Ok(TypedValue::new(value, lhs.value_type))
+    }
+
+    /// Compiles `&&` and `||` with short-circuit evaluation of the right operand.
+    fn compile_logical(
+        &mut self,
+        left: &Expr,
+        operator: &BinaryOp,
+        right: &Expr,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let func = self.main_func.ok_or_else(|| {
+            CodeGenError::NotImplemented("expressions outside of a function".into())
+        })?;
+
+        let lhs = self.compile_value(left)?.value.into_int_value();
+        let lhs_block = self.llvm_builder.get_insert_block().unwrap();
+
+        let rhs_block = self.llvm_ctx.append_basic_block(func, "logic_rhs");
+        let merge_block = self.llvm_ctx.append_basic_block(func, "logic_merge");
+
+        let short_circuit = match operator {
+            BinaryOp::And => {
+                self.llvm_builder
+                    .build_conditional_branch(lhs, rhs_block, merge_block)?;
+                self.llvm_ctx.bool_type().const_zero()
+            }
+            _ => {
+                self.llvm_builder
+                    .build_conditional_branch(lhs, merge_block, rhs_block)?;
+                self.llvm_ctx.bool_type().const_all_ones()
+            }
+        };
+
+        self.llvm_builder.position_at_end(rhs_block);
+        let rhs = self.compile_value(right)?.value.into_int_value();
+        let rhs_end_block = self.llvm_builder.get_insert_block().unwrap();
+        self.llvm_builder.build_unconditional_branch(merge_block)?;
+
+        self.llvm_builder.position_at_end(merge_block);
+        let phi = self
+            .llvm_builder
+            .build_phi(self.llvm_ctx.bool_type(), "logic")?;
+        phi.add_incoming(&[(&short_circuit, lhs_block), (&rhs, rhs_end_block)]);
+
+        Ok(TypedValue::new(phi.as_basic_value(), Types::Bool))
+    }
+
+    pub fn build_neg(&self, value: TypedValue<'ctx>) -> Result<TypedValue<'ctx>, CodeGenError> {
+        if matches!(value.value_type, Types::Struct(_)) {
+            return self.build_unary_overload(&UnaryOp::Minus, value);
+        }
+
+        let negated: BasicValueEnum = match value.value {
+            BasicValueEnum::IntValue(v) => self.llvm_builder.build_int_neg(v, "neg")?.into(),
+            BasicValueEnum::FloatValue(v) => self.llvm_builder.build_float_neg(v, "fneg")?.into(),
+            _ => {
+                return Err(CodeGenError::InvalidOperand(format!(
+                    "`-` on `{:?}`",
+                    value.value_type
+                )));
+            }
+        };
+        Ok(TypedValue::new(negated, value.value_type))
+    }
+
+    pub fn build_not(&self, value: TypedValue<'ctx>) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let inverted = self
+            .llvm_builder
+            .build_not(value.value.into_int_value(), "not")?;
+        Ok(TypedValue::new(inverted, value.value_type))
+    }
+
+    pub fn compile_unary(
+        &mut self,
+        operator: &UnaryOp,
+        operand: &Expr,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        match operator {
+            UnaryOp::PreIncrement => self.compile_step(operand, true, false),
+            UnaryOp::PreDecrement => self.compile_step(operand, false, false),
+            UnaryOp::PostIncrement => self.compile_step(operand, true, true),
+            UnaryOp::PostDecrement => self.compile_step(operand, false, true),
+            UnaryOp::Minus => {
+                let value = self.compile_value(operand)?;
+                self.build_neg(value)
+            }
+            UnaryOp::Not | UnaryOp::BitNot => {
+                let value = self.compile_value(operand)?;
+                self.build_not(value)
+            }
+        }
+    }
+
+    /// Lowers `++`/`--` as load, add or subtract one, store. Postfix forms
+    /// evaluate to the value before the update, prefix forms to the value after.
+    fn compile_step(
+        &mut self,
+        operand: &Expr,
+        increment: bool,
+        postfix: bool,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let (ptr, value_type) = self.compile_place(operand)?;
+        let llvm_type = self.get_llvm_type(&value_type);
+        let old = self.llvm_builder.build_load(llvm_type, ptr, "old")?;
+
+        let new: BasicValueEnum = match old {
+            BasicValueEnum::IntValue(v) => {
+                let one = v.get_type().const_int(1, false);
+                if increment {
+                    self.llvm_builder.build_int_add(v, one, "inc")?.into()
+                } else {
+                    self.llvm_builder.build_int_sub(v, one, "dec")?.into()
+                }
+            }
+            BasicValueEnum::FloatValue(v) => {
+                let one = v.get_type().const_float(1.0);
+                if increment {
+                    self.llvm_builder.build_float_add(v, one, "inc")?.into()
+                } else {
+                    self.llvm_builder.build_float_sub(v, one, "dec")?.into()
+                }
+            }
+            _ => {
+                return Err(CodeGenError::InvalidOperand(format!(
+                    "cannot step `{:?}`",
+                    value_type
+                )));
+            }
+        };
+
+        self.llvm_builder.build_store(ptr, new)?;
+
+        Ok(TypedValue::new(if postfix { old } else { new }, value_type))
+    }
+}
@@ -0,0 +1,145 @@
+use cog_parser::parser::core::{expr::Expr, nodes::Nodes, ops::BinaryOp, types::Types};
+use inkwell::{
+    IntPredicate,
+    values::{BasicValueEnum, PointerValue},
+};
+
+use crate::codegen::{CodeGen, core::value::TypedValue, errors::CodeGenError};
+
+impl<'ctx> CodeGen<'ctx> {
+    pub fn compile_address_of(&mut self, operand: &Expr) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let (ptr, pointee) = self.compile_place(operand)?;
+
+        Ok(TypedValue::new(ptr, Types::Pointer(Box::new(pointee))))
+    }
+
+    pub fn compile_dereference(
+        &mut self,
+        operand: &Expr,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let (ptr, pointee) = self.compile_pointee(operand)?;
+        let value = self
+            .llvm_builder
+            .build_load(self.get_llvm_type(&pointee), ptr, "deref")?;
+
+        Ok(TypedValue::new(value, pointee))
+    }
+
+    /// Compiles a pointer-typed operand, returning the address it holds and the
+    /// type it points to.
+    pub fn compile_pointee(
+        &mut self,
+        operand: &Expr,
+    ) -> Result<(PointerValue<'ctx>, Types), CodeGenError> {
+        let pointer = self.compile_value(operand)?;
+
+        match pointer.value_type {
+            Types::Pointer(pointee) | Types::Box(pointee) => {
+                Ok((pointer.value.into_pointer_value(), *pointee))
+            }
+            other => Err(CodeGenError::InvalidOperand(format!(
+                "cannot dereference `{:?}`",
+                other
+            ))),
+        }
+    }
+
+    /// Compiles the right operand of `ptr + n` or `ptr - n`, which is either
+    /// an integer of any type or, for a difference, another pointer. An integer
+    /// literal is taken as an `isize`.
+    pub fn compile_offset(&mut self, offset: &Expr) -> Result<TypedValue<'ctx>, CodeGenError> {
+        if matches!(offset, Expr::Literal(Nodes::Integer(_))) {
+            self.compile_value_as(offset, &Types::Isize)
+        } else {
+            self.compile_value(offset)
+        }
+    }
+
+    /// A cast keeps the address and only changes the type it is read as.
+    pub fn compile_cast(
+        &mut self,
+        value: &Expr,
+        target: &Types,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let value = self.compile_value(value)?;
+
+        Ok(TypedValue::new(value.value, target.clone()))
+    }
+
+    /// Offsets, differences and comparisons of pointers. Offsets and
+    /// differences count in elements of the pointee type, and pointers are
+    /// ordered by address.
+    pub fn build_pointer_binary(
+        &self,
+        lhs: TypedValue<'ctx>,
+        operator: &BinaryOp,
+        rhs: TypedValue<'ctx>,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let Types::Pointer(pointee) = &lhs.value_type else {
+            return Err(CodeGenError::InvalidOperand(format!(
+                "`{:?}` is not a pointer",
+                lhs.value_type
+            )));
+        };
+        let l = lhs.value.into_pointer_value();
+        let isize_type = self.get_llvm_type(&Types::Isize).into_int_type();
+
+        match (operator, rhs.value) {
+            (BinaryOp::Add | BinaryOp::Subtract, BasicValueEnum::IntValue(n)) => {
+                let index = self.llvm_builder.build_int_cast_sign_flag(
+                    n,
+                    isize_type,
+                    rhs.value_type.is_signed(),
+                    "index",
+                )?;
+                let index = if *operator == BinaryOp::Subtract {
+                    self.llvm_builder.build_int_neg(index, "index")?
+                } else {
+                    index
+                };
+                // SAFETY: the checker only allows offsets inside `unsafe`
+                // blocks, and computing the address does not access memory.
+                let ptr = unsafe {
+                    self.llvm_builder.build_gep(
+                        self.get_llvm_type(pointee),
+                        l,
+                        &[index],
+                        "offset",
+                    )?
+                };
+                Ok(TypedValue::new(ptr, lhs.value_type))
+            }
+            (BinaryOp::Subtract, BasicValueEnum::PointerValue(r)) => {
+                let diff =
+                    self.llvm_builder
+                        .build_ptr_diff(self.get_llvm_type(pointee), l, r, "diff")?;
+                let diff = self.llvm_builder.build_int_cast(diff, isize_type, "diff")?;
+                Ok(TypedValue::new(diff, Types::Isize))
+            }
+            (_, BasicValueEnum::PointerValue(r)) => {
+                let predicate = match operator {
+                    BinaryOp::Equal => IntPredicate::EQ,
+                    BinaryOp::NotEqual => IntPredicate::NE,
+                    BinaryOp::Less => IntPredicate::ULT,
+                    BinaryOp::LessEqual => IntPredicate::ULE,
+                    BinaryOp::Greater => IntPredicate::UGT,
+                    BinaryOp::GreaterEqual => IntPredicate::UGE,
+                    _ => {
+                        return Err(CodeGenError::InvalidOperand(format!(
+                            "`{:?}` on `{:?}`",
+                            operator, lhs.value_type
+                        )));
+                    }
+                };
+                let value = self
+                    .llvm_builder
+                    .build_int_compare(predicate, l, r, "cmp")?;
+                Ok(TypedValue::new(value, Types::Bool))
+            }
+            _ => Err(CodeGenError::InvalidOperand(format!(
+                "`{:?}` on `{:?}`",
+                operator, lhs.value_type
+            ))),
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use cog_parser::parser::core::{expr::Expr, types::Types};
+use inkwell::{
+    AddressSpace, IntPredicate,
+    values::{BasicMetadataValueEnum, IntValue},
+};
+
+use crate::codegen::{
+    CodeGen,
+    core::{mangle::display_name, value::TypedValue},
+    errors::CodeGenError,
+};
+
+/// The file descriptor `print` and `println` write to.
+const STDOUT: u64 = 1;
+
+impl<'ctx> CodeGen<'ctx> {
+    /// Compiles `print` and `println`. All arguments are evaluated first; the
+    /// text around the placeholders is then written with libc's `write`, and
+    /// each value is formatted according to its type.
+    pub fn compile_print(
+        &mut self,
+        pieces: &[String],
+        arguments: &[Expr],
+        newline: bool,
+    ) -> Result<(), CodeGenError> {
+        let mut values = Vec::with_capacity(arguments.len());
+        for argument in arguments {
+            values.push(self.compile_value(argument)?);
+        }
+
+        for (piece, value) in pieces.iter().zip(values) {
+            self.build_write_text(piece)?;
+            self.build_print_value(value)?;
+        }
+
+        let last = pieces.last().map(String::as_str).unwrap_or_default();
+        if newline {
+            self.build_write_text(&format!("{}\n", last))
+        } else {
+            self.build_write_text(last)
+        }
+    }
+
+    /// Formats a value the way it would be written in source: structs as
+    /// `Point { x: 1, y: 2 }`, tuples as `(1, true)` and variants as
+    /// `Some(1)`. Pointers and function values print their address.
+    fn build_print_value(&mut self, value: TypedValue<'ctx>) -> Result<(), CodeGenError> {
+        match value.value_type.clone() {
+            int_type if int_type.is_integer() => {
+                let wide = self.llvm_builder.build_int_cast_sign_flag(
+                    value.value.into_int_value(),
+                    self.llvm_ctx.i64_type(),
+                    int_type.is_signed(),
+                    "print_int",
+                )?;
+                let spec = if int_type.is_signed() { "%lld" } else { "%llu" };
+                self.build_write_formatted(spec, &[wide.into()])
+            }
+            // Floats print with enough digits to read back as the same value.
+            float_type @ (Types::F32 | Types::F64) => {
+                let wide = self.llvm_builder.build_float_cast(
+                    value.value.into_float_value(),
+                    self.llvm_ctx.f64_type(),
+                    "print_float",
+                )?;
+                let spec = if float_type == Types::F32 {
+                    "%.9g"
+                } else {
+                    "%.17g"
+                };
+                self.build_write_formatted(spec, &[wide.into()])
+            }
+            Types::Bool => {
+                let text = self.llvm_builder.build_select(
+                    value.value.into_int_value(),
+                    self.build_string_literal("true"),
+                    self.build_string_literal("false"),
+                    "print_bool",
+                )?;
+                self.build_write_formatted("%s", &[text.into()])
+            }
+            Types::Char => self.build_print_char(value.value.into_int_value()),
+            Types::String => self.build_write_formatted("%s", &[value.value.into()]),
+            // Trait objects and function values print their first half, the
+            // data or code pointer.
+            Types::Pointer(_) if value.value_type.is_dyn_pointer() => {
+                let data = self.llvm_builder.build_extract_value(
+                    value.value.into_struct_value(),
+                    0,
+                    "print_data",
+                )?;
+                self.build_write_formatted("%p", &[data.into()])
+            }
+            Types::Function(..) => {
+                let code = self.llvm_builder.build_extract_value(
+                    value.value.into_struct_value(),
+                    0,
+                    "print_code",
+                )?;
+                self.build_write_formatted("%p", &[code.into()])
+            }
+            Types::Pointer(_) => self.build_write_formatted("%p", &[value.value.into()]),
+            // A box prints the value it holds.
+            Types::Box(pointee) => {
+                let boxed = self.llvm_builder.build_load(
+                    self.get_llvm_type(&pointee),
+                    value.value.into_pointer_value(),
+                    "print_box",
+                )?;
+                self.build_print_value(TypedValue::new(boxed, *pointee))
+            }
+            Types::Tuple(element_types) => {
+                let aggregate = value.value.into_struct_value();
+                self.build_write_text("(")?;
+                for (index, element_type) in element_types.into_iter().enumerate() {
+                    if index > 0 {
+                        self.build_write_text(", ")?;
+                    }
+                    let element = self.llvm_builder.build_extract_value(
+                        aggregate,
+                        index as u32,
+                        "print_element",
+                    )?;
+                    self.build_print_value(TypedValue::new(element, element_type))?;
+                }
+                self.build_write_text(")")
+            }
+            Types::Struct(name) => {
+                let fields = self.lookup_struct(&name)?;
+                let aggregate = value.value.into_struct_value();
+                self.build_write_text(&display_name(&name))?;
+                if fields.is_empty() {
+                    return Ok(());
+                }
+                for (index, field) in fields.into_iter().enumerate() {
+                    let separator = if index == 0 { " { " } else { ", " };
+                    self.build_write_text(&format!("{}{}: ", separator, field.identifier))?;
+                    let field_value = self.llvm_builder.build_extract_value(
+                        aggregate,
+                        index as u32,
+                        "print_field",
+                    )?;
+                    self.build_print_value(TypedValue::new(field_value, field.field_type))?;
+                }
+                self.build_write_text(" }")
+            }
+            Types::Option(_) | Types::Result(..) => self.build_print_variant(value),
+            other => Err(CodeGenError::InvalidOperand(format!(
+                "cannot print `{:?}`",
+                other
+            ))),
+        }
+    }
+
+    /// Prints `Some(..)`, `None`, `Ok(..)` or `Err(..)` depending on the tag.
+    fn build_print_variant(&mut self, value: TypedValue<'ctx>) -> Result<(), CodeGenError> {
+        let func = self
+            .main_func
+            .ok_or_else(|| CodeGenError::NotImplemented("print outside of a function".into()))?;
+
+        let variant_type = value.value_type;
+        let (full, empty) = match &variant_type {
+            Types::Option(value_type) => (("Some", Some(value_type)), ("None", None)),
+            Types::Result(value_type, error_type) => {
+                (("Ok", Some(value_type)), ("Err", Some(error_type)))
+            }
+            other => {
+                return Err(CodeGenError::InvalidOperand(format!(
+                    "`{:?}` is not a variant",
+                    other
+                )));
+            }
+        };
+
+        let ptr = self.build_entry_alloca(self.get_llvm_type(&variant_type), "print")?;
+        self.llvm_builder.build_store(ptr, value.value)?;
+        let tag = self.load_variant_tag(ptr, &variant_type)?;
+
+        let full_block = self.llvm_ctx.append_basic_block(func, "print_full");
+        let empty_block = self.llvm_ctx.append_basic_block(func, "print_empty");
+        let cont_block = self.llvm_ctx.append_basic_block(func, "print_cont");
+        self.llvm_builder
+            .build_conditional_branch(tag, full_block, empty_block)?;
+
+        for (block, (name, payload_type)) in [(full_block, full), (empty_block, empty)] {
+            self.llvm_builder.position_at_end(block);
+            match payload_type {
+                Some(payload_type) => {
+                    self.build_write_text(&format!("{}(", name))?;
+                    let payload = self.load_variant_payload(ptr, &variant_type, payload_type)?;
+                    self.build_print_value(payload)?;
+                    self.build_write_text(")")?;
+                }
+                None => self.build_write_text(name)?,
+            }
+            self.llvm_builder.build_unconditional_branch(cont_block)?;
+        }
+
+        self.llvm_builder.position_at_end(cont_block);
+
+        Ok(())
+    }
+
+    /// Prints a char as UTF-8. The scalar value decides how many bytes it
+    /// takes; the leading byte carries the length marker and each following
+    /// byte six more bits.
+    fn build_print_char(&mut self, scalar: IntValue<'ctx>) -> Result<(), CodeGenError> {
+        let i32_type = self.llvm_ctx.i32_type();
+        let i8_type = self.llvm_ctx.i8_type();
+        let int = |value: u64| i32_type.const_int(value, false);
+
+        let mut len = int(1);
+        for bound in [0x80, 0x800, 0x10000] {
+            let wider = self.llvm_builder.build_int_compare(
+                IntPredicate::UGE,
+                scalar,
+                int(bound),
+                "wider",
+            )?;
+            let wider = self
+                .llvm_builder
+                .build_int_z_extend(wider, i32_type, "wider")?;
+            len = self.llvm_builder.build_int_add(len, wider, "len")?;
+        }
+
+        let mut marker = int(0);
+        for (bytes, bits) in [(2, 0xC0), (3, 0xE0), (4, 0xF0)] {
+            let matches =
+                self.llvm_builder
+                    .build_int_compare(IntPredicate::EQ, len, int(bytes), "is_len")?;
+            marker = self
+                .llvm_builder
+                .build_select(matches, int(bits), marker, "marker")?
+                .into_int_value();
+        }
+
+        // Bytes past `len` are filled too, but the precision given to
+        // `dprintf` stops before them.
+        let buffer = self.build_entry_alloca(i8_type.array_type(4).into(), "utf8")?;
+        for index in 0..4 {
+            let used =
+                self.llvm_builder
+                    .build_int_compare(IntPredicate::UGT, len, int(index), "used")?;
+            let remaining = self
+                .llvm_builder
+                .build_int_sub(len, int(index + 1), "remaining")?;
+            let remaining = self
+                .llvm_builder
+                .build_select(used, remaining, int(0), "remaining")?
+                .into_int_value();
+            let shift = self
+                .llvm_builder
+                .build_int_mul(remaining, int(6), "shift")?;
+            let bits = self
+                .llvm_builder
+                .build_right_shift(scalar, shift, false, "bits")?;
+            let byte = if index == 0 {
+                self.llvm_builder.build_or(bits, marker, "lead")?
+            } else {
+                let low = self.llvm_builder.build_and(bits, int(0x3F), "low")?;
+                self.llvm_builder.build_or(low, int(0x80), "continuation")?
+            };
+            let byte = self
+                .llvm_builder
+                .build_int_truncate(byte, i8_type, "byte")?;
+
+            // SAFETY: the index stays within the four bytes of the buffer.
+            let slot = unsafe {
+                self.llvm_builder.build_in_bounds_gep(
+                    i8_type,
+                    buffer,
+                    &[self.llvm_ctx.i64_type().const_int(index, false)],
+                    "slot",
+                )?
+            };
+            self.llvm_builder.build_store(slot, byte)?;
+        }
+
+        self.build_write_formatted("%.*s", &[len.into(), buffer.into()])
+    }
+
+    /// Writes literal text to standard output.
+    fn build_write_text(&mut self, text: &str) -> Result<(), CodeGenError> {
+        if text.is_empty() {
+            return Ok(());
+        }
+
+        let size_type = self.get_llvm_type(&Types::Usize).into_int_type();
+        let ptr_type = self.llvm_ctx.ptr_type(AddressSpace::default());
+        let fd_type = self.llvm_ctx.i32_type();
+        let write = self.libc_function(
+            "write",
+            size_type.fn_type(&[fd_type.into(), ptr_type.into(), size_type.into()], false),
+        )?;
+
+        let data = self.build_string_literal(text);
+        let len = size_type.const_int(text.len() as u64, false);
+        self.llvm_builder.build_call(
+            write,
+            &[
+                fd_type.const_int(STDOUT, false).into(),
+                data.into(),
+                len.into(),
+            ],
+            "",
+        )?;
+
+        Ok(())
+    }
+
+    /// Formats `arguments` with the C format string `spec` and writes the
+    /// result to standard output with `dprintf`.
+    fn build_write_formatted(
+        &mut self,
+        spec: &str,
+        arguments: &[BasicMetadataValueEnum<'ctx>],
+    ) -> Result<(), CodeGenError> {
+        let ptr_type = self.llvm_ctx.ptr_type(AddressSpace::default());
+        let fd_type = self.llvm_ctx.i32_type();
+        let dprintf = self.libc_function(
+            "dprintf",
+            fd_type.fn_type(&[fd_type.into(), ptr_type.into()], true),
+        )?;
+
+        let mut args: Vec<BasicMetadataValueEnum<'ctx>> = vec![
+            fd_type.const_int(STDOUT, false).into(),
+            self.build_string_literal(spec).into(),
+        ];
+        args.extend_from_slice(arguments);
+        self.llvm_builder.build_call(dprintf, &args, "")?;
+
+        Ok(())
+    }
+}
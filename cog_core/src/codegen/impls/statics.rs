@@ -0,0 +1,86 @@
+use cog_parser::parser::core::{expr::Expr, types::Types};
+use inkwell::{AddressSpace, module::Linkage, values::BasicValueEnum};
+
+use crate::{
+    check::core::consts::{ConstValue, eval_const},
+    codegen::{CodeGen, core::mangle::mangle_name, errors::CodeGenError},
+};
+
+impl<'ctx> CodeGen<'ctx> {
+    /// Emits each static as a global initialized with its value, worked out
+    /// at compile time. Immutable statics are marked constant.
+    pub fn declare_statics(&mut self, statements: &[Expr]) -> Result<(), CodeGenError> {
+        for stmt in statements {
+            if let Expr::StaticDeclaration {
+                identifier,
+                var_type,
+                value,
+                mutable,
+            } = stmt.item()
+            {
+                let value = eval_const(value, var_type, &|name| self.lookup_struct(name).ok())
+                    .map_err(CodeGenError::InvalidOperand)?;
+                let initializer = self.build_const(&value, var_type)?;
+
+                let global = self.lvvm_module.add_global(
+                    self.get_llvm_type(var_type),
+                    None,
+                    &mangle_name(identifier),
+                );
+                global.set_initializer(&initializer);
+                global.set_constant(!mutable);
+                global.set_linkage(if stmt.is_public() {
+                    Linkage::External
+                } else {
+                    Linkage::Internal
+                });
+                self.statics.insert(
+                    identifier.clone(),
+                    (global.as_pointer_value(), var_type.clone()),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn build_const(
+        &self,
+        value: &ConstValue,
+        t: &Types,
+    ) -> Result<BasicValueEnum<'ctx>, CodeGenError> {
+        let llvm_type = self.get_llvm_type(t);
+        Ok(match value {
+            ConstValue::Int(v) => llvm_type.into_int_type().const_int(*v as u64, false).into(),
+            ConstValue::Float(v) => llvm_type.into_float_type().const_float(*v).into(),
+            ConstValue::Bool(v) => llvm_type.into_int_type().const_int(*v as u64, false).into(),
+            ConstValue::Char(v) => llvm_type.into_int_type().const_int(*v as u64, false).into(),
+            ConstValue::Null => self
+                .llvm_ctx
+                .ptr_type(AddressSpace::default())
+                .const_null()
+                .into(),
+            ConstValue::String(v) => self.build_string_literal(v).into(),
+            ConstValue::Aggregate(values) => {
+                let element_types: Vec<Types> = match t {
+                    Types::Tuple(elements) => elements.clone(),
+                    Types::Struct(name) => self
+                        .lookup_struct(name)?
+                        .into_iter()
+                        .map(|field| field.field_type)
+                        .collect(),
+                    _ => return Err(CodeGenError::UnknownType(t.clone())),
+                };
+                let fields = values
+                    .iter()
+                    .zip(&element_types)
+                    .map(|(value, element_type)| self.build_const(value, element_type))
+                    .collect::<Result<Vec<_>, _>>()?;
+                llvm_type
+                    .into_struct_type()
+                    .const_named_struct(&fields)
+                    .into()
+            }
+        })
+    }
+}
@@ -0,0 +1,258 @@
+use cog_parser::parser::core::{
+    expr::{Expr, StructField},
+    types::Types,
+};
+use inkwell::{
+    types::BasicTypeEnum,
+    values::{BasicMetadataValueEnum, BasicValueEnum, PointerValue},
+};
+
+use crate::codegen::{
+    CodeGen,
+    core::{mangle::trait_method_name, value::TypedValue},
+    errors::CodeGenError,
+};
+
+impl<'ctx> CodeGen<'ctx> {
+    /// Creates every struct type up front. All names are registered as opaque
+    /// types before any body is set so structs can refer to each other.
+    pub fn declare_structs(&mut self, statements: &[Expr]) {
+        for stmt in statements {
+            if let Expr::StructDeclaration { identifier, .. } = stmt.item() {
+                self.llvm_ctx.opaque_struct_type(identifier);
+            }
+        }
+
+        for stmt in statements {
+            if let Expr::StructDeclaration { identifier, fields } = stmt.item() {
+                let field_types: Vec<BasicTypeEnum<'ctx>> = fields
+                    .iter()
+                    .map(|field| self.get_llvm_type(&field.field_type))
+                    .collect();
+                if let Some(struct_type) = self.llvm_ctx.get_struct_type(identifier) {
+                    struct_type.set_body(&field_types, false);
+                }
+                self.structs.insert(identifier.clone(), fields.clone());
+            }
+        }
+    }
+
+    pub fn compile_struct_literal(
+        &mut self,
+        identifier: &str,
+        fields: &[(String, Expr)],
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let struct_type = Types::Struct(identifier.to_string());
+        let declared = self.lookup_struct(identifier)?;
+
+        let mut aggregate = self
+            .get_llvm_type(&struct_type)
+            .into_struct_type()
+            .get_undef();
+        for (index, field) in declared.iter().enumerate() {
+            let (_, value) = fields
+                .iter()
+                .find(|(name, _)| name == &field.identifier)
+                .ok_or_else(|| {
+                    CodeGenError::UndefinedField(identifier.to_string(), field.identifier.clone())
+                })?;
+            let value = self.compile_value_as(value, &field.field_type)?;
+            aggregate = self
+                .llvm_builder
+                .build_insert_value(aggregate, value.value, index as u32, "field")?
+                .into_struct_value();
+        }
+
+        Ok(TypedValue::new(aggregate, struct_type))
+    }
+
+    /// Returns the address of `object.field`. Pointers to structs are looked
+    /// through, and struct values that are not places are spilled to a
+    /// temporary first. Tuple elements are fields named by their index.
+    pub fn compile_field_place(
+        &mut self,
+        object: &Expr,
+        field: &str,
+    ) -> Result<(PointerValue<'ctx>, Types), CodeGenError> {
+        let (ptr, object_type) = self.compile_object(object)?;
+        let (index, field_type) = match &object_type {
+            Types::Struct(struct_name) => self
+                .lookup_struct(struct_name)?
+                .iter()
+                .enumerate()
+                .find(|(_, f)| f.identifier == field)
+                .map(|(index, f)| (index, f.field_type.clone()))
+                .ok_or_else(|| {
+                    CodeGenError::UndefinedField(struct_name.clone(), field.to_string())
+                })?,
+            Types::Tuple(elements) => field
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| Some((index, elements.get(index)?.clone())))
+                .ok_or_else(|| {
+                    CodeGenError::UndefinedField(format!("{:?}", object_type), field.to_string())
+                })?,
+            other => {
+                return Err(CodeGenError::InvalidOperand(format!(
+                    "`{:?}` has no fields",
+                    other
+                )));
+            }
+        };
+
+        let struct_type = self.get_llvm_type(&object_type).into_struct_type();
+        let field_ptr =
+            self.llvm_builder
+                .build_struct_gep(struct_type, ptr, index as u32, field)?;
+
+        Ok((field_ptr, field_type))
+    }
+
+    /// Packs `values` into a tuple.
+    pub fn build_tuple(
+        &mut self,
+        values: Vec<TypedValue<'ctx>>,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let tuple_type = Types::Tuple(values.iter().map(|v| v.value_type.clone()).collect());
+
+        let mut aggregate = self
+            .get_llvm_type(&tuple_type)
+            .into_struct_type()
+            .get_undef();
+        for (index, value) in values.into_iter().enumerate() {
+            aggregate = self
+                .llvm_builder
+                .build_insert_value(aggregate, value.value, index as u32, "element")?
+                .into_struct_value();
+        }
+
+        Ok(TypedValue::new(aggregate, tuple_type))
+    }
+
+    /// Yields the address of the object `expr` denotes and its type, looking
+    /// through one level of pointer. A `*dyn Trait` is not looked through, as
+    /// its pointee has no known type; the address of the fat pointer is
+    /// returned instead.
+    fn compile_object(&mut self, expr: &Expr) -> Result<(PointerValue<'ctx>, Types), CodeGenError> {
+        if expr.is_place() {
+            let (ptr, place_type) = self.compile_place(expr)?;
+            match &place_type {
+                Types::Pointer(pointee) | Types::Box(pointee) if !place_type.is_dyn_pointer() => {
+                    let pointer = self.llvm_builder.build_load(
+                        self.get_llvm_type(&place_type),
+                        ptr,
+                        "load",
+                    )?;
+                    Ok((pointer.into_pointer_value(), (**pointee).clone()))
+                }
+                _ => Ok((ptr, place_type)),
+            }
+        } else {
+            let value = self.compile_value(expr)?;
+            match value.value_type {
+                Types::Pointer(pointee) | Types::Box(pointee)
+                    if !matches!(*pointee, Types::Dyn(_)) =>
+                {
+                    Ok((value.value.into_pointer_value(), *pointee))
+                }
+                value_type => {
+                    let temp = self.build_entry_alloca(self.get_llvm_type(&value_type), "tmp")?;
+                    self.llvm_builder.build_store(temp, value.value)?;
+                    Ok((temp, value_type))
+                }
+            }
+        }
+    }
+
+    /// Compiles the methods of `impl Type`, or of `impl Trait for Type` when
+    /// `trait_name` is given.
+    pub fn compile_impl_block(
+        &mut self,
+        type_name: &str,
+        trait_name: Option<&str>,
+        methods: &[Expr],
+    ) -> Result<(), CodeGenError> {
+        for method in methods {
+            if let Expr::FunctionDeclaration {
+                identifier,
+                parameters,
+                body,
+                return_type,
+            } = method.item()
+            {
+                let name = method_name(type_name, trait_name, identifier);
+                self.compile_func_decl(&name, parameters, body, return_type)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Compiles `receiver.method(args)`, passing the receiver as `self`: by
+    /// address when the method takes `*Type`, by value otherwise. Calls on a
+    /// `*dyn Trait` go through its vtable.
+    pub fn compile_method_call(
+        &mut self,
+        receiver: &Expr,
+        method: &str,
+        arguments: &[Expr],
+    ) -> Result<Option<TypedValue<'ctx>>, CodeGenError> {
+        let (ptr, object_type) = self.compile_object(receiver)?;
+        let type_name = match object_type {
+            Types::Struct(name) => name,
+            Types::Option(_) | Types::Result(..) => {
+                return self.compile_variant_method(ptr, &object_type, method, arguments);
+            }
+            Types::Pointer(pointee) => match *pointee {
+                Types::Dyn(trait_name) => {
+                    return self.compile_dyn_method_call(ptr, &trait_name, method, arguments);
+                }
+                pointee => {
+                    return Err(CodeGenError::InvalidOperand(format!(
+                        "`{:?}` has no methods",
+                        Types::Pointer(Box::new(pointee))
+                    )));
+                }
+            },
+            other => {
+                return Err(CodeGenError::InvalidOperand(format!(
+                    "`{:?}` has no methods",
+                    other
+                )));
+            }
+        };
+        let name = self.resolve_method(&type_name, method)?;
+        let (func, signature) = self
+            .functions
+            .get(&name)
+            .cloned()
+            .ok_or_else(|| CodeGenError::UndefinedFunction(name.clone()))?;
+
+        let struct_type = Types::Struct(type_name);
+        let receiver: BasicValueEnum<'ctx> = match signature.parameters.first() {
+            Some(Types::Pointer(_)) => ptr.into(),
+            _ => self
+                .llvm_builder
+                .build_load(self.get_llvm_type(&struct_type), ptr, "self")?,
+        };
+        let leading: Vec<BasicMetadataValueEnum<'ctx>> = vec![receiver.into()];
+
+        self.build_call_with(func, &signature, leading, arguments)
+    }
+
+    pub fn lookup_struct(&self, identifier: &str) -> Result<Vec<StructField>, CodeGenError> {
+        self.structs
+            .get(identifier)
+            .cloned()
+            .ok_or_else(|| CodeGenError::UnknownType(Types::Struct(identifier.to_string())))
+    }
+}
+
+/// The name a method is declared under: `Type::method` for the type's own
+/// methods and `Type::Trait::method` for those of a trait impl.
+pub fn method_name(type_name: &str, trait_name: Option<&str>, identifier: &str) -> String {
+    match trait_name {
+        Some(trait_name) => trait_method_name(type_name, trait_name, identifier),
+        None => format!("{}::{}", type_name, identifier),
+    }
+}
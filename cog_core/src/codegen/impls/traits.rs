@@ -0,0 +1,183 @@
+use cog_parser::parser::core::{
+    expr::{Expr, TraitMethod},
+    signature::FunctionSignature,
+    types::Types,
+};
+use inkwell::{
+    AddressSpace,
+    module::Linkage,
+    values::{BasicMetadataValueEnum, GlobalValue, PointerValue},
+};
+
+use crate::codegen::{
+    CodeGen,
+    core::{
+        mangle::{mangle_vtable, trait_method_name},
+        value::TypedValue,
+    },
+    errors::CodeGenError,
+};
+
+impl<'ctx> CodeGen<'ctx> {
+    /// Records each trait's methods. Their order fixes the vtable layout.
+    pub fn declare_traits(&mut self, statements: &[Expr]) {
+        for stmt in statements {
+            if let Expr::TraitDeclaration {
+                identifier,
+                methods,
+            } = stmt.item()
+            {
+                self.traits.insert(identifier.clone(), methods.clone());
+            }
+        }
+    }
+
+    /// Turns a `*Type` into a `*dyn Trait` when that is what `expected` asks
+    /// for. Other values are returned unchanged.
+    pub fn coerce_to_dyn(
+        &mut self,
+        value: TypedValue<'ctx>,
+        expected: &Types,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let (Types::Pointer(pointee), Types::Pointer(target)) = (&value.value_type, expected)
+        else {
+            return Ok(value);
+        };
+        let (Types::Struct(type_name), Types::Dyn(trait_name)) =
+            (pointee.as_ref(), target.as_ref())
+        else {
+            return Ok(value);
+        };
+
+        let vtable = self.get_vtable(type_name, trait_name)?;
+        let fat_type = self.get_llvm_type(expected).into_struct_type();
+        let fat =
+            self.llvm_builder
+                .build_insert_value(fat_type.get_undef(), value.value, 0, "dyn")?;
+        let fat = self
+            .llvm_builder
+            .build_insert_value(fat, vtable.as_pointer_value(), 1, "dyn")?;
+
+        Ok(TypedValue::new(fat.into_struct_value(), expected.clone()))
+    }
+
+    /// Returns the vtable for `type_name` as `trait_name`, emitting it on first
+    /// use: a constant array holding a pointer to each trait method's
+    /// implementation, in declaration order.
+    fn get_vtable(
+        &mut self,
+        type_name: &str,
+        trait_name: &str,
+    ) -> Result<GlobalValue<'ctx>, CodeGenError> {
+        let name = mangle_vtable(type_name, trait_name);
+        if let Some(global) = self.lvvm_module.get_global(&name) {
+            return Ok(global);
+        }
+
+        let methods = self.lookup_trait(trait_name)?;
+        let mut entries: Vec<PointerValue<'ctx>> = Vec::with_capacity(methods.len());
+        for method in &methods {
+            let method_name = trait_method_name(type_name, trait_name, &method.identifier);
+            let (func, _) = self
+                .functions
+                .get(&method_name)
+                .ok_or_else(|| CodeGenError::UndefinedFunction(method_name.clone()))?;
+            entries.push(func.as_global_value().as_pointer_value());
+        }
+
+        let ptr_type = self.llvm_ctx.ptr_type(AddressSpace::default());
+        let table = ptr_type.const_array(&entries);
+        let global = self.lvvm_module.add_global(table.get_type(), None, &name);
+        global.set_initializer(&table);
+        global.set_constant(true);
+        global.set_linkage(Linkage::Internal);
+
+        Ok(global)
+    }
+
+    /// Calls `method` through the vtable of the `*dyn Trait` stored at
+    /// `fat_ptr`, passing the data pointer as `self`.
+    pub fn compile_dyn_method_call(
+        &mut self,
+        fat_ptr: PointerValue<'ctx>,
+        trait_name: &str,
+        method: &str,
+        arguments: &[Expr],
+    ) -> Result<Option<TypedValue<'ctx>>, CodeGenError> {
+        let methods = self.lookup_trait(trait_name)?;
+        let (index, trait_method) = methods
+            .iter()
+            .enumerate()
+            .find(|(_, m)| m.identifier == method)
+            .ok_or_else(|| {
+                CodeGenError::UndefinedFunction(format!("{}::{}", trait_name, method))
+            })?;
+
+        let dyn_type = Types::Pointer(Box::new(Types::Dyn(trait_name.to_string())));
+        let fat = self
+            .llvm_builder
+            .build_load(self.get_llvm_type(&dyn_type), fat_ptr, "dyn")?
+            .into_struct_value();
+        let data = self.llvm_builder.build_extract_value(fat, 0, "data")?;
+        let vtable = self
+            .llvm_builder
+            .build_extract_value(fat, 1, "vtable")?
+            .into_pointer_value();
+
+        let ptr_type = self.llvm_ctx.ptr_type(AddressSpace::default());
+        let index = self.llvm_ctx.i64_type().const_int(index as u64, false);
+        // SAFETY: the index comes from the trait declaration the vtable was built from.
+        let slot = unsafe {
+            self.llvm_builder
+                .build_in_bounds_gep(ptr_type, vtable, &[index], "slot")?
+        };
+        let func = self
+            .llvm_builder
+            .build_load(ptr_type, slot, "method")?
+            .into_pointer_value();
+
+        let signature = FunctionSignature::new(
+            &trait_method.identifier,
+            &trait_method.parameters,
+            &trait_method.return_type,
+            false,
+        );
+        let leading: Vec<BasicMetadataValueEnum<'ctx>> = vec![data.into()];
+        let args = self.compile_arguments(&signature, leading, arguments)?;
+        let call = self.llvm_builder.build_indirect_call(
+            self.get_llvm_fn_type(&signature),
+            func,
+            &args,
+            "call",
+        )?;
+
+        Ok(call
+            .try_as_basic_value()
+            .left()
+            .map(|value| TypedValue::new(value, signature.return_type.clone())))
+    }
+
+    /// The function `method` names on the struct `type_name`: its own method
+    /// if it has one, otherwise the one from its trait impls. The checker has
+    /// rejected calls that more than one trait could answer.
+    pub fn resolve_method(&self, type_name: &str, method: &str) -> Result<String, CodeGenError> {
+        let inherent = format!("{}::{}", type_name, method);
+        if self.functions.contains_key(&inherent) {
+            return Ok(inherent);
+        }
+
+        self.traits
+            .iter()
+            .filter(|(_, methods)| methods.iter().any(|m| m.identifier == method))
+            .map(|(trait_name, _)| trait_method_name(type_name, trait_name, method))
+            .find(|name| self.functions.contains_key(name))
+            .ok_or(CodeGenError::UndefinedFunction(inherent))
+    }
+
+    fn lookup_trait(&self, identifier: &str) -> Result<Vec<TraitMethod>, CodeGenError> {
+        self.traits
+            .get(identifier)
+            .cloned()
+            .ok_or_else(|| CodeGenError::UnknownType(Types::Dyn(identifier.to_string())))
+    }
+}
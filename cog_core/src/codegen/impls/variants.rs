@@ -0,0 +1,218 @@
+use cog_parser::parser::core::{
+    expr::{Expr, Variant},
+    types::Types,
+};
+use inkwell::values::{IntValue, PointerValue};
+
+use crate::codegen::{CodeGen, core::value::TypedValue, errors::CodeGenError};
+
+impl<'ctx> CodeGen<'ctx> {
+    /// Compiles an `Option` or `Result` constructor. Without an expected type
+    /// only `Some(x)` can be built, taking its type from `x`.
+    pub fn compile_variant(
+        &mut self,
+        variant: Variant,
+        value: &Option<Box<Expr>>,
+        expected: Option<&Types>,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let variant_type = match (expected, variant, value) {
+            (Some(expected), _, _) => expected.clone(),
+            (None, Variant::Some, Some(value)) => {
+                let value = self.compile_value(value)?;
+                let variant_type = Types::Option(Box::new(value.value_type.clone()));
+                return self.build_variant(&variant_type, true, Some(value));
+            }
+            _ => {
+                return Err(CodeGenError::NotImplemented(format!(
+                    "`{:?}` without a known type",
+                    variant
+                )));
+            }
+        };
+
+        let payload_type = match (variant, &variant_type) {
+            (Variant::Some, Types::Option(payload_type))
+            | (Variant::Ok, Types::Result(payload_type, _))
+            | (Variant::Err, Types::Result(_, payload_type)) => Some(payload_type.as_ref()),
+            (Variant::None, Types::Option(_)) => None,
+            _ => {
+                return Err(CodeGenError::InvalidOperand(format!(
+                    "`{:?}` is not a `{:?}`",
+                    variant, variant_type
+                )));
+            }
+        };
+
+        let payload = match (value, payload_type) {
+            (Some(value), Some(payload_type)) => Some(self.compile_value_as(value, payload_type)?),
+            _ => None,
+        };
+
+        self.build_variant(
+            &variant_type,
+            matches!(variant, Variant::Some | Variant::Ok),
+            payload,
+        )
+    }
+
+    /// Compiles `operand?`: on `None` or `Err(e)` the enclosing function
+    /// returns the same, after running its cleanups, otherwise the payload is
+    /// unwrapped.
+    pub fn compile_try(&mut self, operand: &Expr) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let ret_type = self
+            .return_type
+            .clone()
+            .ok_or_else(|| CodeGenError::NotImplemented("`?` outside of a function".into()))?;
+        let func = self
+            .main_func
+            .ok_or_else(|| CodeGenError::NotImplemented("`?` outside of a function".into()))?;
+
+        let operand = self.compile_value(operand)?;
+        let operand_type = operand.value_type.clone();
+        let value_type = match &operand_type {
+            Types::Option(value_type) | Types::Result(value_type, _) => value_type.as_ref().clone(),
+            other => {
+                return Err(CodeGenError::InvalidOperand(format!(
+                    "`?` applied to `{:?}`",
+                    other
+                )));
+            }
+        };
+
+        let ptr = self.build_entry_alloca(self.get_llvm_type(&operand_type), "try")?;
+        self.llvm_builder.build_store(ptr, operand.value)?;
+        let tag = self.load_variant_tag(ptr, &operand_type)?;
+
+        let fail_block = self.llvm_ctx.append_basic_block(func, "try_fail");
+        let cont_block = self.llvm_ctx.append_basic_block(func, "try_cont");
+        self.llvm_builder
+            .build_conditional_branch(tag, cont_block, fail_block)?;
+
+        self.llvm_builder.position_at_end(fail_block);
+        let error = match &operand_type {
+            Types::Result(_, error_type) => {
+                Some(self.load_variant_payload(ptr, &operand_type, error_type)?)
+            }
+            _ => None,
+        };
+        let failure = self.build_variant(&ret_type, false, error)?;
+        self.build_cleanups_for_return()?;
+        self.llvm_builder.build_return(Some(&failure.value))?;
+
+        self.llvm_builder.position_at_end(cont_block);
+        self.load_variant_payload(ptr, &operand_type, &value_type)
+    }
+
+    /// Compiles a call to one of the methods built into `Option` and `Result`,
+    /// with `ptr` pointing at the receiver.
+    pub fn compile_variant_method(
+        &mut self,
+        ptr: PointerValue<'ctx>,
+        object_type: &Types,
+        method: &str,
+        arguments: &[Expr],
+    ) -> Result<Option<TypedValue<'ctx>>, CodeGenError> {
+        let tag = self.load_variant_tag(ptr, object_type)?;
+
+        let value = match (object_type, method) {
+            (_, "is_some" | "is_ok") => TypedValue::new(tag, Types::Bool),
+            (_, "is_none" | "is_err") => {
+                TypedValue::new(self.llvm_builder.build_not(tag, "not")?, Types::Bool)
+            }
+            (Types::Option(value_type) | Types::Result(value_type, _), "unwrap") => {
+                let is_empty = self.llvm_builder.build_not(tag, "not")?;
+                self.build_trap_if(is_empty)?;
+                self.load_variant_payload(ptr, object_type, value_type)?
+            }
+            (Types::Result(_, error_type), "unwrap_err") => {
+                self.build_trap_if(tag)?;
+                self.load_variant_payload(ptr, object_type, error_type)?
+            }
+            (Types::Option(value_type) | Types::Result(value_type, _), "unwrap_or") => {
+                let default = arguments.first().ok_or_else(|| {
+                    CodeGenError::InvalidOperand("`unwrap_or` needs a default".into())
+                })?;
+                let default = self.compile_value_as(default, value_type)?;
+                let payload = self.load_variant_payload(ptr, object_type, value_type)?;
+                let value = self.llvm_builder.build_select(
+                    tag,
+                    payload.value,
+                    default.value,
+                    "unwrap_or",
+                )?;
+                TypedValue::new(value, value_type.as_ref().clone())
+            }
+            _ => {
+                return Err(CodeGenError::UndefinedFunction(format!(
+                    "{:?}::{}",
+                    object_type, method
+                )));
+            }
+        };
+
+        Ok(Some(value))
+    }
+
+    /// Builds an `Option` or `Result` value from its tag and payload. `Some`
+    /// and `Ok` are tagged `1`.
+    fn build_variant(
+        &mut self,
+        variant_type: &Types,
+        tag: bool,
+        payload: Option<TypedValue<'ctx>>,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let llvm_type = self.get_llvm_type(variant_type);
+        let ptr = self.build_entry_alloca(llvm_type, "variant")?;
+
+        let tag_ptr = self
+            .llvm_builder
+            .build_struct_gep(llvm_type, ptr, 0, "tag")?;
+        let tag = self.llvm_ctx.bool_type().const_int(tag as u64, false);
+        self.llvm_builder.build_store(tag_ptr, tag)?;
+
+        if let Some(payload) = payload {
+            let payload_ptr = self
+                .llvm_builder
+                .build_struct_gep(llvm_type, ptr, 1, "payload")?;
+            self.llvm_builder.build_store(payload_ptr, payload.value)?;
+        }
+
+        let value = self.llvm_builder.build_load(llvm_type, ptr, "variant")?;
+        Ok(TypedValue::new(value, variant_type.clone()))
+    }
+
+    pub fn load_variant_tag(
+        &self,
+        ptr: PointerValue<'ctx>,
+        variant_type: &Types,
+    ) -> Result<IntValue<'ctx>, CodeGenError> {
+        let llvm_type = self.get_llvm_type(variant_type);
+        let tag_ptr = self
+            .llvm_builder
+            .build_struct_gep(llvm_type, ptr, 0, "tag")?;
+        let tag = self
+            .llvm_builder
+            .build_load(self.llvm_ctx.bool_type(), tag_ptr, "tag")?;
+        Ok(tag.into_int_value())
+    }
+
+    /// Reads the payload as `payload_type`. For a `Result` both payloads
+    /// share the same storage.
+    pub fn load_variant_payload(
+        &self,
+        ptr: PointerValue<'ctx>,
+        variant_type: &Types,
+        payload_type: &Types,
+    ) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let llvm_type = self.get_llvm_type(variant_type);
+        let payload_ptr = self
+            .llvm_builder
+            .build_struct_gep(llvm_type, ptr, 1, "payload")?;
+        let value = self.llvm_builder.build_load(
+            self.get_llvm_type(payload_type),
+            payload_ptr,
+            "payload",
+        )?;
+        Ok(TypedValue::new(value, payload_type.clone()))
+    }
+}
@@ -0,0 +1,165 @@
+use cog_parser::parser::core::{expr::Expr, nodes::Nodes, ops::BinaryOp, types::Types};
+use inkwell::{types::BasicTypeEnum, values::PointerValue};
+
+use crate::codegen::{CodeGen, core::value::TypedValue, errors::CodeGenError};
+
+impl<'ctx> CodeGen<'ctx> {
+    pub fn compile_declaration(
+        &mut self,
+        identifier: &str,
+        var_type: &Option<Types>,
+        value: &Expr,
+    ) -> Result<(), CodeGenError> {
+        let value = match var_type {
+            Some(t) => self.compile_value_as(value, t)?,
+            None => {
+                let compiled = self.compile_value(value)?;
+                self.move_value(value)?;
+                compiled
+            }
+        };
+
+        let llvm_type = self.get_llvm_type(&value.value_type);
+        let ptr = self.build_entry_alloca(llvm_type, identifier)?;
+        self.llvm_builder.build_store(ptr, value.value)?;
+        if let Types::Box(_) = value.value_type {
+            self.declare_owned_box(ptr, &value.value_type)?;
+        }
+        self.variables
+            .insert(identifier.to_string(), (ptr, value.value_type));
+
+        Ok(())
+    }
+
+    /// Binds each element of the tuple `value` to a new variable.
+    pub fn compile_tuple_declaration(
+        &mut self,
+        identifiers: &[String],
+        var_type: &Option<Types>,
+        value: &Expr,
+    ) -> Result<(), CodeGenError> {
+        let value = match var_type {
+            Some(t) => self.compile_value_as(value, t)?,
+            None => self.compile_value(value)?,
+        };
+        let Types::Tuple(element_types) = &value.value_type else {
+            return Err(CodeGenError::InvalidOperand(format!(
+                "`{:?}` is not a tuple",
+                value.value_type
+            )));
+        };
+
+        let tuple = value.value.into_struct_value();
+        for (index, (identifier, element_type)) in identifiers.iter().zip(element_types).enumerate()
+        {
+            let element = self
+                .llvm_builder
+                .build_extract_value(tuple, index as u32, identifier)?;
+            let ptr = self.build_entry_alloca(self.get_llvm_type(element_type), identifier)?;
+            self.llvm_builder.build_store(ptr, element)?;
+            if let Types::Box(_) = element_type {
+                self.declare_owned_box(ptr, element_type)?;
+            }
+            self.variables
+                .insert(identifier.clone(), (ptr, element_type.clone()));
+        }
+
+        Ok(())
+    }
+
+    /// Assigning to a box variable frees the box it owned before.
+    pub fn compile_assignment(&mut self, target: &Expr, value: &Expr) -> Result<(), CodeGenError> {
+        let (ptr, target_type) = self.compile_place(target)?;
+        let value = self.compile_value_as(value, &target_type)?;
+        if self.assign_owned_box(target, &value)? {
+            return Ok(());
+        }
+        self.llvm_builder.build_store(ptr, value.value)?;
+
+        Ok(())
+    }
+
+    /// Lowers `target op= value` as load, op, store, computing the address of
+    /// `target` only once. `value` is compiled before `target` is loaded, so
+    /// a change it makes to `target` is not lost.
+    pub fn compile_compound_assignment(
+        &mut self,
+        target: &Expr,
+        operator: &BinaryOp,
+        value: &Expr,
+    ) -> Result<(), CodeGenError> {
+        let (ptr, target_type) = self.compile_place(target)?;
+        let value = match &target_type {
+            Types::Pointer(_) if operator.is_offset() => self.compile_offset(value)?,
+            _ => self.compile_value_as(value, &target_type)?,
+        };
+
+        let current =
+            self.llvm_builder
+                .build_load(self.get_llvm_type(&target_type), ptr, "current")?;
+        let current = TypedValue::new(current, target_type.clone());
+        let result = self.build_binary(current, operator, value)?;
+        self.llvm_builder.build_store(ptr, result.value)?;
+
+        Ok(())
+    }
+
+    pub fn load_variable(&mut self, identifier: &str) -> Result<TypedValue<'ctx>, CodeGenError> {
+        let (ptr, var_type) = self.lookup_variable(identifier)?;
+        let value = self
+            .llvm_builder
+            .build_load(self.get_llvm_type(&var_type), ptr, identifier)?;
+
+        Ok(TypedValue::new(value, var_type))
+    }
+
+    /// Compiles an lvalue expression to the address it names, along with the
+    /// type stored there.
+    pub fn compile_place(
+        &mut self,
+        expr: &Expr,
+    ) -> Result<(PointerValue<'ctx>, Types), CodeGenError> {
+        match expr {
+            Expr::Literal(Nodes::Identifier(name)) => self.lookup_variable(name),
+            Expr::Path(segments) => self.lookup_variable(&segments.join("::")),
+            Expr::Dereference(operand) => self.compile_pointee(operand),
+            Expr::FieldAccess { object, field } => self.compile_field_place(object, field),
+            _ => Err(CodeGenError::NotAnLvalue(format!("{:?}", expr))),
+        }
+    }
+
+    /// Looks up a local variable, or else a static.
+    pub fn lookup_variable(
+        &self,
+        identifier: &str,
+    ) -> Result<(PointerValue<'ctx>, Types), CodeGenError> {
+        self.variables
+            .get(identifier)
+            .or_else(|| self.statics.get(identifier))
+            .cloned()
+            .ok_or_else(|| CodeGenError::UndefinedVariable(identifier.to_string()))
+    }
+
+    /// Allocates stack space in the entry block of the current function so that
+    /// LLVM can promote it to a register.
+    pub fn build_entry_alloca(
+        &self,
+        llvm_type: BasicTypeEnum<'ctx>,
+        name: &str,
+    ) -> Result<PointerValue<'ctx>, CodeGenError> {
+        let entry = self
+            .main_func
+            .and_then(|func| func.get_first_basic_block())
+            .ok_or_else(|| {
+                CodeGenError::NotImplemented("variables outside of a function".into())
+            })?;
+
+        let builder = self.llvm_ctx.create_builder();
+        match entry.get_first_instruction() {
+            Some(instruction) => builder.position_before(&instruction),
+            None => builder.position_at_end(entry),
+        }
+
+        Ok(builder.build_alloca(llvm_type, name)?)
+    }
+}
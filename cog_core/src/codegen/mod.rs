@@ -1,16 +1,23 @@
 use std::collections::HashMap;
 
-use cog_parser::parser::core::expr::Expr;
+use cog_parser::parser::core::{
+    expr::{Expr, StructField, TraitMethod},
+    signature::FunctionSignature,
+    types::Types,
+};
 
 use inkwell::{
     builder::Builder,
     context::Context,
     module::Module,
-    types::BasicTypeEnum,
+    targets::TargetMachine,
     values::{FunctionValue, PointerValue},
 };
 
-use crate::codegen::errors::CodeGenError;
+use crate::{
+    check::core::operators::OperatorTrait,
+    codegen::{core::value::Cleanup, errors::CodeGenError},
+};
 pub mod core;
 pub mod errors;
 pub mod impls;
@@ -19,8 +26,19 @@ pub struct CodeGen<'ctx> {
     pub llvm_ctx: &'ctx Context,
     pub lvvm_module: Module<'ctx>,
     pub llvm_builder: Builder<'ctx>,
-    variables: HashMap<String, (PointerValue<'ctx>, BasicTypeEnum<'ctx>)>,
+    variables: HashMap<String, (PointerValue<'ctx>, Types)>,
+    functions: HashMap<String, (FunctionValue<'ctx>, FunctionSignature)>,
+    structs: HashMap<String, Vec<StructField>>,
+    /// The global holding each static, and its type.
+    statics: HashMap<String, (PointerValue<'ctx>, Types)>,
+    /// Declared traits, starting with the built-in operator traits.
+    traits: HashMap<String, Vec<TraitMethod>>,
     main_func: Option<FunctionValue<'ctx>>,
+    return_type: Option<Types>,
+    /// The cleanups of each open scope of the current function, innermost
+    /// last.
+    cleanups: Vec<Vec<Cleanup<'ctx>>>,
+    debug_checks: bool,
 }
 
 impl<'ctx> CodeGen<'ctx> {
@@ -33,11 +51,39 @@ impl<'ctx> CodeGen<'ctx> {
             llvm_builder: builder,
             llvm_ctx: context,
             variables: HashMap::new(),
+            functions: HashMap::new(),
+            structs: HashMap::new(),
+            statics: HashMap::new(),
+            traits: OperatorTrait::all()
+                .into_iter()
+                .map(|op| (op.name.to_string(), op.declaration()))
+                .collect(),
             main_func: None,
+            return_type: None,
+            cleanups: Vec::new(),
+            debug_checks: true,
         }
     }
 
+    /// Enables or disables the runtime checks emitted for debug builds.
+    pub fn set_debug_checks(&mut self, enabled: bool) {
+        self.debug_checks = enabled;
+    }
+
+    /// Adopts the triple and data layout of `machine`. Type layouts depend on
+    /// the data layout, so this must be called before `compile`.
+    pub fn set_target(&self, machine: &TargetMachine) {
+        self.lvvm_module.set_triple(&machine.get_triple());
+        self.lvvm_module
+            .set_data_layout(&machine.get_target_data().get_data_layout());
+    }
+
     pub fn compile(&mut self, statements: &[Expr]) -> Result<(), CodeGenError> {
+        self.declare_structs(statements);
+        self.declare_statics(statements)?;
+        self.declare_traits(statements);
+        self.declare_functions(statements);
+
         for stmt in statements {
             self.compile_expr(stmt)?;
         }
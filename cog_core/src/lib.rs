@@ -1,2 +1,4 @@
 pub mod check;
 pub mod codegen;
+pub mod modules;
+pub mod mono;
@@ -0,0 +1,80 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
+
+use cog_parser::parser::core::expr::Expr;
+
+/// One source file of a program, and the names it declares and imports.
+#[derive(Debug, Clone)]
+pub struct Module {
+    /// The module's place in the tree, such as `["geo", "shapes"]`. Empty for
+    /// the root.
+    pub path: Vec<String>,
+    pub file: PathBuf,
+    pub statements: Vec<Expr>,
+    /// Child modules by name, as indices into the tree.
+    pub children: HashMap<String, usize>,
+    /// The name each item declared here has once the program is linked.
+    pub items: HashMap<String, String>,
+    /// The linked names of the items that are type aliases.
+    pub aliases: HashSet<String>,
+    /// The absolute path each `import` brings into scope, by its last segment.
+    pub imports: HashMap<String, Vec<String>>,
+}
+
+impl Module {
+    pub fn new(path: Vec<String>, file: PathBuf, statements: Vec<Expr>) -> Self {
+        let mut module = Self {
+            path,
+            file,
+            statements: Vec::new(),
+            children: HashMap::new(),
+            items: HashMap::new(),
+            aliases: HashSet::new(),
+            imports: HashMap::new(),
+        };
+
+        for stmt in &statements {
+            let item = match stmt.item() {
+                Expr::Generic { item, .. } => item.as_ref(),
+                other => other,
+            };
+            match item {
+                Expr::FunctionDeclaration { identifier, .. }
+                | Expr::StructDeclaration { identifier, .. }
+                | Expr::TraitDeclaration { identifier, .. }
+                | Expr::StaticDeclaration { identifier, .. } => {
+                    module
+                        .items
+                        .insert(identifier.clone(), module.qualify(identifier));
+                }
+                Expr::TypeAlias { identifier, .. } => {
+                    let linked = module.qualify(identifier);
+                    module.aliases.insert(linked.clone());
+                    module.items.insert(identifier.clone(), linked);
+                }
+                Expr::Import { path } => {
+                    if let Some(alias) = path.last() {
+                        module.imports.insert(alias.clone(), path.clone());
+                    }
+                }
+                _ => {}
+            }
+        }
+        module.statements = statements;
+
+        module
+    }
+
+    /// The linked name of `name` declared in this module. Items of the root
+    /// module keep their own names, so `main` stays `main`.
+    pub fn qualify(&self, name: &str) -> String {
+        self.path
+            .iter()
+            .map(String::as_str)
+            .chain([name])
+            .collect::<Vec<_>>()
+            .join("::")
+    }
+}
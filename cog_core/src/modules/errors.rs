@@ -0,0 +1,20 @@
+use std::fmt::{self};
+
+use cog_parser::parser::errors::ParserError;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum ModuleError {
+    Io(String, String),
+    Parse(String, ParserError),
+    ModuleNotFound(String, String),
+    DuplicateModule(String),
+    UnresolvedImport(String),
+}
+
+impl fmt::Display for ModuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for ModuleError {}
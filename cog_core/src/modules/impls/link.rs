@@ -0,0 +1,49 @@
+use cog_parser::parser::core::expr::Expr;
+
+use crate::modules::{ModuleTree, ROOT, errors::ModuleError, impls::resolve::Resolver};
+
+impl ModuleTree {
+    /// Merges the modules into one program, with every item under its
+    /// linked name and every use of it pointed there. `mod` and `import`
+    /// declarations are dropped, and an extern declared the same way in
+    /// several modules is kept once.
+    pub fn link(&self) -> Result<Vec<Expr>, ModuleError> {
+        self.check_imports()?;
+
+        let mut program: Vec<Expr> = Vec::new();
+        for (index, module) in self.modules.iter().enumerate() {
+            let mut resolver = Resolver::new(self, index);
+            for stmt in &module.statements {
+                if matches!(
+                    stmt.item(),
+                    Expr::ModuleDeclaration { .. } | Expr::Import { .. }
+                ) {
+                    continue;
+                }
+
+                let item = resolver.item(stmt);
+                let is_duplicate_extern =
+                    matches!(item.item(), Expr::ExternFunctionDeclaration { .. })
+                        && program.iter().any(|linked| linked.item() == item.item());
+                if !is_duplicate_extern {
+                    program.push(item);
+                }
+            }
+        }
+
+        Ok(program)
+    }
+
+    /// Every import has to name a module or an item of one.
+    fn check_imports(&self) -> Result<(), ModuleError> {
+        for module in &self.modules {
+            for path in module.imports.values() {
+                if self.lookup_in(ROOT, path, false).is_none() && !self.is_module(path) {
+                    return Err(ModuleError::UnresolvedImport(path.join("::")));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
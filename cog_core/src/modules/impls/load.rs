@@ -0,0 +1,75 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use cog_parser::parser::{Parser, core::expr::Expr};
+
+use crate::modules::{EXTENSION, ModuleTree, core::module::Module, errors::ModuleError};
+
+impl ModuleTree {
+    /// Loads the program rooted at `root`. `mod name;` in a file refers to
+    /// `name.cog` beside the root, or in the directory named after the
+    /// declaring module, so `mod shapes;` in `geo.cog` loads `geo/shapes.cog`.
+    pub fn load(root: &Path) -> Result<Self, ModuleError> {
+        let dir = root.parent().unwrap_or(Path::new(".")).to_path_buf();
+
+        let mut tree = Self {
+            modules: Vec::new(),
+        };
+        tree.load_module(Vec::new(), root.to_path_buf(), dir)?;
+
+        Ok(tree)
+    }
+
+    fn load_module(
+        &mut self,
+        path: Vec<String>,
+        file: PathBuf,
+        dir: PathBuf,
+    ) -> Result<usize, ModuleError> {
+        if self.modules.iter().any(|module| module.file == file) {
+            return Err(ModuleError::DuplicateModule(file.display().to_string()));
+        }
+
+        let source = fs::read_to_string(&file)
+            .map_err(|err| ModuleError::Io(file.display().to_string(), err.to_string()))?;
+        let statements = Parser::new(source)
+            .and_then(|mut parser| parser.parse())
+            .map_err(|err| ModuleError::Parse(file.display().to_string(), err))?;
+
+        let children: Vec<String> = statements
+            .iter()
+            .filter_map(|stmt| match stmt.item() {
+                Expr::ModuleDeclaration { identifier } => Some(identifier.clone()),
+                _ => None,
+            })
+            .collect();
+
+        let index = self.modules.len();
+        self.modules
+            .push(Module::new(path.clone(), file, statements));
+
+        for name in children {
+            let qualified = self.modules[index].qualify(&name);
+            if self.modules[index].children.contains_key(&name) {
+                return Err(ModuleError::DuplicateModule(qualified));
+            }
+
+            let child_file = dir.join(&name).with_extension(EXTENSION);
+            if !child_file.is_file() {
+                return Err(ModuleError::ModuleNotFound(
+                    qualified,
+                    child_file.display().to_string(),
+                ));
+            }
+
+            let mut child_path = path.clone();
+            child_path.push(name.clone());
+            let child = self.load_module(child_path, child_file, dir.join(&name))?;
+            self.modules[index].children.insert(name, child);
+        }
+
+        Ok(index)
+    }
+}
@@ -0,0 +1,323 @@
+use std::collections::HashSet;
+
+use cog_parser::parser::core::{
+    expr::{Expr, TraitMethod, TypeParameter},
+    nodes::Nodes,
+    types::Types,
+};
+
+use crate::{
+    modules::{ModuleTree, ROOT},
+    mono::core::rebuild::Rebuild,
+};
+
+impl ModuleTree {
+    /// Finds the linked name of a path used in `module`. The path may start
+    /// with a child module, an imported name or an item of the module, and
+    /// otherwise is looked up from the root.
+    pub fn lookup(&self, module: usize, segments: &[String]) -> Option<String> {
+        self.lookup_in(module, segments, true).or_else(|| {
+            (module != ROOT)
+                .then(|| self.lookup_in(ROOT, segments, false))
+                .flatten()
+        })
+    }
+
+    /// Imports are only visible inside the module that declares them, so a
+    /// path into another module never follows its imports.
+    pub fn lookup_in(&self, module: usize, segments: &[String], imports: bool) -> Option<String> {
+        let (first, rest) = segments.split_first()?;
+        let module = &self.modules[module];
+
+        if let Some(&child) = module.children.get(first) {
+            return self.lookup_in(child, rest, false);
+        }
+        if imports && let Some(target) = module.imports.get(first) {
+            return self.lookup_in(ROOT, &[target.as_slice(), rest].concat(), false);
+        }
+
+        // Anything after the item names something inside it, as the `new` in
+        // `Point::new`.
+        module.items.get(first).map(|item| {
+            [item.clone()]
+                .into_iter()
+                .chain(rest.iter().cloned())
+                .collect::<Vec<_>>()
+                .join("::")
+        })
+    }
+
+    /// Whether a linked name belongs to a type alias. Aliases used through a
+    /// path or an import parse as struct types until they are resolved.
+    pub fn is_alias(&self, linked: &str) -> bool {
+        self.modules
+            .iter()
+            .any(|module| module.aliases.contains(linked))
+    }
+
+    /// Whether `segments` names a module, counting from the root.
+    pub fn is_module(&self, segments: &[String]) -> bool {
+        let mut module = ROOT;
+        for segment in segments {
+            match self.modules[module].children.get(segment) {
+                Some(&child) => module = child,
+                None => return false,
+            }
+        }
+        true
+    }
+}
+
+/// Rewrites the names used in one module to their linked names. Variables
+/// in scope shadow items, and names that resolve to nothing are left for the
+/// checker to report.
+pub struct Resolver<'a> {
+    tree: &'a ModuleTree,
+    module: usize,
+    scopes: Vec<HashSet<String>>,
+}
+
+impl<'a> Resolver<'a> {
+    pub fn new(tree: &'a ModuleTree, module: usize) -> Self {
+        Self {
+            tree,
+            module,
+            scopes: vec![HashSet::new()],
+        }
+    }
+
+    /// Rebuilds a top-level statement of the module, giving the item it
+    /// declares its linked name.
+    pub fn item(&mut self, stmt: &Expr) -> Expr {
+        match stmt {
+            Expr::Documented { doc, item } => Expr::Documented {
+                doc: doc.clone(),
+                item: Box::new(self.item(item)),
+            },
+            Expr::Public { item } => Expr::Public {
+                item: Box::new(self.item(item)),
+            },
+            Expr::Generic {
+                type_parameters,
+                item,
+            } => Expr::Generic {
+                type_parameters: type_parameters
+                    .iter()
+                    .map(|param| TypeParameter {
+                        identifier: param.identifier.clone(),
+                        bounds: param
+                            .bounds
+                            .iter()
+                            .map(|bound| self.resolve_name(bound))
+                            .collect(),
+                    })
+                    .collect(),
+                item: Box::new(self.item(item)),
+            },
+            Expr::TraitDeclaration {
+                identifier,
+                methods,
+            } => Expr::TraitDeclaration {
+                identifier: self.linked_name(identifier),
+                methods: methods
+                    .iter()
+                    .map(|method| self.trait_method(method))
+                    .collect(),
+            },
+            Expr::TypeAlias {
+                identifier,
+                aliased,
+            } => Expr::TypeAlias {
+                identifier: self.linked_name(identifier),
+                aliased: self.rebuild_type(aliased),
+            },
+            Expr::FunctionDeclaration { identifier, .. }
+            | Expr::StructDeclaration { identifier, .. }
+            | Expr::StaticDeclaration { identifier, .. } => {
+                let linked = self.linked_name(identifier);
+                match self.rebuild_expr(stmt) {
+                    Expr::FunctionDeclaration {
+                        parameters,
+                        body,
+                        return_type,
+                        ..
+                    } => Expr::FunctionDeclaration {
+                        identifier: linked,
+                        parameters,
+                        body,
+                        return_type,
+                    },
+                    Expr::StructDeclaration { fields, .. } => Expr::StructDeclaration {
+                        identifier: linked,
+                        fields,
+                    },
+                    Expr::StaticDeclaration {
+                        var_type,
+                        value,
+                        mutable,
+                        ..
+                    } => Expr::StaticDeclaration {
+                        identifier: linked,
+                        var_type,
+                        value,
+                        mutable,
+                    },
+                    other => other,
+                }
+            }
+            other => self.rebuild_expr(other),
+        }
+    }
+
+    fn trait_method(&mut self, method: &TraitMethod) -> TraitMethod {
+        self.scopes.push(HashSet::new());
+        let method = TraitMethod {
+            identifier: method.identifier.clone(),
+            parameters: method
+                .parameters
+                .iter()
+                .map(|param| self.rebuild_expr(param))
+                .collect(),
+            return_type: method.return_type.as_ref().map(|t| self.rebuild_type(t)),
+            body: method
+                .body
+                .as_ref()
+                .map(|body| Box::new(self.rebuild_expr(body))),
+            doc: method.doc.clone(),
+        };
+        self.scopes.pop();
+
+        method
+    }
+
+    fn linked_name(&self, identifier: &str) -> String {
+        self.tree.modules[self.module]
+            .items
+            .get(identifier)
+            .cloned()
+            .unwrap_or_else(|| identifier.to_string())
+    }
+
+    fn resolve_name(&self, name: &str) -> String {
+        let segments: Vec<String> = name.split("::").map(String::from).collect();
+        self.tree
+            .lookup(self.module, &segments)
+            .unwrap_or_else(|| name.to_string())
+    }
+
+    /// A name used as a value, which becomes a path once it is qualified.
+    fn resolve_value(&self, segments: Vec<String>) -> Expr {
+        let linked = self.tree.lookup(self.module, &segments);
+        match linked {
+            Some(name) if name.contains("::") => {
+                Expr::Path(name.split("::").map(String::from).collect())
+            }
+            Some(name) => Expr::Literal(Nodes::Identifier(name)),
+            None if segments.len() == 1 => Expr::Literal(Nodes::Identifier(segments[0].clone())),
+            None => Expr::Path(segments),
+        }
+    }
+
+    fn is_local(&self, name: &str) -> bool {
+        self.scopes.iter().any(|scope| scope.contains(name))
+    }
+
+    fn declare(&mut self, name: &str) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name.to_string());
+        }
+    }
+}
+
+impl Rebuild for Resolver<'_> {
+    fn rebuild_type(&mut self, t: &Types) -> Types {
+        match t {
+            Types::Struct(name) => {
+                let linked = self.resolve_name(name);
+                if self.tree.is_alias(&linked) {
+                    Types::Alias(linked)
+                } else {
+                    Types::Struct(linked)
+                }
+            }
+            Types::Alias(name) => Types::Alias(self.resolve_name(name)),
+            Types::Dyn(name) => Types::Dyn(self.resolve_name(name)),
+            Types::Generic(name, arguments) => Types::Generic(
+                self.resolve_name(name),
+                arguments.iter().map(|arg| self.rebuild_type(arg)).collect(),
+            ),
+            Types::Pointer(pointee) => Types::Pointer(Box::new(self.rebuild_type(pointee))),
+            Types::Function(parameters, return_type) => Types::Function(
+                parameters.iter().map(|p| self.rebuild_type(p)).collect(),
+                Box::new(self.rebuild_type(return_type)),
+            ),
+            Types::Tuple(elements) => {
+                Types::Tuple(elements.iter().map(|e| self.rebuild_type(e)).collect())
+            }
+            Types::Option(value) => Types::Option(Box::new(self.rebuild_type(value))),
+            Types::Box(value) => Types::Box(Box::new(self.rebuild_type(value))),
+            Types::Result(value, error) => Types::Result(
+                Box::new(self.rebuild_type(value)),
+                Box::new(self.rebuild_type(error)),
+            ),
+            _ => t.clone(),
+        }
+    }
+
+    fn enter_node(&mut self, expr: &Expr) {
+        if matches!(
+            expr,
+            Expr::FunctionDeclaration { .. } | Expr::Closure { .. } | Expr::Block(_)
+        ) {
+            self.scopes.push(HashSet::new());
+        }
+    }
+
+    fn exit_node(&mut self, expr: &Expr) {
+        if matches!(
+            expr,
+            Expr::FunctionDeclaration { .. } | Expr::Closure { .. } | Expr::Block(_)
+        ) {
+            self.scopes.pop();
+        }
+    }
+
+    fn rebuild_node(&mut self, _original: &Expr, rebuilt: Expr) -> Expr {
+        match rebuilt {
+            Expr::Declaration { ref identifier, .. } => {
+                self.declare(identifier);
+                rebuilt
+            }
+            Expr::TupleDeclaration {
+                ref identifiers, ..
+            } => {
+                for identifier in identifiers {
+                    self.declare(identifier);
+                }
+                rebuilt
+            }
+            Expr::Literal(Nodes::Identifier(name)) if !self.is_local(&name) => {
+                self.resolve_value(vec![name])
+            }
+            Expr::Path(segments) => self.resolve_value(segments),
+            Expr::StructLiteral { identifier, fields } => Expr::StructLiteral {
+                identifier: self.resolve_name(&identifier),
+                fields,
+            },
+            Expr::ImplBlock { type_name, methods } => Expr::ImplBlock {
+                type_name: self.resolve_name(&type_name),
+                methods,
+            },
+            Expr::TraitImpl {
+                trait_name,
+                type_name,
+                methods,
+            } => Expr::TraitImpl {
+                trait_name: self.resolve_name(&trait_name),
+                type_name: self.resolve_name(&type_name),
+                methods,
+            },
+            other => other,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use std::path::Path;
+
+use crate::modules::core::module::Module;
+pub mod core;
+pub mod errors;
+pub mod impls;
+
+/// The extension of source files, used to find the file of a `mod`.
+const EXTENSION: &str = "cog";
+
+/// The index of the root module, the file the program was loaded from.
+const ROOT: usize = 0;
+
+/// The modules of a program: the file it was loaded from, and every file
+/// reached through its `mod` declarations. Items in a module are linked under
+/// their qualified names, such as `geo::Point`, so the whole tree compiles as
+/// one program.
+#[derive(Debug, Clone)]
+pub struct ModuleTree {
+    modules: Vec<Module>,
+}
+
+impl ModuleTree {
+    /// The files the tree was loaded from, the root first.
+    pub fn files(&self) -> impl Iterator<Item = &Path> {
+        self.modules.iter().map(|module| module.file.as_path())
+    }
+}
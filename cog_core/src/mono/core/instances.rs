@@ -0,0 +1,39 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    rc::Rc,
+};
+
+use cog_parser::parser::core::{expr::Expr, types::Types};
+
+/// What the checker inferred about a program that later stages need: the
+/// generic specializations it uses, the types of its closures and what its
+/// aliases stand for.
+#[derive(Debug, Default)]
+pub struct Instances {
+    /// Concrete copies of generic functions and of inherited trait default
+    /// methods, keyed by the name codegen declares them under.
+    pub functions: BTreeMap<String, Rc<Expr>>,
+    /// The type arguments inferred for each generic call and generic struct
+    /// literal, keyed by [`site_key`].
+    pub sites: HashMap<usize, Vec<Types>>,
+    /// Every checked closure, keyed by the [`site_key`] of the closure itself.
+    pub closures: HashMap<usize, ClosureInstance>,
+    /// The type each alias names, with any aliases inside it expanded too.
+    pub aliases: HashMap<String, Types>,
+}
+
+/// The signature the checker settled on for a closure, and what it captures.
+#[derive(Debug, Clone)]
+pub struct ClosureInstance {
+    pub parameters: Vec<Types>,
+    pub return_type: Types,
+    /// The enclosing variables the closure's body uses, in order of first use.
+    pub captures: Vec<String>,
+}
+
+/// Identifies a use site by address: the callee of a generic call, or the
+/// struct literal or closure itself. Keys stay valid as long as the checked statements
+/// are not moved or mutated.
+pub fn site_key(expr: &Expr) -> usize {
+    expr as *const Expr as usize
+}
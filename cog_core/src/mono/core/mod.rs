@@ -0,0 +1,3 @@
+pub mod instances;
+pub mod rebuild;
+pub mod subst;
@@ -0,0 +1,235 @@
+use cog_parser::parser::core::{
+    expr::{Expr, StructField},
+    types::Types,
+};
+
+/// A copy of an expression tree that may rewrite the types and nodes it
+/// passes through.
+pub trait Rebuild {
+    fn rebuild_type(&mut self, t: &Types) -> Types;
+
+    /// Rewrites a node once its children have been rebuilt. `original` is the
+    /// node the copy was made from.
+    fn rebuild_node(&mut self, _original: &Expr, rebuilt: Expr) -> Expr {
+        rebuilt
+    }
+
+    /// Called before the children of `expr` are rebuilt.
+    fn enter_node(&mut self, _expr: &Expr) {}
+
+    /// Called after the children of `expr` are rebuilt, before `rebuild_node`.
+    fn exit_node(&mut self, _expr: &Expr) {}
+
+    fn rebuild_expr(&mut self, expr: &Expr) -> Expr
+    where
+        Self: Sized,
+    {
+        self.enter_node(expr);
+        let rebuilt = rebuild_children(self, expr);
+        self.exit_node(expr);
+        self.rebuild_node(expr, rebuilt)
+    }
+}
+
+fn rebuild_children<R: Rebuild>(r: &mut R, expr: &Expr) -> Expr {
+    match expr {
+        Expr::Literal(_)
+        | Expr::Path(_)
+        | Expr::Generic { .. }
+        | Expr::TraitDeclaration { .. }
+        | Expr::ModuleDeclaration { .. }
+        | Expr::Import { .. }
+        | Expr::TypeAlias { .. } => expr.clone(),
+        Expr::Binary {
+            left,
+            operator,
+            right,
+        } => Expr::Binary {
+            left: rebuild_box(r, left),
+            operator: operator.clone(),
+            right: rebuild_box(r, right),
+        },
+        Expr::Unary { operator, operand } => Expr::Unary {
+            operator: operator.clone(),
+            operand: rebuild_box(r, operand),
+        },
+        Expr::Assignment { target, value } => Expr::Assignment {
+            target: rebuild_box(r, target),
+            value: rebuild_box(r, value),
+        },
+        Expr::CompoundAssignment {
+            target,
+            operator,
+            value,
+        } => Expr::CompoundAssignment {
+            target: rebuild_box(r, target),
+            operator: operator.clone(),
+            value: rebuild_box(r, value),
+        },
+        Expr::Declaration {
+            identifier,
+            var_type,
+            value,
+        } => Expr::Declaration {
+            identifier: identifier.clone(),
+            var_type: var_type.as_ref().map(|t| r.rebuild_type(t)),
+            value: rebuild_box(r, value),
+        },
+        Expr::StaticDeclaration {
+            identifier,
+            var_type,
+            value,
+            mutable,
+        } => Expr::StaticDeclaration {
+            identifier: identifier.clone(),
+            var_type: r.rebuild_type(var_type),
+            value: rebuild_box(r, value),
+            mutable: *mutable,
+        },
+        Expr::FunctionDeclaration {
+            identifier,
+            parameters,
+            body,
+            return_type,
+        } => Expr::FunctionDeclaration {
+            identifier: identifier.clone(),
+            parameters: rebuild_all(r, parameters),
+            body: rebuild_box(r, body),
+            return_type: return_type.as_ref().map(|t| r.rebuild_type(t)),
+        },
+        Expr::ExternFunctionDeclaration {
+            identifier,
+            parameters,
+            return_type,
+            is_variadic,
+        } => Expr::ExternFunctionDeclaration {
+            identifier: identifier.clone(),
+            parameters: rebuild_all(r, parameters),
+            return_type: return_type.as_ref().map(|t| r.rebuild_type(t)),
+            is_variadic: *is_variadic,
+        },
+        Expr::Call { callee, arguments } => Expr::Call {
+            callee: rebuild_box(r, callee),
+            arguments: rebuild_all(r, arguments),
+        },
+        Expr::Alloc(t) => Expr::Alloc(r.rebuild_type(t)),
+        Expr::Free(operand) => Expr::Free(rebuild_box(r, operand)),
+        Expr::BoxNew(value) => Expr::BoxNew(rebuild_box(r, value)),
+        Expr::Print {
+            pieces,
+            arguments,
+            newline,
+        } => Expr::Print {
+            pieces: pieces.clone(),
+            arguments: rebuild_all(r, arguments),
+            newline: *newline,
+        },
+        Expr::Return { value } => Expr::Return {
+            value: value.as_ref().map(|value| rebuild_box(r, value)),
+        },
+        Expr::Defer(deferred) => Expr::Defer(rebuild_box(r, deferred)),
+        Expr::Block(exprs) => Expr::Block(rebuild_all(r, exprs)),
+        Expr::IfElse {
+            condition,
+            then_branch,
+            else_branch,
+        } => Expr::IfElse {
+            condition: rebuild_box(r, condition),
+            then_branch: rebuild_box(r, then_branch),
+            else_branch: else_branch.as_ref().map(|branch| rebuild_box(r, branch)),
+        },
+        Expr::AddressOf(operand) => Expr::AddressOf(rebuild_box(r, operand)),
+        Expr::Dereference(operand) => Expr::Dereference(rebuild_box(r, operand)),
+        Expr::Cast { value, target } => Expr::Cast {
+            value: rebuild_box(r, value),
+            target: r.rebuild_type(target),
+        },
+        Expr::Unsafe(block) => Expr::Unsafe(rebuild_box(r, block)),
+        Expr::StructDeclaration { identifier, fields } => Expr::StructDeclaration {
+            identifier: identifier.clone(),
+            fields: fields
+                .iter()
+                .map(|field| StructField {
+                    identifier: field.identifier.clone(),
+                    field_type: r.rebuild_type(&field.field_type),
+                    doc: field.doc.clone(),
+                    public: field.public,
+                })
+                .collect(),
+        },
+        Expr::StructLiteral { identifier, fields } => Expr::StructLiteral {
+            identifier: identifier.clone(),
+            fields: fields
+                .iter()
+                .map(|(name, value)| (name.clone(), r.rebuild_expr(value)))
+                .collect(),
+        },
+        Expr::FieldAccess { object, field } => Expr::FieldAccess {
+            object: rebuild_box(r, object),
+            field: field.clone(),
+        },
+        Expr::ImplBlock { type_name, methods } => Expr::ImplBlock {
+            type_name: type_name.clone(),
+            methods: rebuild_all(r, methods),
+        },
+        Expr::TraitImpl {
+            trait_name,
+            type_name,
+            methods,
+        } => Expr::TraitImpl {
+            trait_name: trait_name.clone(),
+            type_name: type_name.clone(),
+            methods: rebuild_all(r, methods),
+        },
+        Expr::Tuple(elements) => Expr::Tuple(rebuild_all(r, elements)),
+        Expr::Variant { variant, value } => Expr::Variant {
+            variant: *variant,
+            value: value.as_ref().map(|value| rebuild_box(r, value)),
+        },
+        Expr::Try(operand) => Expr::Try(rebuild_box(r, operand)),
+        Expr::Documented { doc, item } => Expr::Documented {
+            doc: doc.clone(),
+            item: rebuild_box(r, item),
+        },
+        Expr::Public { item } => Expr::Public {
+            item: rebuild_box(r, item),
+        },
+        Expr::TupleDeclaration {
+            identifiers,
+            var_type,
+            value,
+        } => Expr::TupleDeclaration {
+            identifiers: identifiers.clone(),
+            var_type: var_type.as_ref().map(|t| r.rebuild_type(t)),
+            value: rebuild_box(r, value),
+        },
+        Expr::Closure {
+            parameters,
+            return_type,
+            body,
+            captures,
+        } => Expr::Closure {
+            parameters: rebuild_all(r, parameters),
+            return_type: return_type.as_ref().map(|t| r.rebuild_type(t)),
+            body: rebuild_box(r, body),
+            captures: captures.clone(),
+        },
+        Expr::MethodCall {
+            receiver,
+            method,
+            arguments,
+        } => Expr::MethodCall {
+            receiver: rebuild_box(r, receiver),
+            method: method.clone(),
+            arguments: rebuild_all(r, arguments),
+        },
+    }
+}
+
+fn rebuild_box<R: Rebuild>(r: &mut R, expr: &Expr) -> Box<Expr> {
+    Box::new(r.rebuild_expr(expr))
+}
+
+fn rebuild_all<R: Rebuild>(r: &mut R, exprs: &[Expr]) -> Vec<Expr> {
+    exprs.iter().map(|expr| r.rebuild_expr(expr)).collect()
+}
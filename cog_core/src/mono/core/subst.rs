@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use cog_parser::parser::core::types::Types;
+
+use crate::mono::core::rebuild::Rebuild;
+
+/// Replaces type parameters with the types they are bound to.
+pub fn substitute(t: &Types, bindings: &HashMap<String, Types>) -> Types {
+    match t {
+        Types::Param(name) => bindings.get(name).cloned().unwrap_or_else(|| t.clone()),
+        Types::Pointer(pointee) => Types::Pointer(Box::new(substitute(pointee, bindings))),
+        Types::Generic(name, arguments) => Types::Generic(
+            name.clone(),
+            arguments
+                .iter()
+                .map(|argument| substitute(argument, bindings))
+                .collect(),
+        ),
+        Types::Function(parameters, return_type) => Types::Function(
+            parameters
+                .iter()
+                .map(|parameter| substitute(parameter, bindings))
+                .collect(),
+            Box::new(substitute(return_type, bindings)),
+        ),
+        Types::Tuple(elements) => Types::Tuple(
+            elements
+                .iter()
+                .map(|element| substitute(element, bindings))
+                .collect(),
+        ),
+        Types::Option(value) => Types::Option(Box::new(substitute(value, bindings))),
+        Types::Box(value) => Types::Box(Box::new(substitute(value, bindings))),
+        Types::Result(value, error) => Types::Result(
+            Box::new(substitute(value, bindings)),
+            Box::new(substitute(error, bindings)),
+        ),
+        _ => t.clone(),
+    }
+}
+
+/// Copies an item with its type parameters replaced.
+pub struct Substitution<'a> {
+    bindings: &'a HashMap<String, Types>,
+}
+
+impl<'a> Substitution<'a> {
+    pub fn new(bindings: &'a HashMap<String, Types>) -> Self {
+        Self { bindings }
+    }
+}
+
+impl Rebuild for Substitution<'_> {
+    fn rebuild_type(&mut self, t: &Types) -> Types {
+        substitute(t, self.bindings)
+    }
+}
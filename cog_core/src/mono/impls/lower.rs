@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+
+use cog_parser::parser::core::{
+    expr::{Expr, StructField, TraitMethod},
+    nodes::Nodes,
+    types::Types,
+};
+
+use crate::{
+    codegen::core::mangle::mangle_instance,
+    mono::{
+        Monomorphizer,
+        core::{instances::site_key, rebuild::Rebuild, subst::substitute},
+    },
+};
+
+impl Rebuild for Monomorphizer<'_> {
+    /// Replaces every generic struct type with its specialization, and every
+    /// alias with the type it names.
+    fn rebuild_type(&mut self, t: &Types) -> Types {
+        match t {
+            Types::Alias(name) => {
+                let instances = self.instances;
+                self.rebuild_type(&instances.aliases[name])
+            }
+            Types::Pointer(pointee) => Types::Pointer(Box::new(self.rebuild_type(pointee))),
+            Types::Generic(name, arguments) => {
+                Types::Struct(self.instantiate_struct(name, arguments))
+            }
+            Types::Function(parameters, return_type) => Types::Function(
+                parameters
+                    .iter()
+                    .map(|parameter| self.rebuild_type(parameter))
+                    .collect(),
+                Box::new(self.rebuild_type(return_type)),
+            ),
+            Types::Tuple(elements) => Types::Tuple(
+                elements
+                    .iter()
+                    .map(|element| self.rebuild_type(element))
+                    .collect(),
+            ),
+            Types::Option(value) => Types::Option(Box::new(self.rebuild_type(value))),
+            Types::Box(value) => Types::Box(Box::new(self.rebuild_type(value))),
+            Types::Result(value, error) => Types::Result(
+                Box::new(self.rebuild_type(value)),
+                Box::new(self.rebuild_type(error)),
+            ),
+            _ => t.clone(),
+        }
+    }
+
+    fn rebuild_node(&mut self, original: &Expr, rebuilt: Expr) -> Expr {
+        match (original, rebuilt) {
+            // Doc comments have no bearing on the generated code.
+            (_, Expr::Documented { item, .. }) => *item,
+            // Traits are copied whole, but codegen lays out their vtables
+            // from the method signatures.
+            (
+                _,
+                Expr::TraitDeclaration {
+                    identifier,
+                    methods,
+                },
+            ) => Expr::TraitDeclaration {
+                identifier,
+                methods: methods
+                    .iter()
+                    .map(|method| TraitMethod {
+                        parameters: method
+                            .parameters
+                            .iter()
+                            .map(|param| self.rebuild_expr(param))
+                            .collect(),
+                        return_type: method.return_type.as_ref().map(|t| self.rebuild_type(t)),
+                        ..method.clone()
+                    })
+                    .collect(),
+            },
+            (Expr::Call { callee, .. }, Expr::Call { arguments, .. })
+                if self.instances.sites.contains_key(&site_key(callee)) =>
+            {
+                let type_arguments = &self.instances.sites[&site_key(callee)];
+                let name = match callee.as_ref() {
+                    Expr::Path(segments) => segments.join("::"),
+                    Expr::Literal(Nodes::Identifier(name)) => name.clone(),
+                    other => format!("{:?}", other),
+                };
+
+                Expr::Call {
+                    callee: Box::new(Expr::Literal(Nodes::Identifier(mangle_instance(
+                        &name,
+                        type_arguments,
+                    )))),
+                    arguments,
+                }
+            }
+            (Expr::StructLiteral { .. }, Expr::StructLiteral { identifier, fields })
+                if self.instances.sites.contains_key(&site_key(original)) =>
+            {
+                let type_arguments = self.instances.sites[&site_key(original)].clone();
+                Expr::StructLiteral {
+                    identifier: self.instantiate_struct(&identifier, &type_arguments),
+                    fields,
+                }
+            }
+            (
+                Expr::Closure { .. },
+                Expr::Closure {
+                    parameters, body, ..
+                },
+            ) if self.instances.closures.contains_key(&site_key(original)) => {
+                let closure = self.instances.closures[&site_key(original)].clone();
+                let parameters = parameters
+                    .into_iter()
+                    .zip(&closure.parameters)
+                    .map(|(parameter, param_type)| match parameter {
+                        Expr::Declaration {
+                            identifier, value, ..
+                        } => Expr::Declaration {
+                            identifier,
+                            var_type: Some(self.rebuild_type(param_type)),
+                            value,
+                        },
+                        other => other,
+                    })
+                    .collect();
+
+                Expr::Closure {
+                    parameters,
+                    return_type: Some(self.rebuild_type(&closure.return_type)),
+                    body,
+                    captures: closure.captures,
+                }
+            }
+            (_, rebuilt) => rebuilt,
+        }
+    }
+}
+
+impl Monomorphizer<'_> {
+    /// Emits the declaration of `name<arguments>` if it has not been emitted
+    /// yet, and returns its name.
+    fn instantiate_struct(&mut self, name: &str, arguments: &[Types]) -> String {
+        let symbol = mangle_instance(name, arguments);
+        if self.structs.contains_key(&symbol) {
+            return symbol;
+        }
+
+        // Reserve the name first so a struct that points to itself stops here.
+        self.structs.insert(
+            symbol.clone(),
+            Expr::StructDeclaration {
+                identifier: symbol.clone(),
+                fields: Vec::new(),
+            },
+        );
+
+        let Some((type_parameters, fields)) = self.generic_structs.get(name).cloned() else {
+            return symbol;
+        };
+        let bindings: HashMap<String, Types> = type_parameters
+            .into_iter()
+            .map(|param| param.identifier)
+            .zip(arguments.iter().cloned())
+            .collect();
+        let fields = fields
+            .iter()
+            .map(|field| StructField {
+                identifier: field.identifier.clone(),
+                field_type: self.rebuild_type(&substitute(&field.field_type, &bindings)),
+                doc: field.doc.clone(),
+                public: field.public,
+            })
+            .collect();
+
+        self.structs.insert(
+            symbol.clone(),
+            Expr::StructDeclaration {
+                identifier: symbol.clone(),
+                fields,
+            },
+        );
+
+        symbol
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::{BTreeMap, HashMap};
+
+use cog_parser::parser::core::expr::{Expr, StructField, TypeParameter};
+
+use crate::mono::core::{instances::Instances, rebuild::Rebuild};
+pub mod core;
+pub mod impls;
+
+/// Turns a checked program into one without generics: generic items are
+/// dropped, each specialization the checker recorded is emitted once, and
+/// generic uses are pointed at their specialization. Closures get the types
+/// and captures the checker inferred for them, aliases are replaced by the
+/// types they name, and doc comments are dropped.
+pub struct Monomorphizer<'a> {
+    instances: &'a Instances,
+    generic_structs: HashMap<String, (Vec<TypeParameter>, Vec<StructField>)>,
+    structs: BTreeMap<String, Expr>,
+}
+
+impl<'a> Monomorphizer<'a> {
+    pub fn new(instances: &'a Instances) -> Self {
+        Self {
+            instances,
+            generic_structs: HashMap::new(),
+            structs: BTreeMap::new(),
+        }
+    }
+
+    /// Specializations are emitted in mangled-name order, so the output does
+    /// not depend on the order in which they were discovered.
+    pub fn run(&mut self, statements: &[Expr]) -> Vec<Expr> {
+        for stmt in statements {
+            if let Expr::Generic {
+                type_parameters,
+                item,
+            } = stmt.item()
+                && let Expr::StructDeclaration { identifier, fields } = item.as_ref()
+            {
+                self.generic_structs.insert(
+                    identifier.clone(),
+                    (type_parameters.clone(), fields.clone()),
+                );
+            }
+        }
+
+        let mut program: Vec<Expr> = statements
+            .iter()
+            .filter(|stmt| !matches!(stmt.item(), Expr::Generic { .. } | Expr::TypeAlias { .. }))
+            .map(|stmt| self.rebuild_expr(stmt))
+            .collect();
+
+        let instances = self.instances;
+        for function in instances.functions.values() {
+            program.push(self.rebuild_expr(function));
+        }
+
+        let mut output: Vec<Expr> = std::mem::take(&mut self.structs).into_values().collect();
+        output.extend(program);
+
+        output
+    }
+}
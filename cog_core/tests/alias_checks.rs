@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod alias_checks_test {
+    use cog_core::check::{Checker, errors::CheckError};
+    use cog_parser::parser::{Parser, core::types::Types};
+
+    fn check(input: &str) -> Result<(), CheckError> {
+        let statements = Parser::new(input.to_string())
+            .expect("Failed to create parser")
+            .parse()
+            .expect("Failed to parse");
+        Checker::new().check(&statements)
+    }
+
+    fn handle() -> Types {
+        Types::Alias("Handle".into())
+    }
+
+    #[test]
+    fn aliases_stand_for_their_types() {
+        let input = "type Handle = *i32;
+        type Count = u64;
+        struct S { h: Handle, n: Count }
+        fn deref(h: Handle) -> i32 { unsafe { return *h; } }
+        fn count(s: *S) -> Count { return s.n + 1; }
+        fn main() -> i32 {
+            let x: i32 = 3;
+            let s = S { h: &x, n: 2 };
+            let n: u64 = count(&s);
+            return deref(s.h);
+        }";
+        assert_eq!(check(input), Ok(()));
+    }
+
+    #[test]
+    fn argument_names_parameter_alias() {
+        let input = "type Handle = *i32;
+        fn f(h: Handle) -> i32 { return 0; }
+        fn main() -> i32 { return f(3); }";
+        assert_eq!(
+            check(input),
+            Err(CheckError::MismatchedTypes(handle(), Types::I32))
+        );
+    }
+
+    #[test]
+    fn return_value_names_return_alias() {
+        let input = "type Handle = *i32;
+        fn g() -> Handle { return 1; }";
+        assert_eq!(
+            check(input),
+            Err(CheckError::MismatchedTypes(handle(), Types::I32))
+        );
+    }
+
+    #[test]
+    fn field_value_names_field_alias() {
+        let input = "type Handle = *i32;
+        struct S { h: Handle }
+        fn main() -> i32 { let s = S { h: 1 }; return 0; }";
+        assert_eq!(
+            check(input),
+            Err(CheckError::MismatchedTypes(handle(), Types::I32))
+        );
+    }
+
+    #[test]
+    fn static_value_names_static_alias() {
+        let input = "type Flag = bool;
+        static ON: Flag = 1;";
+        assert_eq!(
+            check(input),
+            Err(CheckError::MismatchedTypes(
+                Types::Alias("Flag".into()),
+                Types::I32
+            ))
+        );
+    }
+}
@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod closure_checks_test {
+    use cog_core::check::{Checker, errors::CheckError};
+    use cog_parser::parser::Parser;
+
+    fn check(input: &str) -> Result<(), CheckError> {
+        let statements = Parser::new(input.to_string())
+            .expect("Failed to create parser")
+            .parse()
+            .expect("Failed to parse");
+        Checker::new().check(&statements)
+    }
+
+    #[test]
+    fn return_closure_without_captures() {
+        let input = "fn make_double() -> fn(i32) -> i32 {
+            return |x: i32| x * 2;
+        }
+        fn main() -> i32 {
+            return make_double()(21);
+        }";
+        assert_eq!(check(input), Ok(()));
+    }
+
+    #[test]
+    fn return_capturing_closure() {
+        let input = "fn make_adder(n: i32) -> fn(i32) -> i32 {
+            return |x: i32| x + n;
+        }";
+        assert_eq!(
+            check(input),
+            Err(CheckError::EscapingClosure("closure".into()))
+        );
+    }
+
+    #[test]
+    fn return_variable_holding_capturing_closure() {
+        let input = "fn make_adder(n: i32) -> fn(i32) -> i32 {
+            let add = |x: i32| x + n;
+            let copy = add;
+            return copy;
+        }";
+        assert_eq!(
+            check(input),
+            Err(CheckError::EscapingClosure("copy".into()))
+        );
+    }
+
+    #[test]
+    fn return_capturing_closure_in_option() {
+        let input = "fn make_adder(n: i32) -> Option<fn(i32) -> i32> {
+            let add = |x: i32| x + n;
+            return Some(add);
+        }";
+        assert_eq!(
+            check(input),
+            Err(CheckError::EscapingClosure("closure".into()))
+        );
+    }
+
+    #[test]
+    fn return_capturing_closure_in_box() {
+        let input = "fn make_adder(n: i32) -> Box<fn(i32) -> i32> {
+            let add = Box::new(|x: i32| x + n);
+            return add;
+        }";
+        assert_eq!(check(input), Err(CheckError::EscapingClosure("add".into())));
+    }
+
+    #[test]
+    fn variable_reassigned_to_function() {
+        let input = "fn double(x: i32) -> i32 { return x * 2; }
+        fn make(n: i32) -> fn(i32) -> i32 {
+            let f = |x: i32| x + n;
+            let y = f(1);
+            f = double;
+            return f;
+        }";
+        assert_eq!(check(input), Ok(()));
+    }
+
+    #[test]
+    fn store_capturing_closure_through_pointer() {
+        let input = "fn set(slot: *fn(i32) -> i32, n: i32) {
+            unsafe {
+                *slot = |x: i32| x + n;
+            }
+        }";
+        assert_eq!(
+            check(input),
+            Err(CheckError::EscapingClosure("closure".into()))
+        );
+    }
+
+    #[test]
+    fn store_capturing_closure_in_field() {
+        let input = "struct Hook { f: fn(i32) -> i32 }
+        fn set(hook: *Hook, n: i32) {
+            let add = |x: i32| x + n;
+            hook.f = add;
+        }";
+        assert_eq!(check(input), Err(CheckError::EscapingClosure("add".into())));
+    }
+
+    #[test]
+    fn capturing_closure_used_in_its_frame() {
+        let input = "struct Hook { f: fn(i32) -> i32 }
+        fn apply(f: fn(i32) -> i32, x: i32) -> i32 { return f(x); }
+        fn main() -> i32 {
+            let n = 1;
+            let add = |x: i32| x + n;
+            let hook = Hook { f: add };
+            let f = hook.f;
+            return apply(add, 1) + f(2);
+        }";
+        assert_eq!(check(input), Ok(()));
+    }
+
+    #[test]
+    fn closure_returns_captured_closure() {
+        let input = "fn main() -> i32 {
+            let n = 1;
+            let add = |x: i32| x + n;
+            let get = || -> fn(i32) -> i32 { return add; };
+            return get()(2);
+        }";
+        assert_eq!(check(input), Ok(()));
+    }
+}
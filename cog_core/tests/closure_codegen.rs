@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod closure_codegen_test {
+    use cog_core::{check::Checker, codegen::CodeGen, mono::Monomorphizer};
+    use cog_parser::parser::Parser;
+    use inkwell::context::Context;
+
+    /// Compiles a checked program and returns its LLVM IR.
+    fn compile(input: &str) -> String {
+        let statements = Parser::new(input.to_string())
+            .expect("Failed to create parser")
+            .parse()
+            .expect("Failed to parse");
+        let mut checker = Checker::new();
+        checker.check(&statements).expect("Failed to check");
+        let statements = Monomorphizer::new(checker.instances()).run(&statements);
+
+        let context = Context::create();
+        let mut codegen = CodeGen::new(&context, "test");
+        codegen.compile(&statements).expect("Failed to compile");
+        codegen.lvvm_module.print_to_string().to_string()
+    }
+
+    /// The IR of the function `name`.
+    fn function<'a>(ir: &'a str, name: &str) -> &'a str {
+        let start = ir
+            .find(&format!("@{}(", name))
+            .unwrap_or_else(|| panic!("no `{}` in:\n{}", name, ir));
+        let function = &ir[start..];
+        let end = function.find("\n}").unwrap_or(function.len());
+        &function[..end]
+    }
+
+    #[test]
+    fn call_closure_returned_from_function() {
+        let ir = compile(
+            "fn make_double() -> fn(i32) -> i32 {
+                return |x: i32| x * 2;
+            }
+
+            fn main() -> i32 {
+                return make_double()(21);
+            }",
+        );
+
+        // Without captures the closure has no environment to outlive.
+        let make_double = function(&ir, "make_double");
+        assert!(!make_double.contains("alloca"), "{}", make_double);
+        assert!(make_double.contains("ptr null"), "{}", make_double);
+
+        let main = function(&ir, "main");
+        assert!(
+            main.contains("call { ptr, ptr } @make_double()"),
+            "{}",
+            main
+        );
+        assert!(
+            main.contains("call i32 %code(ptr %env, i32 21)"),
+            "{}",
+            main
+        );
+    }
+
+    #[test]
+    fn environment_lives_in_creating_frame() {
+        let ir = compile(
+            "fn apply(f: fn(i32) -> i32, x: i32) -> i32 {
+                return f(x);
+            }
+
+            fn main() -> i32 {
+                let n = 1;
+                return apply(|x: i32| x + n, 2);
+            }",
+        );
+
+        let main = function(&ir, "main");
+        assert!(main.contains("%env = alloca { i32 }"), "{}", main);
+    }
+}
@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod compound_codegen_test {
+    use cog_core::{check::Checker, codegen::CodeGen};
+    use cog_parser::parser::Parser;
+    use inkwell::context::Context;
+
+    /// Compiles a checked program and returns its LLVM IR.
+    fn compile(input: &str) -> String {
+        let statements = Parser::new(input.to_string())
+            .expect("Failed to create parser")
+            .parse()
+            .expect("Failed to parse");
+        Checker::new().check(&statements).expect("Failed to check");
+
+        let context = Context::create();
+        let mut codegen = CodeGen::new(&context, "test");
+        codegen.compile(&statements).expect("Failed to compile");
+        codegen.lvvm_module.print_to_string().to_string()
+    }
+
+    #[test]
+    fn value_is_compiled_before_the_target_is_loaded() {
+        let ir = compile(
+            "fn main() -> i32 {
+                let x = 1;
+                x += x++;
+                return x;
+            }",
+        );
+
+        let increment = ir.find("%inc = ");
+        let current = ir.find("%current = load");
+        assert!(
+            matches!((increment, current), (Some(increment), Some(current)) if increment < current),
+            "`x` is loaded before `x++` updates it:\n{}",
+            ir
+        );
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod defer_checks_test {
+    use cog_core::check::{Checker, errors::CheckError};
+    use cog_parser::parser::Parser;
+
+    fn check(input: &str) -> Result<(), CheckError> {
+        let statements = Parser::new(input.to_string())
+            .expect("Failed to create parser")
+            .parse()
+            .expect("Failed to parse");
+        Checker::new().check(&statements)
+    }
+
+    #[test]
+    fn use_after_deferred_free() {
+        let input = "fn main() -> i32 {
+            let p = alloc<i32>();
+            defer free(p);
+            *p = 1;
+            return *p;
+        }";
+        assert_eq!(check(input), Ok(()));
+    }
+
+    #[test]
+    fn double_deferred_free() {
+        let input = "fn main() -> i32 {
+            let p = alloc<i32>();
+            defer free(p);
+            defer free(p);
+            return 0;
+        }";
+        assert_eq!(check(input), Err(CheckError::DoubleFree("p".into())));
+    }
+
+    #[test]
+    fn free_with_deferred_free_pending() {
+        let input = "fn main() -> i32 {
+            let p = alloc<i32>();
+            defer free(p);
+            free(p);
+            return 0;
+        }";
+        assert_eq!(check(input), Err(CheckError::DoubleFree("p".into())));
+    }
+
+    #[test]
+    fn deferred_free_applies_when_its_block_is_left() {
+        let input = "fn main() -> i32 {
+            let p = alloc<i32>();
+            {
+                defer free(p);
+                *p = 1;
+            }
+            return *p;
+        }";
+        assert_eq!(check(input), Err(CheckError::UseAfterFree("p".into())));
+    }
+
+    #[test]
+    fn return_in_defer() {
+        let input = "fn main() -> i32 { defer { return 1; }; return 0; }";
+        assert_eq!(check(input), Err(CheckError::ReturnInDefer));
+    }
+}
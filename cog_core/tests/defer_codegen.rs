@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod defer_codegen_test {
+    use cog_core::{check::Checker, codegen::CodeGen, mono::Monomorphizer};
+    use cog_parser::parser::Parser;
+    use inkwell::context::Context;
+
+    /// Compiles a checked program and returns its LLVM IR.
+    fn compile(input: &str) -> String {
+        let statements = Parser::new(input.to_string())
+            .expect("Failed to create parser")
+            .parse()
+            .expect("Failed to parse");
+        let mut checker = Checker::new();
+        checker.check(&statements).expect("Failed to check");
+        let statements = Monomorphizer::new(checker.instances()).run(&statements);
+
+        let context = Context::create();
+        let mut codegen = CodeGen::new(&context, "test");
+        codegen.compile(&statements).expect("Failed to compile");
+        codegen.lvvm_module.print_to_string().to_string()
+    }
+
+    /// The instructions of the basic block labelled `label`.
+    fn block<'a>(ir: &'a str, label: &str) -> &'a str {
+        let start = ir
+            .find(&format!("\n{}:", label))
+            .unwrap_or_else(|| panic!("no `{}` block in:\n{}", label, ir));
+        let block = &ir[start + 1..];
+        let end = block.find("\n\n").unwrap_or(block.len());
+        &block[..end]
+    }
+
+    #[test]
+    fn try_runs_deferred_expressions_before_returning() {
+        let ir = compile(
+            "fn cleanup() {}
+
+            fn half(x: i32) -> Option<i32> {
+                if x % 2 == 0 {
+                    return Some(x / 2);
+                }
+                return None;
+            }
+
+            fn quarter(x: i32) -> Option<i32> {
+                defer cleanup();
+                let h = half(x)?;
+                return half(h);
+            }
+
+            fn main() -> i32 {
+                return 0;
+            }",
+        );
+
+        let fail = block(&ir, "try_fail");
+        let cleanup = fail.find("call void @cleanup()");
+        let ret = fail.find("ret ");
+        assert!(
+            matches!((cleanup, ret), (Some(cleanup), Some(ret)) if cleanup < ret),
+            "`try_fail` does not run the deferred call before returning:\n{}",
+            fail
+        );
+    }
+}
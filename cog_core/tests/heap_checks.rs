@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod heap_checks_test {
+    use cog_core::check::{Checker, errors::CheckError};
+    use cog_parser::parser::Parser;
+
+    fn check(input: &str) -> Result<(), CheckError> {
+        let statements = Parser::new(input.to_string())
+            .expect("Failed to create parser")
+            .parse()
+            .expect("Failed to parse");
+        Checker::new().check(&statements)
+    }
+
+    #[test]
+    fn alloc_and_free() {
+        let input = "fn main() -> i32 {
+            let p = alloc<i32>();
+            *p = 1;
+            free(p);
+            p = alloc<i32>();
+            *p = 2;
+            let x = *p;
+            free(p);
+            return x;
+        }";
+        assert_eq!(check(input), Ok(()));
+    }
+
+    #[test]
+    fn double_free() {
+        let input = "fn main() -> i32 {
+            let p = alloc<i32>();
+            free(p);
+            free(p);
+            return 0;
+        }";
+        assert_eq!(check(input), Err(CheckError::DoubleFree("p".into())));
+    }
+
+    #[test]
+    fn use_after_free() {
+        let input = "fn main() -> i32 {
+            let p = alloc<i32>();
+            free(p);
+            return *p;
+        }";
+        assert_eq!(check(input), Err(CheckError::UseAfterFree("p".into())));
+    }
+
+    #[test]
+    fn use_after_free_in_one_branch() {
+        let input = "fn main() -> i32 {
+            let p = alloc<i32>();
+            *p = 1;
+            if *p > 0 {
+                free(p);
+            }
+            return *p;
+        }";
+        assert_eq!(check(input), Err(CheckError::UseAfterFree("p".into())));
+    }
+
+    #[test]
+    fn free_in_branch_that_returns() {
+        let input = "fn main() -> i32 {
+            let p = alloc<i32>();
+            *p = 1;
+            if *p > 0 {
+                free(p);
+                return 0;
+            }
+            let x = *p;
+            free(p);
+            return x;
+        }";
+        assert_eq!(check(input), Ok(()));
+    }
+
+    #[test]
+    fn use_after_move() {
+        let input = "fn main() -> i32 {
+            let a = Box::new(1);
+            let b = a;
+            let c = a;
+            return 0;
+        }";
+        assert_eq!(check(input), Err(CheckError::UseAfterMove("a".into())));
+    }
+
+    #[test]
+    fn use_after_move_into_call() {
+        let input = "fn take(b: Box<i32>) {}
+        fn main() -> i32 {
+            let a = Box::new(1);
+            take(a);
+            take(a);
+            return 0;
+        }";
+        assert_eq!(check(input), Err(CheckError::UseAfterMove("a".into())));
+    }
+
+    #[test]
+    fn function_named_like_builtin() {
+        let input = "fn print(x: i32) {}";
+        assert_eq!(check(input), Err(CheckError::ReservedName("print".into())));
+    }
+
+    #[test]
+    fn generic_function_named_like_builtin() {
+        let input = "fn alloc<T>(value: T) -> T { return value; }";
+        assert_eq!(check(input), Err(CheckError::ReservedName("alloc".into())));
+    }
+
+    #[test]
+    fn extern_named_like_builtin() {
+        let input = "extern fn free(p: *i8);";
+        assert_eq!(check(input), Err(CheckError::ReservedName("free".into())));
+    }
+
+    #[test]
+    fn function_named_like_malloc() {
+        let input = "fn malloc(size: u64) -> i32 { return 0; }
+        fn main() -> i32 {
+            let p = alloc<i32>();
+            free(p);
+            return 0;
+        }";
+        assert_eq!(check(input), Err(CheckError::ReservedName("malloc".into())));
+    }
+}
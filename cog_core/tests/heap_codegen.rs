@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod heap_codegen_test {
+    use cog_core::{
+        check::Checker,
+        codegen::{CodeGen, errors::CodeGenError},
+        mono::Monomorphizer,
+    };
+    use cog_parser::parser::Parser;
+    use inkwell::context::Context;
+
+    /// Compiles a checked program and returns its LLVM IR.
+    fn compile(input: &str) -> Result<String, CodeGenError> {
+        let statements = Parser::new(input.to_string())
+            .expect("Failed to create parser")
+            .parse()
+            .expect("Failed to parse");
+        let mut checker = Checker::new();
+        checker.check(&statements).expect("Failed to check");
+        let statements = Monomorphizer::new(checker.instances()).run(&statements);
+
+        let context = Context::create();
+        let mut codegen = CodeGen::new(&context, "test");
+        codegen.compile(&statements)?;
+        Ok(codegen.lvvm_module.print_to_string().to_string())
+    }
+
+    #[test]
+    fn alloc_and_free_call_libc() {
+        let ir = compile(
+            "fn main() -> i32 {
+                let p = alloc<i64>();
+                *p = 1;
+                free(p);
+                return 0;
+            }",
+        )
+        .expect("Failed to compile");
+
+        assert!(ir.contains("call ptr @malloc("), "{}", ir);
+        assert!(ir.contains("call void @free(ptr"), "{}", ir);
+    }
+
+    #[test]
+    fn extern_malloc_of_another_type() {
+        let result = compile(
+            "extern fn malloc(size: i32) -> i32;
+            fn main() -> i32 {
+                let p = alloc<i32>();
+                free(p);
+                return 0;
+            }",
+        );
+
+        assert_eq!(
+            result,
+            Err(CodeGenError::MismatchedRuntimeFunction("malloc".into()))
+        );
+    }
+}
@@ -0,0 +1,126 @@
+#[cfg(test)]
+mod int_checks_test {
+    use cog_core::check::{Checker, errors::CheckError};
+    use cog_parser::parser::{Parser, core::types::Types};
+
+    fn check(input: &str) -> Result<(), CheckError> {
+        let statements = Parser::new(input.to_string())
+            .expect("Failed to create parser")
+            .parse()
+            .expect("Failed to parse");
+        Checker::new().check(&statements)
+    }
+
+    fn check_main(body: &str) -> Result<(), CheckError> {
+        check(&format!("fn main() -> i32 {{ {} return 0; }}", body))
+    }
+
+    #[test]
+    fn expected_type_reaches_literal_operands() {
+        let input = "let x: i64 = 1 + 4;
+            let b: u8 = 1 << 4;
+            let f: f32 = 1.0 + 2.0;
+            let m: u16 = ~0 & 0xFF;
+            let n: i8 = -(2 * 3);
+            let s: u64 = (1 << 40) | 1;";
+        assert_eq!(check_main(input), Ok(()));
+    }
+
+    #[test]
+    fn expected_type_reaches_literals_beside_typed_operand() {
+        let input = "let a: i64 = 3;
+            let x: i64 = (1 + 2) * a;
+            let y: i64 = a + (1 << 40);";
+        assert_eq!(check_main(input), Ok(()));
+    }
+
+    #[test]
+    fn expected_type_reaches_return_and_arguments() {
+        let input = "fn mask(bits: u32) -> u32 { return bits & (0xFF << 8); }
+        fn main() -> i32 {
+            let m = mask(0xFFFF_FFFF ^ 1);
+            return 0;
+        }";
+        assert_eq!(check(input), Ok(()));
+    }
+
+    #[test]
+    fn literal_tree_out_of_range_for_expected_type() {
+        let input = "let b: u8 = 200 + 256;";
+        assert_eq!(
+            check_main(input),
+            Err(CheckError::IntegerOutOfRange("256".into(), Types::U8))
+        );
+    }
+
+    #[test]
+    fn pointer_difference_keeps_its_type() {
+        let input = "fn main() -> i32 {
+            let a: i32 = 1;
+            let p: *i32 = &a;
+            unsafe {
+                let q = p + 1;
+                let d: isize = q - p;
+            }
+            return 0;
+        }";
+        assert_eq!(check(input), Ok(()));
+    }
+
+    #[test]
+    fn signed_and_unsigned_operands() {
+        let input = "let a: i32 = 1; let b: u32 = 2; let c = a + b;";
+        assert_eq!(
+            check_main(input),
+            Err(CheckError::MixedSignedness(Types::I32, Types::U32))
+        );
+    }
+
+    #[test]
+    fn signed_operand_for_unsigned_result() {
+        let input = "let a: i32 = 1; let c: u32 = a + 1;";
+        assert_eq!(
+            check_main(input),
+            Err(CheckError::MixedSignedness(Types::U32, Types::I32))
+        );
+    }
+
+    #[test]
+    fn unsigned_operand_for_signed_result() {
+        let input = "let a: u8 = 1; let c: i64 = 1 + a;";
+        assert_eq!(
+            check_main(input),
+            Err(CheckError::MixedSignedness(Types::I64, Types::U8))
+        );
+    }
+
+    #[test]
+    fn wider_operand_for_narrower_result() {
+        let input = "let a: i64 = 1; let c: i32 = a * 2;";
+        assert_eq!(
+            check_main(input),
+            Err(CheckError::MismatchedTypes(Types::I32, Types::I64))
+        );
+    }
+
+    #[test]
+    fn negative_literal_for_unsigned_type() {
+        let input = "let c: u8 = 1 - -1;";
+        assert_eq!(
+            check_main(input),
+            Err(CheckError::MixedSignedness(Types::U8, Types::I32))
+        );
+    }
+
+    #[test]
+    fn shift_amount_of_another_type() {
+        let input = "let n: u8 = 3; let x: i64 = 1 << n;";
+        assert_eq!(check_main(input), Ok(()));
+    }
+
+    #[test]
+    fn unsigned_shift_amount_for_signed_value() {
+        let input = "let n: i8 = 3; let x: u16 = 1; let y = x >> n;";
+        assert_eq!(check_main(input), Ok(()));
+    }
+}
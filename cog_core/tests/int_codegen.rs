@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod int_codegen_test {
+    use cog_core::{check::Checker, codegen::CodeGen, mono::Monomorphizer};
+    use cog_parser::parser::Parser;
+    use inkwell::context::Context;
+
+    /// Compiles a checked program and returns its LLVM IR.
+    fn compile(input: &str) -> String {
+        let statements = Parser::new(input.to_string())
+            .expect("Failed to create parser")
+            .parse()
+            .expect("Failed to parse");
+        let mut checker = Checker::new();
+        checker.check(&statements).expect("Failed to check");
+        let statements = Monomorphizer::new(checker.instances()).run(&statements);
+
+        let context = Context::create();
+        let mut codegen = CodeGen::new(&context, "test");
+        codegen.compile(&statements).expect("Failed to compile");
+        codegen.lvvm_module.print_to_string().to_string()
+    }
+
+    #[test]
+    fn literal_operands_take_the_expected_type() {
+        let ir = compile(
+            "fn main() -> i32 {
+                let x: i64 = 1 + 4;
+                let b: u8 = 1 << 4;
+                let f: f32 = 1.0 + 2.0;
+                let m: u16 = ~0 & 0xFF;
+                return 0;
+            }",
+        );
+
+        assert!(ir.contains("store i64 5, ptr %x"), "{}", ir);
+        assert!(ir.contains("store i8 16, ptr %b"), "{}", ir);
+        assert!(ir.contains("store float 3.000000e+00, ptr %f"), "{}", ir);
+        assert!(ir.contains("store i16 255, ptr %m"), "{}", ir);
+    }
+
+    #[test]
+    fn literals_beside_typed_operand() {
+        let ir = compile(
+            "fn scale(a: i64) -> i64 {
+                return (1 + 2) * a;
+            }
+
+            fn main() -> i32 { return 0; }",
+        );
+
+        assert!(ir.contains("mul i64 3, %"), "{}", ir);
+    }
+}
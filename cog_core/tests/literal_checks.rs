@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod literal_checks_test {
+    use cog_core::check::{Checker, errors::CheckError};
+    use cog_parser::parser::{Parser, core::types::Types};
+
+    fn check(input: &str) -> Result<(), CheckError> {
+        let statements = Parser::new(input.to_string())
+            .expect("Failed to create parser")
+            .parse()
+            .expect("Failed to parse");
+        Checker::new().check(&statements)
+    }
+
+    fn out_of_range(literal: &str, t: Types) -> Result<(), CheckError> {
+        Err(CheckError::IntegerOutOfRange(literal.into(), t))
+    }
+
+    #[test]
+    fn literals_at_the_limits_of_their_type() {
+        let input = "fn main() -> i32 {
+            let a: u8 = 0xFF;
+            let b: i8 = -128;
+            let c: u64 = 0xFFFF_FFFF_FFFF_FFFF;
+            let d: i64 = -9_223_372_036_854_775_808;
+            let e = -2147483648;
+            return 2147483647;
+        }";
+        assert_eq!(check(input), Ok(()));
+    }
+
+    #[test]
+    fn literal_too_wide_for_declared_type() {
+        let input = "fn main() -> i32 { let x: u8 = 0x1FF; return 0; }";
+        assert_eq!(check(input), out_of_range("511", Types::U8));
+    }
+
+    #[test]
+    fn untyped_literal_too_wide_for_i32() {
+        let input = "fn main() -> i32 { let y = 0xFFFF_FFFF_FF; return 0; }";
+        assert_eq!(check(input), out_of_range("1099511627775", Types::I32));
+    }
+
+    #[test]
+    fn negated_literal_below_minimum() {
+        let input = "fn main() -> i32 { let x: i8 = -129; return 0; }";
+        assert_eq!(check(input), out_of_range("-129", Types::I8));
+    }
+
+    #[test]
+    fn literal_operand_takes_the_other_operand_type() {
+        let input = "fn main() -> i32 { let a: u8 = 1; let b: u8 = a + 256; return 0; }";
+        assert_eq!(check(input), out_of_range("256", Types::U8));
+    }
+}
@@ -0,0 +1,198 @@
+#[cfg(test)]
+mod module_checks_test {
+    use std::{fs, path::PathBuf};
+
+    use cog_core::{
+        check::{Checker, errors::CheckError},
+        modules::{ModuleTree, errors::ModuleError},
+    };
+    use cog_parser::parser::core::expr::Expr;
+
+    /// Writes `files` into a directory of their own, named after the test.
+    /// The program is rooted at `main.cog`.
+    fn write(test: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("cog_{}_{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        for (name, source) in files {
+            let file = dir.join(name);
+            fs::create_dir_all(file.parent().expect("file has a parent"))
+                .expect("Failed to create module directory");
+            fs::write(file, source).expect("Failed to write module");
+        }
+        dir
+    }
+
+    fn link(test: &str, files: &[(&str, &str)]) -> Result<Vec<Expr>, ModuleError> {
+        let dir = write(test, files);
+        ModuleTree::load(&dir.join("main.cog"))?.link()
+    }
+
+    fn check(test: &str, files: &[(&str, &str)]) -> Result<(), CheckError> {
+        let statements = link(test, files).expect("Failed to link");
+        Checker::new().check(&statements)
+    }
+
+    /// The names of the functions and structs in a linked program.
+    fn item_names(program: &[Expr]) -> Vec<String> {
+        program
+            .iter()
+            .filter_map(|stmt| match stmt.item() {
+                Expr::FunctionDeclaration { identifier, .. }
+                | Expr::StructDeclaration { identifier, .. } => Some(identifier.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn items_are_linked_under_qualified_names() {
+        let program = link(
+            "qualified_names",
+            &[
+                ("main.cog", "mod geo;\nfn main() -> i32 { return 0; }"),
+                ("geo.cog", "mod shapes;\npub fn unit() -> i32 { return 1; }"),
+                ("geo/shapes.cog", "pub struct Circle { pub r: i32 }"),
+            ],
+        )
+        .expect("Failed to link");
+
+        assert_eq!(
+            item_names(&program),
+            vec!["main", "geo::unit", "geo::shapes::Circle"]
+        );
+    }
+
+    #[test]
+    fn paths_and_imports_resolve() {
+        let files = [
+            (
+                "main.cog",
+                "mod geo;
+                import geo::shapes::Circle;
+                import geo::shapes;
+                fn main() -> i32 {
+                    let c = Circle { r: 2 };
+                    let d: shapes::Circle = geo::shapes::Circle { r: 3 };
+                    return geo::unit() + shapes::area(&c) + d.r;
+                }",
+            ),
+            ("geo.cog", "mod shapes;\npub fn unit() -> i32 { return 1; }"),
+            (
+                "geo/shapes.cog",
+                "pub struct Circle { pub r: i32 }
+                pub fn area(c: *Circle) -> i32 { return c.r * c.r * 3; }",
+            ),
+        ];
+        assert_eq!(check("paths_and_imports", &files), Ok(()));
+    }
+
+    #[test]
+    fn same_name_in_two_modules() {
+        let files = [
+            (
+                "main.cog",
+                "mod util;
+                fn helper() -> i32 { return 1; }
+                fn main() -> i32 { return helper() + util::helper(2); }",
+            ),
+            (
+                "util.cog",
+                "pub fn helper(x: i32) -> i32 { return twice(x); }
+                fn twice(x: i32) -> i32 { return x * 2; }",
+            ),
+        ];
+        assert_eq!(check("same_name", &files), Ok(()));
+    }
+
+    #[test]
+    fn module_sees_root_items() {
+        let files = [
+            ("main.cog", "mod util;\npub fn base() -> i32 { return 1; }"),
+            ("util.cog", "pub fn next() -> i32 { return base() + 1; }"),
+        ];
+        assert_eq!(check("root_items", &files), Ok(()));
+    }
+
+    #[test]
+    fn extern_declared_in_several_modules_is_linked_once() {
+        let program = link(
+            "duplicate_extern",
+            &[
+                ("main.cog", "mod a;\nextern fn puts(s: *i8) -> i32;"),
+                ("a.cog", "extern fn puts(s: *i8) -> i32;"),
+            ],
+        )
+        .expect("Failed to link");
+
+        let externs = program
+            .iter()
+            .filter(|stmt| matches!(stmt.item(), Expr::ExternFunctionDeclaration { .. }))
+            .count();
+        assert_eq!(externs, 1);
+    }
+
+    #[test]
+    fn missing_module_file() {
+        let dir = write("missing_module", &[("main.cog", "mod geo;")]);
+        let result = ModuleTree::load(&dir.join("main.cog")).map(|_| ());
+        assert_eq!(
+            result,
+            Err(ModuleError::ModuleNotFound(
+                "geo".into(),
+                dir.join("geo.cog").display().to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn module_declared_twice() {
+        let result = link(
+            "module_twice",
+            &[("main.cog", "mod a;\nmod a;"), ("a.cog", "")],
+        );
+        assert_eq!(result, Err(ModuleError::DuplicateModule("a".into())));
+    }
+
+    #[test]
+    fn unresolved_import() {
+        let result = link(
+            "unresolved_import",
+            &[
+                ("main.cog", "mod geo;\nimport geo::Square;"),
+                ("geo.cog", ""),
+            ],
+        );
+        assert_eq!(
+            result,
+            Err(ModuleError::UnresolvedImport("geo::Square".into()))
+        );
+    }
+
+    #[test]
+    fn parse_error_names_its_file() {
+        let dir = write(
+            "parse_error",
+            &[("main.cog", "mod geo;"), ("geo.cog", "fn broken( {")],
+        );
+        let result = ModuleTree::load(&dir.join("main.cog")).map(|_| ());
+        assert!(matches!(
+            result,
+            Err(ModuleError::Parse(file, _)) if file == dir.join("geo.cog").display().to_string()
+        ));
+    }
+
+    #[test]
+    fn undefined_item_of_module() {
+        let files = [
+            (
+                "main.cog",
+                "mod geo;\nfn main() -> i32 { return geo::nope(); }",
+            ),
+            ("geo.cog", "pub fn unit() -> i32 { return 1; }"),
+        ];
+        assert!(matches!(
+            check("undefined_item", &files),
+            Err(CheckError::UndefinedFunction(_))
+        ));
+    }
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod operator_checks_test {
+    use cog_core::check::{Checker, errors::CheckError};
+    use cog_parser::parser::{
+        Parser,
+        core::{
+            ops::{BinaryOp, UnaryOp},
+            types::Types,
+        },
+    };
+
+    const VECTOR: &str = "
+        struct V { x: i32, y: i32 }
+
+        impl Add for V {
+            fn add(self: V, other: V) -> V {
+                return V { x: self.x + other.x, y: self.y + other.y };
+            }
+        }
+        impl Neg for V {
+            fn neg(self: V) -> V {
+                return V { x: -self.x, y: -self.y };
+            }
+        }
+        impl Eq for V {
+            fn eq(self: V, other: V) -> bool {
+                return self.x == other.x && self.y == other.y;
+            }
+        }
+        impl Ord for V {
+            fn cmp(self: V, other: V) -> i32 {
+                return self.x - other.x;
+            }
+        }
+    ";
+
+    fn check(body: &str) -> Result<(), CheckError> {
+        check_with("", body)
+    }
+
+    fn check_with(items: &str, body: &str) -> Result<(), CheckError> {
+        let input = format!(
+            "{} {} fn main() -> i32 {{ {} return 0; }}",
+            VECTOR, items, body
+        );
+        let statements = Parser::new(input)
+            .expect("Failed to create parser")
+            .parse()
+            .expect("Failed to parse");
+        Checker::new().check(&statements)
+    }
+
+    fn vector() -> Types {
+        Types::Struct("V".into())
+    }
+
+    #[test]
+    fn overloaded_operators() {
+        let body = "let a = V { x: 1, y: 2 };
+            let b: V = a + V { x: 3, y: 4 };
+            b += a;
+            let c: V = -b;
+            let same: bool = a == c || a != b;
+            let less: bool = a < b;";
+        assert_eq!(check(body), Ok(()));
+    }
+
+    #[test]
+    fn operator_without_impl() {
+        let body = "let a = V { x: 1, y: 2 }; let b = a * a;";
+        assert_eq!(
+            check(body),
+            Err(CheckError::InvalidBinaryOperand(
+                BinaryOp::Multiply,
+                vector()
+            ))
+        );
+    }
+
+    #[test]
+    fn unary_operator_without_impl() {
+        let body = "let a = V { x: 1, y: 2 }; let b = !a;";
+        assert_eq!(
+            check(body),
+            Err(CheckError::InvalidUnaryOperand(UnaryOp::Not, vector()))
+        );
+    }
+
+    #[test]
+    fn wrong_right_hand_side_type() {
+        let body = "let a = V { x: 1, y: 2 }; let b = a + 1;";
+        assert_eq!(
+            check(body),
+            Err(CheckError::MismatchedTypes(vector(), Types::I32))
+        );
+    }
+
+    #[test]
+    fn overload_through_alias() {
+        let items = "type Vector = V; fn origin() -> Vector { return V { x: 0, y: 0 }; }";
+        let body = "let a: Vector = origin() + origin(); let b: bool = origin() == a;";
+        assert_eq!(check_with(items, body), Ok(()));
+    }
+
+    #[test]
+    fn generic_struct_without_impl() {
+        let body = "let p = Pair { first: 1 }; let q = p + p;";
+        let pair = Types::Generic("Pair".into(), vec![Types::I32]);
+        assert_eq!(
+            check_with("struct Pair<T> { first: T }", body),
+            Err(CheckError::InvalidBinaryOperand(BinaryOp::Add, pair))
+        );
+    }
+}
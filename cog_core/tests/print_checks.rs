@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod print_checks_test {
+    use cog_core::check::{Checker, errors::CheckError};
+    use cog_parser::parser::Parser;
+
+    fn check(input: &str) -> Result<(), CheckError> {
+        let statements = Parser::new(input.to_string())
+            .expect("Failed to create parser")
+            .parse()
+            .expect("Failed to parse");
+        Checker::new().check(&statements)
+    }
+
+    #[test]
+    fn print_values() {
+        let input = "fn main() -> i32 {
+            let x: f32 = 0.1;
+            println(\"{} {} {}\", 1, x, true);
+            return 0;
+        }";
+        assert_eq!(check(input), Ok(()));
+    }
+
+    #[test]
+    fn function_named_after_runtime_function() {
+        let input = "fn write(fd: i32, text: *i8, len: u64) -> i64 { return 0; }
+        fn main() -> i32 {
+            println(\"hi\");
+            return 0;
+        }";
+        assert_eq!(check(input), Err(CheckError::ReservedName("write".into())));
+    }
+
+    #[test]
+    fn runtime_function_of_another_type() {
+        let input = "fn dprintf() -> i32 { return 0; }";
+        assert_eq!(
+            check(input),
+            Err(CheckError::ReservedName("dprintf".into()))
+        );
+    }
+
+    #[test]
+    fn extern_runtime_function() {
+        let input = "extern fn write(fd: i32, text: *i8, len: u64) -> i64;
+        fn main() -> i32 {
+            println(\"hi\");
+            return 0;
+        }";
+        assert_eq!(check(input), Ok(()));
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod print_codegen_test {
+    use cog_core::{
+        check::Checker,
+        codegen::{CodeGen, errors::CodeGenError},
+        mono::Monomorphizer,
+    };
+    use cog_parser::parser::Parser;
+    use inkwell::context::Context;
+
+    /// Compiles a checked program and returns its LLVM IR.
+    fn compile(input: &str) -> Result<String, CodeGenError> {
+        let statements = Parser::new(input.to_string())
+            .expect("Failed to create parser")
+            .parse()
+            .expect("Failed to parse");
+        let mut checker = Checker::new();
+        checker.check(&statements).expect("Failed to check");
+        let statements = Monomorphizer::new(checker.instances()).run(&statements);
+
+        let context = Context::create();
+        let mut codegen = CodeGen::new(&context, "test");
+        codegen.compile(&statements)?;
+        Ok(codegen.lvvm_module.print_to_string().to_string())
+    }
+
+    #[test]
+    fn floats_print_with_round_trip_precision() {
+        let ir = compile(
+            "fn main() -> i32 {
+                let x: f64 = 0.1;
+                let y: f32 = 0.1;
+                println(\"{} {}\", x, y);
+                return 0;
+            }",
+        )
+        .expect("Failed to compile");
+
+        assert!(ir.contains("c\"%.17g\\00\""), "{}", ir);
+        assert!(ir.contains("c\"%.9g\\00\""), "{}", ir);
+    }
+
+    #[test]
+    fn extern_runtime_function_is_called() {
+        let ir = compile(
+            "extern fn write(fd: i32, text: *i8, len: u64) -> i64;
+            fn main() -> i32 {
+                println(\"hi\");
+                return 0;
+            }",
+        )
+        .expect("Failed to compile");
+
+        assert_eq!(ir.matches("declare i64 @write(").count(), 1, "{}", ir);
+    }
+
+    #[test]
+    fn extern_runtime_function_of_another_type() {
+        let result = compile(
+            "extern fn dprintf(fd: i32) -> i32;
+            fn main() -> i32 {
+                println(\"{}\", 1);
+                return 0;
+            }",
+        );
+
+        assert_eq!(
+            result,
+            Err(CodeGenError::MismatchedRuntimeFunction("dprintf".into()))
+        );
+    }
+}
@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod return_checks_test {
+    use cog_core::check::{Checker, errors::CheckError};
+    use cog_parser::parser::{Parser, core::types::Types};
+
+    fn check(input: &str) -> Result<(), CheckError> {
+        let statements = Parser::new(input.to_string())
+            .expect("Failed to create parser")
+            .parse()
+            .expect("Failed to parse");
+        Checker::new().check(&statements)
+    }
+
+    #[test]
+    fn returns_on_every_path() {
+        let input = "fn sign(x: i32) -> i32 {
+            if x < 0 {
+                return -1;
+            } else if x == 0 {
+                return 0;
+            } else {
+                return 1;
+            }
+        }
+
+        fn nothing(x: i32) {}
+
+        fn main() {
+            let a = 1;
+        }";
+        assert_eq!(check(input), Ok(()));
+    }
+
+    #[test]
+    fn falls_off_the_end() {
+        let input = "fn double(x: i32) -> i32 {
+            x * 2;
+        }";
+        assert_eq!(
+            check(input),
+            Err(CheckError::MissingReturn("double".into()))
+        );
+    }
+
+    #[test]
+    fn returns_on_one_branch_only() {
+        let input = "fn abs(x: i32) -> i32 {
+            if x < 0 {
+                return -x;
+            }
+        }";
+        assert_eq!(check(input), Err(CheckError::MissingReturn("abs".into())));
+    }
+
+    #[test]
+    fn method_falls_off_the_end() {
+        let input = "struct P { x: i32 }
+        impl P {
+            fn get(self: P) -> i32 {
+                P { x: self.x };
+            }
+        }";
+        assert_eq!(
+            check(input),
+            Err(CheckError::MissingReturn("P::get".into()))
+        );
+    }
+
+    #[test]
+    fn closure_block_falls_off_the_end() {
+        let input = "fn main() -> i32 {
+            let f = |x: i32| -> i32 { x + 1; };
+            return 0;
+        }";
+        assert_eq!(
+            check(input),
+            Err(CheckError::MissingReturn("closure".into()))
+        );
+    }
+
+    #[test]
+    fn bare_return_from_void_function() {
+        let input = "fn skip(x: i32) {
+            if x < 0 {
+                return;
+            }
+            let y = x;
+        }";
+        assert_eq!(check(input), Ok(()));
+    }
+
+    #[test]
+    fn bare_return_from_returning_function() {
+        let input = "fn get() -> i32 {
+            return;
+        }";
+        assert_eq!(
+            check(input),
+            Err(CheckError::MismatchedTypes(Types::I32, Types::Void))
+        );
+    }
+}
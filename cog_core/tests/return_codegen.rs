@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod return_codegen_test {
+    use cog_core::{check::Checker, codegen::CodeGen, mono::Monomorphizer};
+    use cog_parser::parser::Parser;
+    use inkwell::context::Context;
+
+    /// Compiles a checked program and returns its LLVM IR.
+    fn compile(input: &str) -> String {
+        let statements = Parser::new(input.to_string())
+            .expect("Failed to create parser")
+            .parse()
+            .expect("Failed to parse");
+        let mut checker = Checker::new();
+        checker.check(&statements).expect("Failed to check");
+        let statements = Monomorphizer::new(checker.instances()).run(&statements);
+
+        let context = Context::create();
+        let mut codegen = CodeGen::new(&context, "test");
+        codegen.compile(&statements).expect("Failed to compile");
+        codegen.lvvm_module.print_to_string().to_string()
+    }
+
+    /// The IR of the function `name`.
+    fn function<'a>(ir: &'a str, name: &str) -> &'a str {
+        let start = ir
+            .find(&format!("@{}(", name))
+            .unwrap_or_else(|| panic!("no `{}` in:\n{}", name, ir));
+        let function = &ir[start..];
+        let end = function.find("\n}").unwrap_or(function.len());
+        &function[..end]
+    }
+
+    #[test]
+    fn end_of_a_returning_function_is_unreachable() {
+        let ir = compile(
+            "fn sign(x: i32) -> i32 {
+                if x < 0 {
+                    return -1;
+                } else {
+                    return 1;
+                }
+            }
+
+            fn main() {}",
+        );
+
+        let sign = function(&ir, "sign");
+        assert!(sign.contains("unreachable"), "{}", sign);
+        assert!(!sign.contains("ret i32 0"), "{}", sign);
+        assert!(function(&ir, "main").contains("ret i32 0"), "{}", ir);
+    }
+
+    #[test]
+    fn bare_return_returns_void() {
+        let ir = compile(
+            "fn skip(x: i32) {
+                if x < 0 {
+                    return;
+                }
+                let y = x;
+            }
+
+            fn main() {}",
+        );
+
+        let skip = function(&ir, "skip");
+        assert_eq!(skip.matches("ret void").count(), 2, "{}", skip);
+    }
+}
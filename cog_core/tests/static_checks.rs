@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod static_checks_test {
+    use cog_core::check::{Checker, errors::CheckError};
+    use cog_parser::parser::{Parser, core::types::Types};
+
+    fn check(input: &str) -> Result<(), CheckError> {
+        let statements = Parser::new(input.to_string())
+            .expect("Failed to create parser")
+            .parse()
+            .expect("Failed to parse");
+        Checker::new().check(&statements)
+    }
+
+    #[test]
+    fn initializers_of_other_types() {
+        let input = "struct Point { x: i16, y: i16 }
+        static MASK: u32 = 0xFF << 8;
+        static F: f32 = 1.0 + 2.0;
+        static BIG: u64 = 0xFFFF_FFFF_FFFF_FFFF;
+        static LOW: i8 = -128;
+        static FLAGS: u8 = ~0 ^ 1;
+        static PAIR: (i64, bool) = (1 << 40, true);
+        static ORIGIN: Point = Point { x: -1, y: 2 * 3 };
+        static NAME: *i8 = \"cog\";
+        fn main() -> i32 {
+            let m: u32 = MASK;
+            return 0;
+        }";
+        assert_eq!(check(input), Ok(()));
+    }
+
+    #[test]
+    fn initializer_out_of_range() {
+        let input = "static B: u8 = 256;";
+        assert_eq!(
+            check(input),
+            Err(CheckError::IntegerOutOfRange("256".into(), Types::U8))
+        );
+    }
+
+    #[test]
+    fn initializer_of_wrong_type() {
+        let input = "static B: u8 = true;";
+        assert_eq!(
+            check(input),
+            Err(CheckError::MismatchedTypes(Types::U8, Types::Bool))
+        );
+    }
+
+    #[test]
+    fn initializer_naming_another_static() {
+        let input = "static A: i32 = 1;
+        static B: i32 = A;";
+        assert_eq!(
+            check(input),
+            Err(CheckError::NotConstant("Literal(Identifier(\"A\"))".into()))
+        );
+    }
+
+    #[test]
+    fn initializer_calling_a_function() {
+        let input = "fn one() -> i32 { return 1; }
+        static A: i32 = one() + 1;";
+        assert!(matches!(check(input), Err(CheckError::NotConstant(_))));
+    }
+
+    #[test]
+    fn initializer_dividing_by_zero() {
+        let input = "static A: i32 = 1 / 0;";
+        assert!(matches!(check(input), Err(CheckError::NotConstant(_))));
+    }
+
+    #[test]
+    fn initializer_shifting_past_width() {
+        let input = "static A: u8 = 1 << 8;";
+        assert!(matches!(check(input), Err(CheckError::NotConstant(_))));
+    }
+
+    #[test]
+    fn assign_to_static() {
+        let input = "static LIMIT: i32 = 1;
+        fn main() {
+            LIMIT = 2;
+        }";
+        assert_eq!(
+            check(input),
+            Err(CheckError::AssignToStatic("LIMIT".into()))
+        );
+    }
+
+    #[test]
+    fn assign_to_field_of_static() {
+        let input = "struct Point { x: i32, y: i32 }
+        static ORIGIN: Point = Point { x: 0, y: 0 };
+        fn main() {
+            ORIGIN.x = 2;
+        }";
+        assert_eq!(
+            check(input),
+            Err(CheckError::AssignToStatic("ORIGIN".into()))
+        );
+    }
+
+    #[test]
+    fn assign_to_static_mut() {
+        let input = "static mut COUNT: u64 = 0;
+        fn main() {
+            COUNT += 1;
+            COUNT = COUNT * 2;
+        }";
+        assert_eq!(check(input), Ok(()));
+    }
+
+    #[test]
+    fn variable_shadows_static() {
+        let input = "static LIMIT: i32 = 1;
+        fn main() {
+            let LIMIT = 3;
+            LIMIT = 2;
+        }";
+        assert_eq!(check(input), Ok(()));
+    }
+}
@@ -0,0 +1,246 @@
+#[cfg(test)]
+mod trait_checks_test {
+    use cog_core::check::{Checker, errors::CheckError};
+    use cog_parser::parser::{Parser, core::types::Types};
+
+    fn check(input: &str) -> Result<(), CheckError> {
+        let statements = Parser::new(input.to_string())
+            .expect("Failed to create parser")
+            .parse()
+            .expect("Failed to parse");
+        Checker::new().check(&statements)
+    }
+
+    const TWO_TRAITS: &str = "struct S { x: i32 }
+        trait A { fn go(self: *Self) -> i32; }
+        trait B { fn go(self: *Self) -> bool; }
+        impl A for S {
+            fn go(self: *Self) -> i32 { return self.x; }
+        }
+        impl B for S {
+            fn go(self: *Self) -> bool { return true; }
+        }";
+
+    #[test]
+    fn traits_sharing_a_method_name() {
+        let input = format!(
+            "{}
+            fn main() -> i32 {{
+                let s = S {{ x: 1 }};
+                return 0;
+            }}",
+            TWO_TRAITS
+        );
+        assert_eq!(check(&input), Ok(()));
+    }
+
+    #[test]
+    fn ambiguous_trait_method_call() {
+        let input = format!(
+            "{}
+            fn main() -> i32 {{
+                let s = S {{ x: 1 }};
+                return s.go();
+            }}",
+            TWO_TRAITS
+        );
+        assert_eq!(
+            check(&input),
+            Err(CheckError::AmbiguousMethod("S".into(), "go".into()))
+        );
+    }
+
+    #[test]
+    fn shared_method_name_through_dyn() {
+        let input = format!(
+            "{}
+            fn main() -> i32 {{
+                let s = S {{ x: 1 }};
+                let a: *dyn A = &s;
+                let b: *dyn B = &s;
+                if b.go() {{
+                    return a.go();
+                }}
+                return 0;
+            }}",
+            TWO_TRAITS
+        );
+        assert_eq!(check(&input), Ok(()));
+    }
+
+    #[test]
+    fn inherent_method_shadows_trait_method() {
+        let input = "struct S { x: i32 }
+        trait A { fn go(self: *Self) -> i32; }
+        impl A for S {
+            fn go(self: *Self) -> i32 { return self.x; }
+        }
+        impl S {
+            fn go(self: *Self) -> bool { return false; }
+        }
+        fn main() -> i32 {
+            let s = S { x: 1 };
+            let done: bool = s.go();
+            let through: *dyn A = &s;
+            return through.go();
+        }";
+        assert_eq!(check(input), Ok(()));
+    }
+
+    #[test]
+    fn trait_method_called_by_path() {
+        let input = "struct S { x: i32 }
+        trait Make { fn make() -> Self; }
+        impl Make for S {
+            fn make() -> Self { return S { x: 1 }; }
+        }
+        fn main() -> i32 {
+            let s = S::make();
+            return s.x;
+        }";
+        assert_eq!(check(input), Ok(()));
+    }
+
+    #[test]
+    fn default_method_alongside_inherent_method() {
+        let input = "struct S { x: i32 }
+        trait A { fn go(self: *Self) -> i32 { return 1; } }
+        impl A for S {}
+        impl S {
+            fn go(self: *Self) -> i32 { return 2; }
+        }
+        fn main() -> i32 {
+            let s = S { x: 1 };
+            return s.go();
+        }";
+        assert_eq!(check(input), Ok(()));
+    }
+
+    #[test]
+    fn undefined_method() {
+        let input = "struct S { x: i32 }
+        fn main() -> i32 {
+            let s = S { x: 1 };
+            return s.go();
+        }";
+        assert_eq!(
+            check(input),
+            Err(CheckError::UndefinedMethod("S".into(), "go".into()))
+        );
+    }
+
+    const SHAPES: &str = "struct Circle { r: i32 }
+        struct Square { s: i32 }
+        struct Line { l: i32 }
+        trait Shape {
+            fn area(self: *Self) -> i32;
+            fn scaled(self: *Self, k: i32) -> i32 { return k * self.area(); }
+        }
+        impl Shape for Circle {
+            fn area(self: *Self) -> i32 { return self.r * self.r * 3; }
+        }
+        impl Shape for Square {
+            fn area(self: *Self) -> i32 { return self.s * self.s; }
+        }";
+
+    fn check_shapes(body: &str) -> Result<(), CheckError> {
+        check(&format!("{}\nfn main() -> i32 {{ {} }}", SHAPES, body))
+    }
+
+    #[test]
+    fn dyn_method_calls() {
+        let input = "let c = Circle { r: 2 };
+            let s = Square { s: 3 };
+            let shape: *dyn Shape = &c;
+            let total = shape.area();
+            shape = &s;
+            return total + shape.scaled(2);";
+        assert_eq!(check_shapes(input), Ok(()));
+    }
+
+    #[test]
+    fn dyn_parameter() {
+        let input = format!(
+            "{}
+            fn area_of(shape: *dyn Shape) -> i32 {{ return shape.area(); }}
+            fn main() -> i32 {{
+                let c = Circle {{ r: 2 }};
+                return area_of(&c);
+            }}",
+            SHAPES
+        );
+        assert_eq!(check(&input), Ok(()));
+    }
+
+    #[test]
+    fn dyn_from_type_without_impl() {
+        let input = "let l = Line { l: 1 };
+            let shape: *dyn Shape = &l;
+            return 0;";
+        assert_eq!(
+            check_shapes(input),
+            Err(CheckError::MismatchedTypes(
+                Types::Pointer(Box::new(Types::Dyn("Shape".into()))),
+                Types::Pointer(Box::new(Types::Struct("Line".into()))),
+            ))
+        );
+    }
+
+    #[test]
+    fn dyn_undefined_method() {
+        let input = "let c = Circle { r: 2 };
+            let shape: *dyn Shape = &c;
+            return shape.perimeter();";
+        assert_eq!(
+            check_shapes(input),
+            Err(CheckError::UndefinedMethod(
+                "Shape".into(),
+                "perimeter".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn dyn_argument_count() {
+        let input = "let c = Circle { r: 2 };
+            let shape: *dyn Shape = &c;
+            return shape.scaled();";
+        assert_eq!(
+            check_shapes(input),
+            Err(CheckError::ArgumentCountMismatch(
+                "Shape::scaled".into(),
+                1,
+                0
+            ))
+        );
+    }
+
+    #[test]
+    fn dyn_by_value() {
+        let input = "fn f(shape: dyn Shape) {}";
+        assert_eq!(
+            check(&format!("{}\n{}", SHAPES, input)),
+            Err(CheckError::UnsizedType(Types::Dyn("Shape".into())))
+        );
+    }
+
+    #[test]
+    fn method_without_self_pointer_is_not_object_safe() {
+        let input = "trait Make { fn make() -> i32; }
+        fn f(made: *dyn Make) {}";
+        assert_eq!(
+            check(input),
+            Err(CheckError::NotObjectSafe("Make".into(), "make".into()))
+        );
+    }
+
+    #[test]
+    fn method_returning_self_is_not_object_safe() {
+        let input = "trait Copy { fn copy(self: *Self) -> Self; }
+        fn f(copied: *dyn Copy) {}";
+        assert_eq!(
+            check(input),
+            Err(CheckError::NotObjectSafe("Copy".into(), "copy".into()))
+        );
+    }
+}
@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod trait_codegen_test {
+    use cog_core::{check::Checker, codegen::CodeGen, mono::Monomorphizer};
+    use cog_parser::parser::Parser;
+    use inkwell::context::Context;
+
+    /// Compiles a checked program and returns its LLVM IR.
+    fn compile(input: &str) -> String {
+        let statements = Parser::new(input.to_string())
+            .expect("Failed to create parser")
+            .parse()
+            .expect("Failed to parse");
+        let mut checker = Checker::new();
+        checker.check(&statements).expect("Failed to check");
+        let statements = Monomorphizer::new(checker.instances()).run(&statements);
+
+        let context = Context::create();
+        let mut codegen = CodeGen::new(&context, "test");
+        codegen.compile(&statements).expect("Failed to compile");
+        codegen.lvvm_module.print_to_string().to_string()
+    }
+
+    /// The IR of the function `name`.
+    fn function<'a>(ir: &'a str, name: &str) -> &'a str {
+        let start = ir
+            .find(&format!("@{}(", name))
+            .unwrap_or_else(|| panic!("no `{}` in:\n{}", name, ir));
+        let function = &ir[start..];
+        let end = function.find("\n}").unwrap_or(function.len());
+        &function[..end]
+    }
+
+    fn defines(ir: &str, name: &str) -> bool {
+        ir.lines()
+            .any(|line| line.starts_with("define") && line.contains(&format!("@{}(", name)))
+    }
+
+    #[test]
+    fn trait_methods_are_qualified_by_trait() {
+        let ir = compile(
+            "struct S { x: i32 }
+            trait A { fn go(self: *Self) -> i32; }
+            trait B { fn go(self: *Self) -> i32 { return 2; } }
+            impl A for S {
+                fn go(self: *Self) -> i32 { return self.x; }
+            }
+            impl B for S {}
+            impl S {
+                fn go(self: *Self) -> i32 { return 3; }
+            }
+
+            fn main() -> i32 {
+                let s = S { x: 1 };
+                let b: *dyn B = &s;
+                return s.go() + b.go();
+            }",
+        );
+
+        assert!(defines(&ir, "_CN1S1A2goE"), "{}", ir);
+        assert!(defines(&ir, "_CN1S1B2goE"), "{}", ir);
+        assert!(ir.contains("@_CTV1S1BE = internal constant [1 x ptr] [ptr @_CN1S1B2goE]"));
+
+        let main = function(&ir, "main");
+        assert!(main.contains("call i32 @_CN1S2goE("), "{}", main);
+        assert!(!main.contains("@_CN1S1A2goE"), "{}", main);
+    }
+
+    const SHAPES: &str = "struct Circle { r: i32 }
+        struct Square { s: i32 }
+        trait Shape {
+            fn area(self: *Self) -> i32;
+            fn scaled(self: *Self, k: i32) -> i32 { return k * self.area(); }
+        }
+        impl Shape for Circle {
+            fn area(self: *Self) -> i32 { return self.r * self.r * 3; }
+        }
+        impl Shape for Square {
+            fn area(self: *Self) -> i32 { return self.s * self.s; }
+        }
+        fn scaled_area(shape: *dyn Shape) -> i32 { return shape.scaled(2); }";
+
+    #[test]
+    fn vtable_per_type_and_trait() {
+        let ir = compile(&format!(
+            "{}
+            fn main() -> i32 {{
+                let c = Circle {{ r: 2 }};
+                let s = Square {{ s: 3 }};
+                return scaled_area(&c) + scaled_area(&s);
+            }}",
+            SHAPES
+        ));
+
+        assert!(ir.contains(
+            "@_CTV6Circle5ShapeE = internal constant [2 x ptr] \
+             [ptr @_CN6Circle5Shape4areaE, ptr @_CN6Circle5Shape6scaledE]"
+        ));
+        assert!(ir.contains(
+            "@_CTV6Square5ShapeE = internal constant [2 x ptr] \
+             [ptr @_CN6Square5Shape4areaE, ptr @_CN6Square5Shape6scaledE]"
+        ));
+    }
+
+    #[test]
+    fn unused_vtable_is_not_emitted() {
+        let ir = compile(&format!(
+            "{}
+            fn main() -> i32 {{
+                let c = Circle {{ r: 2 }};
+                let s = Square {{ s: 3 }};
+                return scaled_area(&c) + s.area();
+            }}",
+            SHAPES
+        ));
+
+        assert!(ir.contains("@_CTV6Circle5ShapeE"));
+        assert!(!ir.contains("@_CTV6Square5ShapeE"));
+    }
+
+    #[test]
+    fn dyn_call_goes_through_vtable() {
+        let ir = compile(&format!(
+            "{}
+            fn main() -> i32 {{
+                let c = Circle {{ r: 2 }};
+                return scaled_area(&c);
+            }}",
+            SHAPES
+        ));
+
+        let scaled_area = function(&ir, "scaled_area");
+        assert!(
+            scaled_area.contains("getelementptr inbounds ptr, ptr %vtable, i64 1"),
+            "{}",
+            scaled_area
+        );
+        assert!(scaled_area.contains("call i32 %method("), "{}", scaled_area);
+        assert!(!scaled_area.contains("@_CN6Circle"), "{}", scaled_area);
+
+        let main = function(&ir, "main");
+        assert!(main.contains("@_CTV6Circle5ShapeE"), "{}", main);
+    }
+}
@@ -0,0 +1,194 @@
+#[cfg(test)]
+mod visibility_checks_test {
+    use std::{fs, path::PathBuf};
+
+    use cog_core::{
+        check::{Checker, errors::CheckError},
+        modules::ModuleTree,
+    };
+
+    const LIB: &str = "pub struct Secret { pub shown: i32, hidden: i32 }
+        struct Hidden { value: i32 }
+        static LIMIT: i32 = 10;
+        pub static mut COUNT: i32 = 0;
+        impl Secret {
+            pub fn make() -> Secret { return Secret { shown: 1, hidden: 2 }; }
+            fn peek(self: *Secret) -> i32 { return self.hidden; }
+            pub fn open(self: *Secret) -> i32 { return self.peek() + internal(); }
+        }
+        fn internal() -> i32 { return LIMIT; }
+        pub fn hidden_value() -> i32 { let h = Hidden { value: 3 }; return h.value; }";
+
+    /// Writes `files` into a directory of their own, named after the test.
+    /// The program is rooted at `main.cog`.
+    fn write(test: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("cog_{}_{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        for (name, source) in files {
+            let file = dir.join(name);
+            fs::create_dir_all(file.parent().expect("file has a parent"))
+                .expect("Failed to create module directory");
+            fs::write(file, source).expect("Failed to write module");
+        }
+        dir
+    }
+
+    /// Checks a program whose root module declares `mod lib;` and holds
+    /// `main`, which runs `body`.
+    fn check_main(test: &str, body: &str) -> Result<(), CheckError> {
+        let main = format!("mod lib;\nfn main() -> i32 {{ {} }}", body);
+        check(test, &[("main.cog", &main), ("lib.cog", LIB)])
+    }
+
+    fn check(test: &str, files: &[(&str, &str)]) -> Result<(), CheckError> {
+        let dir = write(test, files);
+        let statements = ModuleTree::load(&dir.join("main.cog"))
+            .and_then(|tree| tree.link())
+            .expect("Failed to link");
+        Checker::new().check(&statements)
+    }
+
+    #[test]
+    fn public_items() {
+        let body = "let s = lib::Secret::make();
+            lib::COUNT = lib::COUNT + 1;
+            return s.shown + s.open() + lib::hidden_value();";
+        assert_eq!(check_main("public_items", body), Ok(()));
+    }
+
+    #[test]
+    fn private_function() {
+        assert_eq!(
+            check_main("private_function", "return lib::internal();"),
+            Err(CheckError::PrivateItem("lib::internal".into()))
+        );
+    }
+
+    #[test]
+    fn private_function_as_value() {
+        assert_eq!(
+            check_main("private_function_value", "let f = lib::internal; return 0;"),
+            Err(CheckError::PrivateItem("lib::internal".into()))
+        );
+    }
+
+    #[test]
+    fn private_method() {
+        let body = "let s = lib::Secret::make(); return s.peek();";
+        assert_eq!(
+            check_main("private_method", body),
+            Err(CheckError::PrivateItem("lib::Secret::peek".into()))
+        );
+    }
+
+    #[test]
+    fn private_struct() {
+        let body = "let h = lib::Hidden { value: 1 }; return 0;";
+        assert_eq!(
+            check_main("private_struct", body),
+            Err(CheckError::PrivateItem("lib::Hidden".into()))
+        );
+    }
+
+    #[test]
+    fn private_struct_in_signature() {
+        let main = "mod lib;\nfn get(h: *lib::Hidden) -> i32 { return 0; }";
+        assert_eq!(
+            check(
+                "private_struct_signature",
+                &[("main.cog", main), ("lib.cog", LIB)]
+            ),
+            Err(CheckError::PrivateItem("lib::Hidden".into()))
+        );
+    }
+
+    #[test]
+    fn private_static() {
+        assert_eq!(
+            check_main("private_static", "return lib::LIMIT;"),
+            Err(CheckError::PrivateItem("lib::LIMIT".into()))
+        );
+    }
+
+    #[test]
+    fn private_field_read() {
+        let body = "let s = lib::Secret::make(); return s.hidden;";
+        assert_eq!(
+            check_main("private_field_read", body),
+            Err(CheckError::PrivateField(
+                "lib::Secret".into(),
+                "hidden".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn private_field_written() {
+        let body = "let s = lib::Secret::make(); s.hidden = 1; return 0;";
+        assert_eq!(
+            check_main("private_field_written", body),
+            Err(CheckError::PrivateField(
+                "lib::Secret".into(),
+                "hidden".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn private_field_in_literal() {
+        let body = "let s = lib::Secret { shown: 1, hidden: 2 }; return 0;";
+        assert_eq!(
+            check_main("private_field_literal", body),
+            Err(CheckError::PrivateField(
+                "lib::Secret".into(),
+                "hidden".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn child_module_sees_private_items_of_parent() {
+        let lib = format!("mod inner;\n{}", LIB);
+        let inner = "pub fn reveal() -> i32 {
+            let s = lib::Secret::make();
+            return lib::internal() + s.hidden + s.peek();
+        }";
+        let files = [
+            (
+                "main.cog",
+                "mod lib;\nfn main() -> i32 { return lib::inner::reveal(); }",
+            ),
+            ("lib.cog", lib.as_str()),
+            ("lib/inner.cog", inner),
+        ];
+        assert_eq!(check("child_module", &files), Ok(()));
+    }
+
+    #[test]
+    fn sibling_module_does_not_see_private_items() {
+        let files = [
+            ("main.cog", "mod lib;\nmod other;"),
+            ("lib.cog", LIB),
+            ("other.cog", "pub fn f() -> i32 { return lib::internal(); }"),
+        ];
+        assert_eq!(
+            check("sibling_module", &files),
+            Err(CheckError::PrivateItem("lib::internal".into()))
+        );
+    }
+
+    #[test]
+    fn root_items_are_visible_everywhere() {
+        let files = [
+            (
+                "main.cog",
+                "mod lib;\nstatic LEVEL: i32 = 2;\nfn secret() -> i32 { return 1; }",
+            ),
+            (
+                "lib.cog",
+                "pub fn level() -> i32 { return LEVEL + secret(); }",
+            ),
+        ];
+        assert_eq!(check("root_items_visible", &files), Ok(()));
+    }
+}
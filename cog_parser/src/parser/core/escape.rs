@@ -0,0 +1,98 @@
+use crate::parser::errors::LexError;
+
+/// What a quoted literal decodes to, which decides the escapes it accepts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Quote {
+    /// Strings and chars hold Unicode text, so `\x` is limited to ASCII and
+    /// `\u{...}` must name a Unicode scalar value.
+    Text,
+    /// Byte literals hold a single byte: any `\x` value, but no `\u{...}`
+    /// and no characters outside ASCII.
+    Byte,
+}
+
+/// Decodes the escapes in the body of a quoted literal, without its quotes.
+pub fn unescape(content: &str, quote: Quote) -> Result<String, LexError> {
+    let mut decoded = String::with_capacity(content.len());
+    let mut chars = content.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        if c != '\\' {
+            if quote == Quote::Byte && !c.is_ascii() {
+                return Err(LexError::InvalidEscape(c.to_string()));
+            }
+            decoded.push(c);
+            continue;
+        }
+
+        let decoded_char = match chars.next().map(|(_, c)| c) {
+            Some('n') => Some('\n'),
+            Some('r') => Some('\r'),
+            Some('t') => Some('\t'),
+            Some('0') => Some('\0'),
+            Some('\\') => Some('\\'),
+            Some('"') => Some('"'),
+            Some('\'') => Some('\''),
+            Some('x') => {
+                let digits: String = (0..2)
+                    .filter_map(|_| chars.next())
+                    .map(|(_, c)| c)
+                    .collect();
+                match u8::from_str_radix(&digits, 16) {
+                    Ok(byte) if digits.len() == 2 && (quote == Quote::Byte || byte.is_ascii()) => {
+                        Some(char::from(byte))
+                    }
+                    _ => None,
+                }
+            }
+            Some('u') if quote == Quote::Text => unicode_escape(&mut chars),
+            _ => None,
+        };
+
+        match decoded_char {
+            Some(decoded_char) => decoded.push(decoded_char),
+            None => {
+                let end = chars.peek().map_or(content.len(), |(index, _)| *index);
+                return Err(LexError::InvalidEscape(content[start..end].to_string()));
+            }
+        }
+    }
+
+    Ok(decoded)
+}
+
+/// Reads the `{...}` of a `\u{...}` escape: one to six hex digits naming a
+/// Unicode scalar value, so surrogates and values past `10FFFF` are rejected.
+fn unicode_escape(chars: &mut std::iter::Peekable<std::str::CharIndices>) -> Option<char> {
+    if chars.next().map(|(_, c)| c) != Some('{') {
+        return None;
+    }
+
+    let mut digits = String::new();
+    let mut closed = false;
+    for (_, c) in chars.by_ref() {
+        if c == '}' {
+            closed = true;
+            break;
+        }
+        digits.push(c);
+    }
+
+    if !closed || digits.is_empty() || digits.len() > 6 {
+        return None;
+    }
+    u32::from_str_radix(&digits, 16)
+        .ok()
+        .and_then(char::from_u32)
+}
+
+/// Decodes the body of a char or byte literal, which must be exactly one
+/// character once escapes are resolved.
+pub fn single_char(content: &str, quote: Quote) -> Result<char, LexError> {
+    let decoded = unescape(content, quote)?;
+    let mut chars = decoded.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(LexError::MalformedCharLiteral(content.to_string())),
+    }
+}
@@ -15,7 +15,12 @@ pub enum Expr {
         operand: Box<Expr>,
     },
     Assignment {
-        identifier: String,
+        target: Box<Expr>,
+        value: Box<Expr>,
+    },
+    CompoundAssignment {
+        target: Box<Expr>,
+        operator: Box<BinaryOp>,
         value: Box<Expr>,
     },
     Declaration {
@@ -29,9 +34,23 @@ pub enum Expr {
         body: Box<Expr>,
         return_type: Option<Types>,
     },
+    ExternFunctionDeclaration {
+        identifier: String,
+        parameters: Vec<Expr>,
+        return_type: Option<Types>,
+        is_variadic: bool,
+    },
+    Call {
+        callee: Box<Expr>,
+        arguments: Vec<Expr>,
+    },
+    /// `return value;`, or a bare `return;` from a function returning void.
     Return {
-        value: Box<Expr>,
+        value: Option<Box<Expr>>,
     },
+    /// `defer expr;`, running `expr` whenever the enclosing block is left,
+    /// the most recently deferred first.
+    Defer(Box<Expr>),
     Block(Vec<Expr>),
     IfElse {
         condition: Box<Expr>,
@@ -40,11 +59,173 @@ pub enum Expr {
     },
     AddressOf(Box<Expr>),
     Dereference(Box<Expr>),
+    /// `value as T`, reinterpreting a pointer as a pointer to another type.
+    Cast {
+        value: Box<Expr>,
+        target: Types,
+    },
+    /// `unsafe { ... }`, a block allowed to do pointer arithmetic and casts.
+    Unsafe(Box<Expr>),
+    StructDeclaration {
+        identifier: String,
+        fields: Vec<StructField>,
+    },
+    StructLiteral {
+        identifier: String,
+        fields: Vec<(String, Expr)>,
+    },
+    FieldAccess {
+        object: Box<Expr>,
+        field: String,
+    },
+    ImplBlock {
+        type_name: String,
+        methods: Vec<Expr>,
+    },
+    MethodCall {
+        receiver: Box<Expr>,
+        method: String,
+        arguments: Vec<Expr>,
+    },
+    /// A `::`-separated path such as `Point::new`.
+    Path(Vec<String>),
+    /// A function or struct declaration with type parameters, such as
+    /// `fn max<T>(a: T, b: T) -> T`.
+    Generic {
+        type_parameters: Vec<TypeParameter>,
+        item: Box<Expr>,
+    },
+    TraitDeclaration {
+        identifier: String,
+        methods: Vec<TraitMethod>,
+    },
+    /// An item preceded by `///` doc comments, one line of `doc` per comment.
+    Documented {
+        doc: String,
+        item: Box<Expr>,
+    },
+    /// `static NAME: T = value;`, a global variable whose value is known at
+    /// compile time. Only a `static mut` may be assigned.
+    StaticDeclaration {
+        identifier: String,
+        var_type: Types,
+        value: Box<Expr>,
+        mutable: bool,
+    },
+    /// `type Handle = *i64;`, naming an existing type.
+    TypeAlias {
+        identifier: String,
+        aliased: Types,
+    },
+    /// An item marked `pub`, visible outside the module that declares it.
+    Public {
+        item: Box<Expr>,
+    },
+    /// `impl Trait for Type { ... }`.
+    TraitImpl {
+        trait_name: String,
+        type_name: String,
+        methods: Vec<Expr>,
+    },
+    /// A tuple expression such as `(1, true)`. Its elements are read back
+    /// with `FieldAccess` using the index as the field name, as in `t.0`.
+    Tuple(Vec<Expr>),
+    /// `let (a, b) = value;`, binding each element of a tuple to a name.
+    TupleDeclaration {
+        identifiers: Vec<String>,
+        var_type: Option<Types>,
+        value: Box<Expr>,
+    },
+    /// A constructor of `Option` or `Result`, such as `Some(1)` or `None`.
+    Variant {
+        variant: Variant,
+        value: Option<Box<Expr>>,
+    },
+    /// The postfix `?`: unwraps a `Some` or `Ok`, and otherwise returns the
+    /// `None` or `Err` from the enclosing function.
+    Try(Box<Expr>),
+    /// A closure such as `|x: i32| x + offset`. Parameters may leave out their
+    /// type when the closure is passed where a function type is expected.
+    /// `captures` is empty as parsed; once checked, it lists the enclosing
+    /// variables the body uses.
+    Closure {
+        parameters: Vec<Expr>,
+        return_type: Option<Types>,
+        body: Box<Expr>,
+        captures: Vec<String>,
+    },
+    /// `mod name;`, declaring the module in the file `name.cog`.
+    ModuleDeclaration {
+        identifier: String,
+    },
+    /// `import a::b;`, bringing the last segment of the path into scope.
+    Import {
+        path: Vec<String>,
+    },
+    /// `alloc<T>()`, uninitialized heap memory for one `T`.
+    Alloc(Types),
+    /// `free(ptr)`, returning memory from `alloc` to the system.
+    Free(Box<Expr>),
+    /// `Box::new(value)`, moving `value` to the heap.
+    BoxNew(Box<Expr>),
+    /// The built-in `print("x = {}", x)`, or `println` when `newline` is set.
+    /// The format string is split at its `{}` placeholders, so there is one
+    /// more piece than there are placeholders.
+    Print {
+        pieces: Vec<String>,
+        arguments: Vec<Expr>,
+        newline: bool,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructField {
+    pub identifier: String,
+    pub field_type: Types,
+    pub doc: Option<String>,
+    pub public: bool,
+}
+
+/// The constructors of the built-in `Option` and `Result` types.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Variant {
+    Some,
+    None,
+    Ok,
+    Err,
+}
+
+/// A type parameter and the traits it must implement, as in `T: Shape`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeParameter {
+    pub identifier: String,
+    pub bounds: Vec<String>,
+}
+
+/// A method a trait requires. Methods with a body provide a default
+/// implementation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraitMethod {
+    pub identifier: String,
+    pub parameters: Vec<Expr>,
+    pub return_type: Option<Types>,
+    pub body: Option<Box<Expr>>,
+    pub doc: Option<String>,
 }
 
 impl Expr {
+    /// Integer literals are never negative, so a negative `value` becomes a
+    /// negated literal, as the parser reads `-5`.
     pub fn new_int_literal(value: i64) -> Self {
-        Expr::Literal(Nodes::Integer(value))
+        let literal = Expr::Literal(Nodes::Integer(value.unsigned_abs()));
+        if value < 0 {
+            return Expr::Unary {
+                operator: Box::new(UnaryOp::Minus),
+                operand: Box::new(literal),
+            };
+        }
+
+        literal
     }
 
     pub fn new_string_literal(value: String) -> Self {
@@ -58,4 +239,87 @@ impl Expr {
     pub fn new_identifier(name: &str) -> Self {
         Expr::Literal(Nodes::Identifier(name.to_string()))
     }
+
+    /// Whether the expression is an item that doc comments may be attached to.
+    pub fn is_item(&self) -> bool {
+        matches!(
+            self,
+            Expr::FunctionDeclaration { .. }
+                | Expr::ExternFunctionDeclaration { .. }
+                | Expr::StructDeclaration { .. }
+                | Expr::TraitDeclaration { .. }
+                | Expr::ImplBlock { .. }
+                | Expr::TraitImpl { .. }
+                | Expr::Generic { .. }
+                | Expr::ModuleDeclaration { .. }
+                | Expr::TypeAlias { .. }
+                | Expr::StaticDeclaration { .. }
+                | Expr::Public { .. }
+        )
+    }
+
+    /// The item itself, looking through any doc comment or `pub` attached
+    /// to it.
+    pub fn item(&self) -> &Expr {
+        match self {
+            Expr::Documented { item, .. } | Expr::Public { item } => item.item(),
+            other => other,
+        }
+    }
+
+    /// Whether the item is marked `pub`.
+    pub fn is_public(&self) -> bool {
+        match self {
+            Expr::Documented { item, .. } => item.is_public(),
+            Expr::Public { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Whether every path through the expression ends in a `return`.
+    pub fn always_returns(&self) -> bool {
+        match self {
+            Expr::Return { .. } => true,
+            Expr::Block(exprs) => exprs.iter().any(Expr::always_returns),
+            Expr::Unsafe(body) => body.always_returns(),
+            Expr::IfElse {
+                then_branch,
+                else_branch: Some(else_branch),
+                ..
+            } => then_branch.always_returns() && else_branch.always_returns(),
+            _ => false,
+        }
+    }
+
+    /// Whether the expression is built only from number literals and the
+    /// arithmetic and bitwise operators, such as `1`, `-2.5` or
+    /// `(1 << 4) | 1`. Its type is the one expected where it is used.
+    pub fn is_literal_tree(&self) -> bool {
+        match self {
+            Expr::Literal(Nodes::Integer(_) | Nodes::Float(_) | Nodes::Null) => true,
+            Expr::Unary { operator, operand } => {
+                matches!(**operator, UnaryOp::Minus | UnaryOp::BitNot) && operand.is_literal_tree()
+            }
+            Expr::Binary {
+                left,
+                operator,
+                right,
+            } => {
+                (operator.is_arithmetic() || operator.is_bitwise())
+                    && left.is_literal_tree()
+                    && (operator.is_shift() || right.is_literal_tree())
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the expression names a memory location that can be assigned to.
+    pub fn is_place(&self) -> bool {
+        match self {
+            // A path can name a static of another module.
+            Expr::Literal(Nodes::Identifier(_)) | Expr::Path(_) | Expr::Dereference(_) => true,
+            Expr::FieldAccess { object, .. } => object.is_place(),
+            _ => false,
+        }
+    }
 }
@@ -1,5 +1,7 @@
+pub mod escape;
 pub mod expr;
 pub mod nodes;
 pub mod ops;
+pub mod signature;
 pub mod token;
 pub mod types;
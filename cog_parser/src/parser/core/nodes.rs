@@ -1,8 +1,13 @@
 #[derive(Debug, Clone, PartialEq)]
 pub enum Nodes {
-    Integer(i64),
+    /// Always non-negative: a leading `-` is a separate unary operator.
+    Integer(u64),
     Float(f64),
     String(String),
+    Char(char),
+    Byte(u8),
     Boolean(bool),
+    /// `null`, the pointer to nothing. Its pointee type comes from context.
+    Null,
     Identifier(String),
 }
@@ -1,3 +1,5 @@
+use crate::parser::core::types::Types;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum BinaryOp {
     Add,
@@ -13,10 +15,61 @@ pub enum BinaryOp {
     LessEqual,
     Greater,
     GreaterEqual,
+    BitAnd,
+    BitOr,
+    BitXor,
+    ShiftLeft,
+    ShiftRight,
+}
+
+impl BinaryOp {
+    pub fn is_shift(&self) -> bool {
+        matches!(self, BinaryOp::ShiftLeft | BinaryOp::ShiftRight)
+    }
+
+    /// Whether the operator gives a `t` when applied to a primitive `t`, so
+    /// the type its result is expected to have is also the type of its
+    /// operands: both of them, or the shifted value for a shift.
+    pub fn preserves(&self, t: &Types) -> bool {
+        (self.is_arithmetic() && t.is_numeric()) || (self.is_bitwise() && t.is_integer())
+    }
+
+    pub fn is_arithmetic(&self) -> bool {
+        matches!(
+            self,
+            BinaryOp::Add
+                | BinaryOp::Subtract
+                | BinaryOp::Multiply
+                | BinaryOp::Divide
+                | BinaryOp::Modulo
+        )
+    }
+
+    /// The bitwise operators, shifts included.
+    pub fn is_bitwise(&self) -> bool {
+        matches!(
+            self,
+            BinaryOp::BitAnd
+                | BinaryOp::BitOr
+                | BinaryOp::BitXor
+                | BinaryOp::ShiftLeft
+                | BinaryOp::ShiftRight
+        )
+    }
+
+    /// Whether the operator offsets a pointer when its left operand is one.
+    pub fn is_offset(&self) -> bool {
+        matches!(self, BinaryOp::Add | BinaryOp::Subtract)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum UnaryOp {
     Minus,
     Not,
+    BitNot,
+    PreIncrement,
+    PreDecrement,
+    PostIncrement,
+    PostDecrement,
 }
@@ -0,0 +1,54 @@
+use crate::parser::core::expr::Expr;
+use crate::parser::core::types::Types;
+
+/// The parameter and return types of a function, as seen by callers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionSignature {
+    pub parameters: Vec<Types>,
+    pub return_type: Types,
+    pub is_variadic: bool,
+}
+
+impl FunctionSignature {
+    pub fn new(
+        identifier: &str,
+        parameters: &[Expr],
+        return_type: &Option<Types>,
+        is_variadic: bool,
+    ) -> Self {
+        let parameters = parameters
+            .iter()
+            .filter_map(|param| match param {
+                Expr::Declaration { var_type, .. } => var_type.clone(),
+                _ => None,
+            })
+            .collect();
+
+        // `main` keeps C's `int` return so the process exits with a status code.
+        let return_type = match return_type {
+            Some(t) => t.clone(),
+            None if identifier == "main" => Types::I32,
+            None => Types::Void,
+        };
+
+        Self {
+            parameters,
+            return_type,
+            is_variadic,
+        }
+    }
+
+    /// The signature of calls through a value of type `fn(parameters) -> return_type`.
+    pub fn from_function_type(parameters: &[Types], return_type: &Types) -> Self {
+        Self {
+            parameters: parameters.to_vec(),
+            return_type: return_type.clone(),
+            is_variadic: false,
+        }
+    }
+
+    /// The type of this function used as a value.
+    pub fn function_type(&self) -> Types {
+        Types::Function(self.parameters.clone(), Box::new(self.return_type.clone()))
+    }
+}
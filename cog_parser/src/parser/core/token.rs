@@ -1,5 +1,13 @@
+use logos::{Filter, FilterResult, Lexer};
+
+use crate::parser::{
+    core::escape::{Quote, single_char, unescape},
+    errors::LexError,
+};
+
 #[derive(logos::Logos, Debug, PartialEq, Clone)]
 #[logos(skip r"[ \t\n\f]+")]
+#[logos(error = LexError)]
 pub enum Token {
     /* math operators */
     #[token("+")]
@@ -22,10 +30,42 @@ pub enum Token {
     Bang,
     #[token("&")]
     Ampersand,
+    #[token("~")]
+    Tilde,
+
+    /* bitwise operators */
+    #[token("|")]
+    Pipe,
+    #[token("^")]
+    Caret,
+    #[token("<<")]
+    LessLess,
+    #[token(">>")]
+    GreaterGreater,
 
     /* assignment operators */
     #[token("=")]
     Equal,
+    #[token("+=")]
+    PlusEqual,
+    #[token("-=")]
+    MinusEqual,
+    #[token("*=")]
+    StarEqual,
+    #[token("/=")]
+    SlashEqual,
+    #[token("%=")]
+    PercentEqual,
+    #[token("&=")]
+    AmpersandEqual,
+    #[token("|=")]
+    PipeEqual,
+    #[token("^=")]
+    CaretEqual,
+    #[token("<<=")]
+    LessLessEqual,
+    #[token(">>=")]
+    GreaterGreaterEqual,
 
     /* boolean operators */
     #[token("==")]
@@ -52,21 +92,37 @@ pub enum Token {
     ArrowBig,
 
     /* broad types */
-    #[regex(r"[0-9]+", |lex| lex.slice().parse::<i64>().ok())]
-    Integer(i64),
-    #[regex(r"[0-9]+\.[0-9]+", |lex| lex.slice().parse::<f64>().ok())]
+    /// A decimal, `0x` hex, `0o` octal or `0b` binary integer, with optional
+    /// `_` separators. Any run of letters and digits starting with a digit is
+    /// lexed here, so malformed literals are reported rather than split up.
+    #[regex(r"[0-9][0-9a-zA-Z_]*", |lex| parse_integer(lex.slice()))]
+    Integer(u64),
+    #[regex(
+        r"[0-9][0-9_]*\.[0-9][0-9_]*([eE][+-]?[0-9_]+)?|[0-9][0-9_]*[eE][+-]?[0-9_]+",
+        |lex| parse_float(lex.slice()),
+        priority = 3
+    )]
     Float(f64),
-    #[regex(r#""([^"\\]|\\[nrt"\\])*""#, |lex| {
+    /// A `///` doc comment line, without the slashes. Plain `//` line
+    /// comments and `/* */` block comments are skipped.
+    #[regex(r"//[^\n]*", line_comment)]
+    #[token("/*", block_comment)]
+    DocComment(String),
+    #[regex(r#""([^"\\]|\\.)*""#, |lex| {
         let slice = lex.slice();
-
-        let content = &slice[1..slice.len()-1];
-        Some(content.replace("\\n", "\n")
-                   .replace("\\r", "\r")
-                   .replace("\\t", "\t")
-                   .replace("\\\"", "\"")
-                   .replace("\\\\", "\\"))
+        unescape(&slice[1..slice.len() - 1], Quote::Text)
     })]
     String(String),
+    #[regex(r"'([^'\\]|\\.)*'", |lex| {
+        let slice = lex.slice();
+        single_char(&slice[1..slice.len() - 1], Quote::Text)
+    })]
+    Char(char),
+    #[regex(r"b'([^'\\]|\\.)*'", |lex| {
+        let slice = lex.slice();
+        single_char(&slice[2..slice.len() - 1], Quote::Byte).map(|c| c as u8)
+    })]
+    Byte(u8),
     #[regex(r"true|false", |lex| match lex.slice() {
         "true" => Some(true),
         "false" => Some(false),
@@ -77,10 +133,28 @@ pub enum Token {
     Identifier(String),
 
     /* types */
+    #[token("i8")]
+    KeywordTypeI8,
+    #[token("i16")]
+    KeywordTypeI16,
     #[token("i32")]
     KeywordTypeI32,
     #[token("i64")]
     KeywordTypeI64,
+    #[token("isize")]
+    KeywordTypeIsize,
+    #[token("u8")]
+    KeywordTypeU8,
+    #[token("u16")]
+    KeywordTypeU16,
+    #[token("u32")]
+    KeywordTypeU32,
+    #[token("u64")]
+    KeywordTypeU64,
+    #[token("usize")]
+    KeywordTypeUsize,
+    #[token("char")]
+    KeywordTypeChar,
     #[token("f32")]
     KeywordTypeF32,
     #[token("f64")]
@@ -89,6 +163,8 @@ pub enum Token {
     KeywordTypeBool,
     #[token("String")]
     KeywordTypeString,
+    #[token("void")]
+    KeywordTypeVoid,
 
     /* delimiters */
     #[token("(")]
@@ -109,10 +185,23 @@ pub enum Token {
     Semicolon,
     #[token(":")]
     Colon,
+    #[token("::")]
+    ColonColon,
     #[token(".")]
     Dot,
     #[token("...")]
     Ellipsis,
+    #[token("?")]
+    Question,
+    /// One or more tuple indices, as in `.0` or `.1.0`. Lexed as a unit so
+    /// that `t.1.0` is not read as `t` followed by the float `1.0`.
+    #[regex(r"\.[0-9]+(\.[0-9]+)*", |lex| {
+        lex.slice()[1..]
+            .split('.')
+            .map(|index| index.parse::<usize>().ok())
+            .collect::<Option<Vec<usize>>>()
+    })]
+    TupleIndex(Vec<usize>),
 
     #[token("if")]
     KeywordIf,
@@ -124,4 +213,99 @@ pub enum Token {
     KeywordFn,
     #[token("return")]
     KeywordReturn,
+    #[token("defer")]
+    KeywordDefer,
+    #[token("extern")]
+    KeywordExtern,
+    #[token("struct")]
+    KeywordStruct,
+    #[token("impl")]
+    KeywordImpl,
+    #[token("trait")]
+    KeywordTrait,
+    #[token("for")]
+    KeywordFor,
+    #[token("dyn")]
+    KeywordDyn,
+    #[token("mod")]
+    KeywordMod,
+    #[token("import")]
+    KeywordImport,
+    #[token("pub")]
+    KeywordPub,
+    #[token("type")]
+    KeywordType,
+    #[token("null")]
+    KeywordNull,
+    #[token("as")]
+    KeywordAs,
+    #[token("unsafe")]
+    KeywordUnsafe,
+    #[token("static")]
+    KeywordStatic,
+    #[token("mut")]
+    KeywordMut,
+}
+
+/// Emits `///` lines as doc comments, dropping one space after the slashes.
+/// `////` and longer runs of slashes are ordinary comments, as in Rust.
+fn line_comment(lex: &mut Lexer<Token>) -> Filter<String> {
+    match lex.slice().strip_prefix("///") {
+        Some(doc) if !doc.starts_with('/') => {
+            Filter::Emit(doc.strip_prefix(' ').unwrap_or(doc).to_string())
+        }
+        _ => Filter::Skip,
+    }
+}
+
+/// Skips a block comment whose `/*` has just been read. Block comments nest,
+/// so the comment ends at the `*/` that balances it.
+fn block_comment(lex: &mut Lexer<Token>) -> FilterResult<String, LexError> {
+    let remainder = lex.remainder().as_bytes();
+    let mut depth = 1;
+    let mut index = 0;
+
+    while index + 1 < remainder.len() {
+        match &remainder[index..index + 2] {
+            b"/*" => {
+                depth += 1;
+                index += 2;
+            }
+            b"*/" => {
+                depth -= 1;
+                index += 2;
+                if depth == 0 {
+                    lex.bump(index);
+                    return FilterResult::Skip;
+                }
+            }
+            _ => index += 1,
+        }
+    }
+
+    lex.bump(remainder.len());
+    FilterResult::Error(LexError::UnterminatedComment)
+}
+
+fn parse_integer(literal: &str) -> Result<u64, LexError> {
+    let (digits, radix) = match literal.get(..2) {
+        Some("0x") => (&literal[2..], 16),
+        Some("0o") => (&literal[2..], 8),
+        Some("0b") => (&literal[2..], 2),
+        _ => (literal, 10),
+    };
+    let digits = digits.replace('_', "");
+
+    if digits.is_empty() {
+        return Err(LexError::InvalidNumber(literal.to_string()));
+    }
+    u64::from_str_radix(&digits, radix).map_err(|_| LexError::InvalidNumber(literal.to_string()))
+}
+
+/// Floats too large for `f64` are rejected instead of becoming infinity.
+fn parse_float(literal: &str) -> Result<f64, LexError> {
+    match literal.replace('_', "").parse::<f64>() {
+        Ok(value) if value.is_finite() => Ok(value),
+        _ => Err(LexError::InvalidNumber(literal.to_string())),
+    }
 }
@@ -1,10 +1,111 @@
 #[derive(Debug, Clone, PartialEq)]
 pub enum Types {
+    I8,
+    I16,
     I32,
     I64,
+    /// A signed integer as wide as a pointer.
+    Isize,
+    U8,
+    U16,
+    U32,
+    U64,
+    /// An unsigned integer as wide as a pointer, used for sizes and indices.
+    Usize,
+    /// A Unicode scalar value, stored in 32 bits. Chars can be compared but
+    /// take no part in arithmetic.
+    Char,
     F32,
     F64,
     Bool,
     String,
+    /// The absence of a value, used for functions without a return type and
+    /// as the pointee of C's `void*`.
+    Void,
     Pointer(Box<Types>),
+    Struct(String),
+    /// A generic struct applied to type arguments, such as `Pair<i32, bool>`.
+    Generic(String, Vec<Types>),
+    /// A type parameter of the enclosing generic item, such as `T` in `fn max<T>`.
+    Param(String),
+    /// Another name for a type, declared by `type Handle = *i64;`. The
+    /// checker expands it, keeping the name for diagnostics.
+    Alias(String),
+    /// Some type implementing the named trait, only usable behind a pointer:
+    /// `*dyn Shape` pairs the data pointer with the type's vtable.
+    Dyn(String),
+    /// A function value taking the given parameters, such as `fn(i32) -> i32`.
+    /// Both named functions and closures have this type.
+    Function(Vec<Types>, Box<Types>),
+    /// An anonymous product of two or more types, such as `(i32, bool)`.
+    Tuple(Vec<Types>),
+    /// The built-in `Option<T>`: `Some(T)` or `None`.
+    Option(Box<Types>),
+    /// The built-in `Result<T, E>`: `Ok(T)` or `Err(E)`.
+    Result(Box<Types>, Box<Types>),
+    /// The built-in `Box<T>`: a pointer to a heap-allocated `T` that owns
+    /// it, freeing it when the variable holding the box goes out of scope.
+    Box(Box<Types>),
+}
+
+impl Types {
+    pub fn is_integer(&self) -> bool {
+        self.is_signed()
+            || matches!(
+                self,
+                Types::U8 | Types::U16 | Types::U32 | Types::U64 | Types::Usize
+            )
+    }
+
+    pub fn is_float(&self) -> bool {
+        matches!(self, Types::F32 | Types::F64)
+    }
+
+    pub fn is_signed(&self) -> bool {
+        matches!(
+            self,
+            Types::I8 | Types::I16 | Types::I32 | Types::I64 | Types::Isize
+        )
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        self.is_integer() || self.is_float()
+    }
+
+    /// The width of an integer type. `isize` and `usize` are 64 bits wide.
+    pub fn integer_bits(&self) -> Option<u32> {
+        match self {
+            Types::I8 | Types::U8 => Some(8),
+            Types::I16 | Types::U16 => Some(16),
+            Types::I32 | Types::U32 => Some(32),
+            Types::I64 | Types::U64 | Types::Isize | Types::Usize => Some(64),
+            _ => None,
+        }
+    }
+
+    /// Whether any type parameter occurs in this type.
+    pub fn has_params(&self) -> bool {
+        match self {
+            Types::Param(_) => true,
+            Types::Pointer(pointee) => pointee.has_params(),
+            Types::Generic(_, arguments) => arguments.iter().any(Types::has_params),
+            Types::Function(parameters, return_type) => {
+                parameters.iter().any(Types::has_params) || return_type.has_params()
+            }
+            Types::Tuple(elements) => elements.iter().any(Types::has_params),
+            Types::Option(value) | Types::Box(value) => value.has_params(),
+            Types::Result(value, error) => value.has_params() || error.has_params(),
+            _ => false,
+        }
+    }
+
+    /// Whether this is a `*dyn Trait` fat pointer.
+    pub fn is_dyn_pointer(&self) -> bool {
+        matches!(self, Types::Pointer(pointee) if matches!(**pointee, Types::Dyn(_)))
+    }
+
+    /// Whether this is `*i8`, the type C APIs use for strings.
+    pub fn is_c_string(&self) -> bool {
+        matches!(self, Types::Pointer(pointee) if **pointee == Types::I8)
+    }
 }
@@ -4,13 +4,31 @@ use std::fmt::{self};
 pub enum ParserError {
     UnknownType(String),
     UnknownCharInInput(char),
+    InvalidEscape(String),
+    MalformedCharLiteral(String),
+    UnterminatedComment,
+    InvalidNumber(String),
+    MisplacedDocComment(String),
+    MisplacedVisibility(String),
     MalformedBinaryOperator(String),
     MalformedFuncDecl(String),
     MalformedReturn(String),
+    MalformedDefer(String),
     MalformedVarDecl(String),
     MalformedIfElse(String),
     MalformedExpression(String),
     MalformedBlock(String),
+    MalformedStructDecl(String),
+    MalformedImpl(String),
+    MalformedTraitDecl(String),
+    MalformedClosure(String),
+    MalformedModuleDecl(String),
+    MalformedImport(String),
+    MalformedTypeAlias(String),
+    MalformedPrint(String),
+    MalformedHeapOp(String),
+    MalformedUnsafe(String),
+    MalformedStatic(String),
     InvalidAssignment(String),
     ExpectedToken(String),
     UnexpectedToken(String),
@@ -24,3 +42,27 @@ impl fmt::Display for ParserError {
 }
 
 impl std::error::Error for ParserError {}
+
+/// Errors raised while lexing a single token. Input that matches no token at
+/// all is left to the recovery in `Parser::new`.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub enum LexError {
+    #[default]
+    UnknownInput,
+    InvalidEscape(String),
+    MalformedCharLiteral(String),
+    UnterminatedComment,
+    InvalidNumber(String),
+}
+
+impl From<LexError> for ParserError {
+    fn from(err: LexError) -> Self {
+        match err {
+            LexError::InvalidEscape(escape) => ParserError::InvalidEscape(escape),
+            LexError::MalformedCharLiteral(literal) => ParserError::MalformedCharLiteral(literal),
+            LexError::UnterminatedComment => ParserError::UnterminatedComment,
+            LexError::InvalidNumber(literal) => ParserError::InvalidNumber(literal),
+            LexError::UnknownInput => ParserError::UnexpectedToken("unknown input".into()),
+        }
+    }
+}
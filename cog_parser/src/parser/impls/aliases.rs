@@ -0,0 +1,40 @@
+use crate::parser::{
+    Parser,
+    core::{expr::Expr, token::Token},
+    errors::ParserError,
+};
+
+impl Parser {
+    /// Parses `type Name = Type;`.
+    pub fn type_alias(&mut self) -> Result<Expr, ParserError> {
+        if !self.match_token(&Token::KeywordType) {
+            return Err(ParserError::ExpectedToken("expected `type`".into()));
+        }
+
+        let Some(Token::Identifier(identifier)) = self.peek().cloned() else {
+            return Err(ParserError::MalformedTypeAlias(
+                "expected alias name after `type`".into(),
+            ));
+        };
+        self.advance();
+
+        if !self.match_token(&Token::Equal) {
+            return Err(ParserError::MalformedTypeAlias(
+                "expected `=` after alias name".into(),
+            ));
+        }
+
+        let aliased = self.parse_type()?;
+
+        if !self.match_token(&Token::Semicolon) {
+            return Err(ParserError::MalformedTypeAlias(
+                "expected `;` after aliased type".into(),
+            ));
+        }
+
+        Ok(Expr::TypeAlias {
+            identifier,
+            aliased,
+        })
+    }
+}
@@ -51,10 +51,10 @@ impl Parser {
     }
 
     pub fn comparison(&mut self) -> Result<Expr, ParserError> {
-        let mut expr = self.term()?;
+        let mut expr = self.bit_or()?;
 
         while let Some(op) = self.match_comparison_op() {
-            let right = self.term()?;
+            let right = self.bit_or()?;
             expr = Expr::Binary {
                 left: Box::new(expr),
                 operator: Box::new(op),
@@ -64,4 +64,49 @@ impl Parser {
 
         Ok(expr)
     }
+
+    pub fn bit_or(&mut self) -> Result<Expr, ParserError> {
+        let mut expr = self.bit_xor()?;
+
+        while self.match_token(&Token::Pipe) {
+            let right = self.bit_xor()?;
+            expr = Expr::Binary {
+                left: Box::new(expr),
+                operator: Box::new(BinaryOp::BitOr),
+                right: Box::new(right),
+            };
+        }
+
+        Ok(expr)
+    }
+
+    pub fn bit_xor(&mut self) -> Result<Expr, ParserError> {
+        let mut expr = self.bit_and()?;
+
+        while self.match_token(&Token::Caret) {
+            let right = self.bit_and()?;
+            expr = Expr::Binary {
+                left: Box::new(expr),
+                operator: Box::new(BinaryOp::BitXor),
+                right: Box::new(right),
+            };
+        }
+
+        Ok(expr)
+    }
+
+    pub fn bit_and(&mut self) -> Result<Expr, ParserError> {
+        let mut expr = self.shift()?;
+
+        while self.match_token(&Token::Ampersand) {
+            let right = self.shift()?;
+            expr = Expr::Binary {
+                left: Box::new(expr),
+                operator: Box::new(BinaryOp::BitAnd),
+                right: Box::new(right),
+            };
+        }
+
+        Ok(expr)
+    }
 }
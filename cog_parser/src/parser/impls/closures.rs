@@ -0,0 +1,82 @@
+use crate::parser::{
+    Parser,
+    core::{expr::Expr, nodes::Nodes, token::Token},
+    errors::ParserError,
+};
+
+impl Parser {
+    /// Parses `|a, b: i32| body` or `|| body`. With a `-> Type` after the
+    /// parameters the body must be a block, as in `|x: i32| -> i32 { ... }`.
+    pub fn closure(&mut self) -> Result<Expr, ParserError> {
+        let parameters = if self.match_token(&Token::Or) {
+            Vec::new()
+        } else if self.match_token(&Token::Pipe) {
+            self.closure_parameters()?
+        } else {
+            return Err(ParserError::ExpectedToken("expected `|`".into()));
+        };
+
+        let return_type = if self.match_token(&Token::ArrowSmall) {
+            Some(self.parse_type()?)
+        } else {
+            None
+        };
+
+        let body = if return_type.is_some() {
+            if self.peek() != Some(&Token::LeftBrace) {
+                return Err(ParserError::MalformedClosure(
+                    "expected `{` after closure return type".into(),
+                ));
+            }
+            self.primary()?
+        } else {
+            self.or()?
+        };
+
+        Ok(Expr::Closure {
+            parameters,
+            return_type,
+            body: Box::new(body),
+            captures: Vec::new(),
+        })
+    }
+
+    /// Parses closure parameters after the opening `|`, up to and including
+    /// the closing `|`. Each parameter's type is optional.
+    fn closure_parameters(&mut self) -> Result<Vec<Expr>, ParserError> {
+        let mut parameters = Vec::new();
+
+        if self.match_token(&Token::Pipe) {
+            return Ok(parameters);
+        }
+
+        loop {
+            let Some(Token::Identifier(name)) = self.peek().cloned() else {
+                return Err(ParserError::MalformedClosure(
+                    "expected identifier in closure parameters".into(),
+                ));
+            };
+            self.advance();
+
+            let var_type = if self.match_token(&Token::Colon) {
+                Some(self.parse_type()?)
+            } else {
+                None
+            };
+            parameters.push(Expr::Declaration {
+                identifier: name,
+                var_type,
+                value: Box::new(Expr::Literal(Nodes::Identifier("placeholder".to_string()))),
+            });
+
+            if self.match_token(&Token::Pipe) {
+                return Ok(parameters);
+            }
+            if !self.match_token(&Token::Comma) {
+                return Err(ParserError::MalformedClosure(
+                    "expected `,` or `|` after closure parameter".into(),
+                ));
+            }
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::parser::{
     Parser,
-    core::{expr::Expr, token::Token},
+    core::{expr::Expr, token::Token, types::Types},
     errors::ParserError,
 };
 
@@ -19,48 +19,38 @@ impl Parser {
             ));
         };
 
+        if self.peek() == Some(&Token::Less) {
+            return self.generic_item(|parser| parser.func_signature_and_body(identifier));
+        }
+
+        self.func_signature_and_body(identifier)
+    }
+
+    fn func_signature_and_body(&mut self, identifier: String) -> Result<Expr, ParserError> {
+        let (parameters, return_type) = self.func_signature()?;
+        let body = self.func_body()?;
+
+        Ok(Expr::FunctionDeclaration {
+            identifier,
+            parameters,
+            body: Box::new(body),
+            return_type,
+        })
+    }
+
+    /// Parses a function's parameter list and optional return type.
+    pub fn func_signature(&mut self) -> Result<(Vec<Expr>, Option<Types>), ParserError> {
         if !self.match_token(&Token::LeftParen) {
             return Err(ParserError::MalformedFuncDecl(
                 "expected `(` after function name".into(),
             ));
         }
 
-        let mut parameters = Vec::new();
-        if !self.match_token(&Token::RightParen) {
-            loop {
-                if let Some(Token::Identifier(name)) = self.peek().cloned() {
-                    self.advance();
-                    if !self.match_token(&Token::Colon) {
-                        return Err(ParserError::MalformedFuncDecl(
-                            "expected `:` after parameter name".into(),
-                        ));
-                    }
-                    let param_type = self.parse_type()?;
-                    parameters.push(Expr::Declaration {
-                        identifier: name,
-                        var_type: Some(param_type),
-                        value: Box::new(Expr::Literal(
-                            crate::parser::core::nodes::Nodes::Identifier(
-                                "placeholder".to_string(),
-                            ),
-                        )),
-                    });
-                } else {
-                    return Err(ParserError::MalformedFuncDecl(
-                        "expected identifier in parameter list".into(),
-                    ));
-                }
-
-                if self.match_token(&Token::RightParen) {
-                    break;
-                }
-
-                if !self.match_token(&Token::Comma) {
-                    return Err(ParserError::MalformedFuncDecl(
-                        "expected `,` or `)` after parameter".into(),
-                    ));
-                }
-            }
+        let (parameters, is_variadic) = self.parse_parameters()?;
+        if is_variadic {
+            return Err(ParserError::MalformedFuncDecl(
+                "only `extern` functions can be variadic".into(),
+            ));
         }
 
         let return_type = if self.match_token(&Token::ArrowSmall) {
@@ -69,6 +59,10 @@ impl Parser {
             None
         };
 
+        Ok((parameters, return_type))
+    }
+
+    pub fn func_body(&mut self) -> Result<Expr, ParserError> {
         if !self.match_token(&Token::LeftBrace) {
             return Err(ParserError::MalformedFuncDecl(
                 "expected `{` before function body".into(),
@@ -86,23 +80,132 @@ impl Parser {
             ));
         }
 
-        Ok(Expr::FunctionDeclaration {
+        Ok(Expr::Block(body))
+    }
+
+    pub fn extern_declaration(&mut self) -> Result<Expr, ParserError> {
+        if !self.match_token(&Token::KeywordExtern) {
+            return Err(ParserError::ExpectedToken("expected `extern`".into()));
+        }
+
+        if !self.match_token(&Token::KeywordFn) {
+            return Err(ParserError::MalformedFuncDecl(
+                "expected `fn` after `extern`".into(),
+            ));
+        }
+
+        let identifier = if let Some(Token::Identifier(name)) = self.peek().cloned() {
+            self.advance();
+            name
+        } else {
+            return Err(ParserError::MalformedFuncDecl(
+                "expected identifier after `fn`".into(),
+            ));
+        };
+
+        if !self.match_token(&Token::LeftParen) {
+            return Err(ParserError::MalformedFuncDecl(
+                "expected `(` after function name".into(),
+            ));
+        }
+
+        let (parameters, is_variadic) = self.parse_parameters()?;
+
+        let return_type = if self.match_token(&Token::ArrowSmall) {
+            Some(self.parse_type()?)
+        } else {
+            None
+        };
+
+        if !self.match_token(&Token::Semicolon) {
+            return Err(ParserError::MalformedFuncDecl(
+                "expected `;` after extern function declaration".into(),
+            ));
+        }
+
+        Ok(Expr::ExternFunctionDeclaration {
             identifier,
             parameters,
-            body: Box::new(Expr::Block(body)),
             return_type,
+            is_variadic,
         })
     }
 
+    /// Parses a parameter list after the opening `(`, up to and including the
+    /// closing `)`. Returns the parameters and whether the list ended in `...`.
+    fn parse_parameters(&mut self) -> Result<(Vec<Expr>, bool), ParserError> {
+        let mut parameters = Vec::new();
+
+        if self.match_token(&Token::RightParen) {
+            return Ok((parameters, false));
+        }
+
+        loop {
+            if self.match_token(&Token::Ellipsis) {
+                if !self.match_token(&Token::RightParen) {
+                    return Err(ParserError::MalformedFuncDecl(
+                        "expected `)` after `...`".into(),
+                    ));
+                }
+                return Ok((parameters, true));
+            }
+
+            if let Some(Token::Identifier(name)) = self.peek().cloned() {
+                self.advance();
+                if !self.match_token(&Token::Colon) {
+                    return Err(ParserError::MalformedFuncDecl(
+                        "expected `:` after parameter name".into(),
+                    ));
+                }
+                let param_type = self.parse_type()?;
+                parameters.push(Expr::Declaration {
+                    identifier: name,
+                    var_type: Some(param_type),
+                    value: Box::new(Expr::Literal(
+                        crate::parser::core::nodes::Nodes::Identifier("placeholder".to_string()),
+                    )),
+                });
+            } else {
+                return Err(ParserError::MalformedFuncDecl(
+                    "expected identifier in parameter list".into(),
+                ));
+            }
+
+            if self.match_token(&Token::RightParen) {
+                return Ok((parameters, false));
+            }
+
+            if !self.match_token(&Token::Comma) {
+                return Err(ParserError::MalformedFuncDecl(
+                    "expected `,` or `)` after parameter".into(),
+                ));
+            }
+        }
+    }
+
     pub fn parse_return(&mut self) -> Result<Expr, ParserError> {
         if !self.match_token(&Token::KeywordReturn) {
             return Err(ParserError::MalformedReturn("expected `return`".into()));
         }
 
-        let value = self.expression()?;
+        let value = match self.peek() {
+            Some(Token::Semicolon | Token::RightBrace) | None => None,
+            _ => Some(Box::new(self.expression()?)),
+        };
 
-        Ok(Expr::Return {
-            value: Box::new(value),
-        })
+        Ok(Expr::Return { value })
+    }
+
+    pub fn parse_defer(&mut self) -> Result<Expr, ParserError> {
+        if !self.match_token(&Token::KeywordDefer) {
+            return Err(ParserError::MalformedDefer("expected `defer`".into()));
+        }
+
+        let deferred = self.expression()?;
+        if deferred.is_item() {
+            return Err(ParserError::MalformedDefer("cannot defer an item".into()));
+        }
+
+        Ok(Expr::Defer(Box::new(deferred)))
     }
 }
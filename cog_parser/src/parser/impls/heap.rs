@@ -0,0 +1,44 @@
+use crate::parser::{
+    Parser,
+    core::{expr::Expr, token::Token},
+    errors::ParserError,
+};
+
+impl Parser {
+    /// Parses `alloc<T>()` after the name.
+    pub fn alloc(&mut self) -> Result<Expr, ParserError> {
+        let mut arguments = self.type_arguments()?;
+        if arguments.len() != 1 {
+            return Err(ParserError::MalformedHeapOp(
+                "`alloc` takes one type argument".into(),
+            ));
+        }
+
+        if !self.match_token(&Token::LeftParen) || !self.match_token(&Token::RightParen) {
+            return Err(ParserError::MalformedHeapOp(
+                "expected `()` after `alloc<T>`".into(),
+            ));
+        }
+
+        Ok(Expr::Alloc(arguments.remove(0)))
+    }
+
+    /// Parses the parenthesized operand of `free` or `Box::new`, one
+    /// expression.
+    pub fn heap_operand(&mut self, name: &str) -> Result<Box<Expr>, ParserError> {
+        if !self.match_token(&Token::LeftParen) {
+            return Err(ParserError::ExpectedToken("expected `(`".into()));
+        }
+
+        let operand = self.expression()?;
+
+        if !self.match_token(&Token::RightParen) {
+            return Err(ParserError::MalformedHeapOp(format!(
+                "`{}` takes one argument",
+                name
+            )));
+        }
+
+        Ok(Box::new(operand))
+    }
+}
@@ -33,22 +33,12 @@ impl Parser {
         let then_branch = Expr::Block(then_statements);
 
         let else_branch = if self.match_token(&Token::KeywordElse) {
-            if !self.match_token(&Token::LeftBrace) {
-                return Err(ParserError::MalformedIfElse(
-                    "expected `{` after `else`".into(),
-                ));
+            // `else if` chains nest as an `IfElse` in the else branch
+            if let Some(Token::KeywordIf) = self.peek() {
+                Some(Box::new(self.if_else()?))
+            } else {
+                Some(Box::new(self.else_block()?))
             }
-
-            let mut else_statements = Vec::new();
-            while !self.match_token(&Token::RightBrace) && !self.is_at_end() {
-                else_statements.push(self.statement()?);
-            }
-
-            if self.previous() != Some(&Token::RightBrace) {
-                return Err(ParserError::MalformedIfElse("`}` else-block".into()));
-            }
-
-            Some(Box::new(Expr::Block(else_statements)))
         } else {
             None
         };
@@ -59,4 +49,23 @@ impl Parser {
             else_branch,
         })
     }
+
+    fn else_block(&mut self) -> Result<Expr, ParserError> {
+        if !self.match_token(&Token::LeftBrace) {
+            return Err(ParserError::MalformedIfElse(
+                "expected `{` after `else`".into(),
+            ));
+        }
+
+        let mut else_statements = Vec::new();
+        while !self.match_token(&Token::RightBrace) && !self.is_at_end() {
+            else_statements.push(self.statement()?);
+        }
+
+        if self.previous() != Some(&Token::RightBrace) {
+            return Err(ParserError::MalformedIfElse("`}` else-block".into()));
+        }
+
+        Ok(Expr::Block(else_statements))
+    }
 }
@@ -1,9 +1,16 @@
+pub mod aliases;
 pub mod bin_ops;
+pub mod closures;
 pub mod funcs;
+pub mod heap;
 pub mod if_else;
+pub mod modules;
 pub mod ops;
 pub mod parse;
 pub mod pointer_ops;
 pub mod primary;
+pub mod print;
+pub mod structs;
+pub mod traits;
 pub mod types;
 pub mod vars;
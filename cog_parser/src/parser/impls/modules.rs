@@ -0,0 +1,66 @@
+use crate::parser::{
+    Parser,
+    core::{expr::Expr, token::Token},
+    errors::ParserError,
+};
+
+impl Parser {
+    /// Parses `mod name;`.
+    pub fn module_declaration(&mut self) -> Result<Expr, ParserError> {
+        if !self.match_token(&Token::KeywordMod) {
+            return Err(ParserError::ExpectedToken("expected `mod`".into()));
+        }
+
+        let Some(Token::Identifier(identifier)) = self.peek().cloned() else {
+            return Err(ParserError::MalformedModuleDecl(
+                "expected module name after `mod`".into(),
+            ));
+        };
+        self.advance();
+
+        if !self.match_token(&Token::Semicolon) {
+            return Err(ParserError::MalformedModuleDecl(
+                "expected `;` after module name".into(),
+            ));
+        }
+
+        Ok(Expr::ModuleDeclaration { identifier })
+    }
+
+    /// Parses `import a::b::c;`. A path needs at least two segments, the
+    /// module and the name imported from it.
+    pub fn import(&mut self) -> Result<Expr, ParserError> {
+        if !self.match_token(&Token::KeywordImport) {
+            return Err(ParserError::ExpectedToken("expected `import`".into()));
+        }
+
+        let mut path = Vec::new();
+        loop {
+            let Some(Token::Identifier(segment)) = self.peek().cloned() else {
+                return Err(ParserError::MalformedImport(
+                    "expected identifier in import path".into(),
+                ));
+            };
+            self.advance();
+            path.push(segment);
+
+            if !self.match_token(&Token::ColonColon) {
+                break;
+            }
+        }
+
+        if path.len() < 2 {
+            return Err(ParserError::MalformedImport(format!(
+                "expected `::` after `{}`",
+                path[0]
+            )));
+        }
+        if !self.match_token(&Token::Semicolon) {
+            return Err(ParserError::MalformedImport(
+                "expected `;` after import path".into(),
+            ));
+        }
+
+        Ok(Expr::Import { path })
+    }
+}
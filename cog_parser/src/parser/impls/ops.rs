@@ -33,6 +33,16 @@ impl Parser {
         }
     }
 
+    pub fn match_shift_op(&mut self) -> Option<BinaryOp> {
+        if self.match_token(&Token::LessLess) {
+            Some(BinaryOp::ShiftLeft)
+        } else if self.match_token(&Token::GreaterGreater) {
+            Some(BinaryOp::ShiftRight)
+        } else {
+            None
+        }
+    }
+
     pub fn match_term_op(&mut self) -> Option<BinaryOp> {
         if self.match_token(&Token::Minus) {
             Some(BinaryOp::Subtract)
@@ -55,11 +65,53 @@ impl Parser {
         }
     }
 
+    pub fn match_compound_assign_op(&mut self) -> Option<BinaryOp> {
+        if self.match_token(&Token::PlusEqual) {
+            Some(BinaryOp::Add)
+        } else if self.match_token(&Token::MinusEqual) {
+            Some(BinaryOp::Subtract)
+        } else if self.match_token(&Token::StarEqual) {
+            Some(BinaryOp::Multiply)
+        } else if self.match_token(&Token::SlashEqual) {
+            Some(BinaryOp::Divide)
+        } else if self.match_token(&Token::PercentEqual) {
+            Some(BinaryOp::Modulo)
+        } else if self.match_token(&Token::AmpersandEqual) {
+            Some(BinaryOp::BitAnd)
+        } else if self.match_token(&Token::PipeEqual) {
+            Some(BinaryOp::BitOr)
+        } else if self.match_token(&Token::CaretEqual) {
+            Some(BinaryOp::BitXor)
+        } else if self.match_token(&Token::LessLessEqual) {
+            Some(BinaryOp::ShiftLeft)
+        } else if self.match_token(&Token::GreaterGreaterEqual) {
+            Some(BinaryOp::ShiftRight)
+        } else {
+            None
+        }
+    }
+
     pub fn match_unary_op(&mut self) -> Option<UnaryOp> {
         if self.match_token(&Token::Minus) {
             Some(UnaryOp::Minus)
         } else if self.match_token(&Token::Bang) {
             Some(UnaryOp::Not)
+        } else if self.match_token(&Token::Tilde) {
+            Some(UnaryOp::BitNot)
+        } else if self.match_token(&Token::PlusPlus) {
+            Some(UnaryOp::PreIncrement)
+        } else if self.match_token(&Token::MinusMinus) {
+            Some(UnaryOp::PreDecrement)
+        } else {
+            None
+        }
+    }
+
+    pub fn match_postfix_op(&mut self) -> Option<UnaryOp> {
+        if self.match_token(&Token::PlusPlus) {
+            Some(UnaryOp::PostIncrement)
+        } else if self.match_token(&Token::MinusMinus) {
+            Some(UnaryOp::PostDecrement)
         } else {
             None
         }
@@ -67,6 +119,21 @@ impl Parser {
 }
 
 impl Parser {
+    pub fn shift(&mut self) -> Result<Expr, ParserError> {
+        let mut expr = self.term()?;
+
+        while let Some(op) = self.match_shift_op() {
+            let right = self.term()?;
+            expr = Expr::Binary {
+                left: Box::new(expr),
+                operator: Box::new(op),
+                right: Box::new(right),
+            };
+        }
+
+        Ok(expr)
+    }
+
     pub fn term(&mut self) -> Result<Expr, ParserError> {
         let mut expr = self.factor()?;
 
@@ -83,10 +150,10 @@ impl Parser {
     }
 
     pub fn factor(&mut self) -> Result<Expr, ParserError> {
-        let mut expr = self.unary()?;
+        let mut expr = self.cast()?;
 
         while let Some(op) = self.match_factor_op() {
-            let right = self.unary()?;
+            let right = self.cast()?;
             expr = Expr::Binary {
                 left: Box::new(expr),
                 operator: Box::new(op),
@@ -108,4 +175,81 @@ impl Parser {
 
         self.pointer_ops()
     }
+
+    pub fn postfix(&mut self) -> Result<Expr, ParserError> {
+        let mut expr = self.primary()?;
+
+        loop {
+            if self.match_token(&Token::LeftParen) {
+                let arguments = self.call_arguments()?;
+                expr = Expr::Call {
+                    callee: Box::new(expr),
+                    arguments,
+                };
+            } else if self.match_token(&Token::Dot) {
+                let Some(Token::Identifier(name)) = self.peek().cloned() else {
+                    return Err(ParserError::MalformedExpression(
+                        "expected field or method name after `.`".into(),
+                    ));
+                };
+                self.advance();
+
+                if self.match_token(&Token::LeftParen) {
+                    let arguments = self.call_arguments()?;
+                    expr = Expr::MethodCall {
+                        receiver: Box::new(expr),
+                        method: name,
+                        arguments,
+                    };
+                } else {
+                    expr = Expr::FieldAccess {
+                        object: Box::new(expr),
+                        field: name,
+                    };
+                }
+            } else if let Some(Token::TupleIndex(indices)) = self.peek().cloned() {
+                self.advance();
+                for index in indices {
+                    expr = Expr::FieldAccess {
+                        object: Box::new(expr),
+                        field: index.to_string(),
+                    };
+                }
+            } else if self.match_token(&Token::Question) {
+                expr = Expr::Try(Box::new(expr));
+            } else if let Some(op) = self.match_postfix_op() {
+                expr = Expr::Unary {
+                    operator: Box::new(op),
+                    operand: Box::new(expr),
+                };
+            } else {
+                break;
+            }
+        }
+
+        Ok(expr)
+    }
+
+    /// Parses call arguments after the opening `(`, up to and including the closing `)`.
+    pub fn call_arguments(&mut self) -> Result<Vec<Expr>, ParserError> {
+        let mut arguments = Vec::new();
+
+        if self.match_token(&Token::RightParen) {
+            return Ok(arguments);
+        }
+
+        loop {
+            arguments.push(self.expression()?);
+
+            if self.match_token(&Token::RightParen) {
+                return Ok(arguments);
+            }
+
+            if !self.match_token(&Token::Comma) {
+                return Err(ParserError::MalformedExpression(
+                    "expected `,` or `)` after argument".into(),
+                ));
+            }
+        }
+    }
 }
@@ -19,12 +19,50 @@ impl Parser {
     }
 
     pub fn statement(&mut self) -> Result<Expr, ParserError> {
+        let doc = self.doc_comment();
+        let public = self.match_token(&Token::KeywordPub);
         let expr = self.expression()?;
 
         // consume `;`
         self.match_token(&Token::Semicolon);
 
-        Ok(expr)
+        let expr = if public { self.public(expr)? } else { expr };
+
+        match doc {
+            Some(doc) if expr.is_item() => Ok(Expr::Documented {
+                doc,
+                item: Box::new(expr),
+            }),
+            Some(doc) => Err(ParserError::MisplacedDocComment(doc)),
+            None => Ok(expr),
+        }
+    }
+
+    /// Marks an item `pub`. Impl blocks have no name of their own to export,
+    /// so only their methods may be `pub`.
+    pub fn public(&self, item: Expr) -> Result<Expr, ParserError> {
+        match item {
+            Expr::ImplBlock { .. } | Expr::TraitImpl { .. } => Err(
+                ParserError::MisplacedVisibility("`pub` on an impl block".into()),
+            ),
+            item if item.is_item() => Ok(Expr::Public {
+                item: Box::new(item),
+            }),
+            _ => Err(ParserError::MisplacedVisibility(
+                "`pub` on an expression".into(),
+            )),
+        }
+    }
+
+    /// Consumes any `///` lines ahead, joining them into one doc string.
+    pub fn doc_comment(&mut self) -> Option<String> {
+        let mut lines = Vec::new();
+        while let Some(Token::DocComment(line)) = self.peek().cloned() {
+            self.advance();
+            lines.push(line);
+        }
+
+        (!lines.is_empty()).then(|| lines.join("\n"))
     }
 
     pub fn expression(&mut self) -> Result<Expr, ParserError> {
@@ -34,9 +72,36 @@ impl Parser {
         if let Some(Token::KeywordFn) = self.peek() {
             return self.func_declaration();
         }
+        if let Some(Token::KeywordStruct) = self.peek() {
+            return self.struct_declaration();
+        }
+        if let Some(Token::KeywordImpl) = self.peek() {
+            return self.impl_block();
+        }
+        if let Some(Token::KeywordTrait) = self.peek() {
+            return self.trait_declaration();
+        }
+        if let Some(Token::KeywordExtern) = self.peek() {
+            return self.extern_declaration();
+        }
+        if let Some(Token::KeywordType) = self.peek() {
+            return self.type_alias();
+        }
+        if let Some(Token::KeywordStatic) = self.peek() {
+            return self.static_declaration();
+        }
+        if let Some(Token::KeywordMod) = self.peek() {
+            return self.module_declaration();
+        }
+        if let Some(Token::KeywordImport) = self.peek() {
+            return self.import();
+        }
         if let Some(Token::KeywordReturn) = self.peek() {
             return self.parse_return();
         }
+        if let Some(Token::KeywordDefer) = self.peek() {
+            return self.parse_defer();
+        }
         self.assignment()
     }
 }
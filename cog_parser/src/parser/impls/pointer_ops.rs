@@ -10,18 +10,45 @@ impl Parser {
             match token {
                 Token::Ampersand => {
                     self.advance();
-                    let operand = self.primary()?;
+                    let operand = self.postfix()?;
                     Ok(Expr::AddressOf(Box::new(operand)))
                 }
                 Token::Star => {
                     self.advance();
-                    let operand = self.primary()?;
+                    let operand = self.postfix()?;
                     Ok(Expr::Dereference(Box::new(operand)))
                 }
-                _ => self.primary(),
+                _ => self.postfix(),
             }
         } else {
             Err(ParserError::UnexpectedEndOfInput)
         }
     }
+
+    /// `unary ('as' type)*`. Casts bind tighter than any binary operator, so
+    /// `p as *u8 + 1` offsets the cast pointer.
+    pub fn cast(&mut self) -> Result<Expr, ParserError> {
+        let mut expr = self.unary()?;
+
+        while self.match_token(&Token::KeywordAs) {
+            let target = self.parse_type()?;
+            expr = Expr::Cast {
+                value: Box::new(expr),
+                target,
+            };
+        }
+
+        Ok(expr)
+    }
+
+    pub fn unsafe_block(&mut self) -> Result<Expr, ParserError> {
+        self.advance(); // consume `unsafe`
+        if self.peek() != Some(&Token::LeftBrace) {
+            return Err(ParserError::MalformedUnsafe(
+                "expected `{` after `unsafe`".into(),
+            ));
+        }
+
+        Ok(Expr::Unsafe(Box::new(self.primary()?)))
+    }
 }
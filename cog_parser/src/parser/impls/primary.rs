@@ -1,9 +1,17 @@
 use crate::parser::{
     Parser,
-    core::{expr::Expr, nodes::Nodes, token::Token},
+    core::{
+        expr::{Expr, Variant},
+        nodes::Nodes,
+        token::Token,
+    },
     errors::ParserError,
 };
 
+/// Names parsed as built-ins rather than calls: `print(...)`,
+/// `println(...)`, `alloc<T>()` and `free(...)`.
+pub const BUILTIN_FUNCTIONS: &[&str] = &["print", "println", "alloc", "free"];
+
 impl Parser {
     pub fn primary(&mut self) -> Result<Expr, ParserError> {
         if let Some(token) = self.peek().cloned() {
@@ -20,17 +28,60 @@ impl Parser {
                     self.advance();
                     Ok(Expr::Literal(Nodes::String(value)))
                 }
+                Token::Char(value) => {
+                    self.advance();
+                    Ok(Expr::Literal(Nodes::Char(value)))
+                }
+                Token::Byte(value) => {
+                    self.advance();
+                    Ok(Expr::Literal(Nodes::Byte(value)))
+                }
                 Token::Boolean(value) => {
                     self.advance();
                     Ok(Expr::Literal(Nodes::Boolean(value)))
                 }
+                Token::KeywordNull => {
+                    self.advance();
+                    Ok(Expr::Literal(Nodes::Null))
+                }
+                Token::KeywordUnsafe => self.unsafe_block(),
                 Token::Identifier(name) => {
                     self.advance();
+
+                    if let Some(variant) = self.match_variant(&name) {
+                        return self.variant(variant);
+                    }
+
+                    match (name.as_str(), self.peek()) {
+                        ("print" | "println", Some(Token::LeftParen)) => {
+                            return self.print(name == "println");
+                        }
+                        ("alloc", Some(Token::Less)) => return self.alloc(),
+                        ("free", Some(Token::LeftParen)) => {
+                            return Ok(Expr::Free(self.heap_operand(&name)?));
+                        }
+                        _ => {}
+                    }
+
+                    if let Some(Token::ColonColon) = self.peek() {
+                        return self.path(name);
+                    }
+
+                    if self.type_names.contains(&name)
+                        && let Some(Token::LeftBrace) = self.peek()
+                    {
+                        return self.struct_literal(name);
+                    }
+
                     Ok(Expr::Literal(Nodes::Identifier(name)))
                 }
+                Token::Pipe | Token::Or => self.closure(),
                 Token::LeftParen => {
                     self.advance(); // consume `(`
                     let expr = self.expression()?;
+                    if self.peek() == Some(&Token::Comma) {
+                        return self.tuple(expr);
+                    }
                     if !self.match_token(&Token::RightParen) {
                         return Err(ParserError::MalformedExpression(
                             "expected `)` after expression".into(),
@@ -61,4 +112,92 @@ impl Parser {
             Err(ParserError::UnexpectedEndOfInput)
         }
     }
+
+    /// Recognizes the `Option` and `Result` constructors. All but `None` are
+    /// only constructors when called.
+    fn match_variant(&self, name: &str) -> Option<Variant> {
+        match (name, self.peek()) {
+            ("None", _) => Some(Variant::None),
+            ("Some", Some(Token::LeftParen)) => Some(Variant::Some),
+            ("Ok", Some(Token::LeftParen)) => Some(Variant::Ok),
+            ("Err", Some(Token::LeftParen)) => Some(Variant::Err),
+            _ => None,
+        }
+    }
+
+    /// Parses the parenthesized value of a constructor, if it takes one.
+    fn variant(&mut self, variant: Variant) -> Result<Expr, ParserError> {
+        if variant == Variant::None {
+            return Ok(Expr::Variant {
+                variant,
+                value: None,
+            });
+        }
+
+        self.advance(); // consume `(`
+        let value = self.expression()?;
+        if !self.match_token(&Token::RightParen) {
+            return Err(ParserError::MalformedExpression(format!(
+                "expected `)` after `{:?}` value",
+                variant
+            )));
+        }
+
+        Ok(Expr::Variant {
+            variant,
+            value: Some(Box::new(value)),
+        })
+    }
+
+    /// Parses the rest of a tuple expression whose first element has been
+    /// consumed. A trailing comma is allowed, and makes `(x,)` a tuple.
+    fn tuple(&mut self, first: Expr) -> Result<Expr, ParserError> {
+        let mut elements = vec![first];
+
+        while self.match_token(&Token::Comma) {
+            if self.peek() == Some(&Token::RightParen) {
+                break;
+            }
+            elements.push(self.expression()?);
+        }
+
+        if !self.match_token(&Token::RightParen) {
+            return Err(ParserError::MalformedExpression(
+                "expected `,` or `)` in tuple".into(),
+            ));
+        }
+
+        Ok(Expr::Tuple(elements))
+    }
+
+    /// Parses the rest of a `::` path whose first segment has been consumed.
+    fn path(&mut self, first: String) -> Result<Expr, ParserError> {
+        let mut segments = vec![first];
+
+        while self.match_token(&Token::ColonColon) {
+            if let Some(Token::Identifier(segment)) = self.peek().cloned() {
+                self.advance();
+                segments.push(segment);
+            } else {
+                return Err(ParserError::MalformedExpression(
+                    "expected identifier after `::`".into(),
+                ));
+            }
+        }
+
+        if segments == ["Box", "new"] && self.peek() == Some(&Token::LeftParen) {
+            return Ok(Expr::BoxNew(self.heap_operand("Box::new")?));
+        }
+
+        // A struct named through a module, as in `geo::Point { x: 1 }`. The
+        // type is not known here, so the `field:` after the brace decides.
+        if self.peek() == Some(&Token::LeftBrace)
+            && matches!(self.peek_nth(1), Some(Token::Identifier(_)))
+            && self.peek_nth(2) == Some(&Token::Colon)
+        {
+            return self.struct_literal(segments.join("::"));
+        }
+
+        Ok(Expr::Path(segments))
+    }
 }
@@ -0,0 +1,69 @@
+use crate::parser::{
+    Parser,
+    core::{expr::Expr, token::Token},
+    errors::ParserError,
+};
+
+impl Parser {
+    /// Parses the arguments of `print` or `println` after the name. The first
+    /// argument must be a string literal, so its placeholders can be counted
+    /// before the program runs.
+    pub fn print(&mut self, newline: bool) -> Result<Expr, ParserError> {
+        if !self.match_token(&Token::LeftParen) {
+            return Err(ParserError::ExpectedToken("expected `(`".into()));
+        }
+
+        let Some(Token::String(format)) = self.peek().cloned() else {
+            return Err(ParserError::MalformedPrint(
+                "expected a format string literal".into(),
+            ));
+        };
+        self.advance();
+        let pieces = format_pieces(&format)?;
+
+        let arguments = if self.match_token(&Token::Comma) {
+            self.call_arguments()?
+        } else if self.match_token(&Token::RightParen) {
+            Vec::new()
+        } else {
+            return Err(ParserError::MalformedPrint(
+                "expected `,` or `)` after format string".into(),
+            ));
+        };
+
+        Ok(Expr::Print {
+            pieces,
+            arguments,
+            newline,
+        })
+    }
+}
+
+/// Splits a format string at its `{}` placeholders. `{{` and `}}` stand for
+/// literal braces; any other brace is an error.
+fn format_pieces(format: &str) -> Result<Vec<String>, ParserError> {
+    let mut pieces = vec![String::new()];
+    let mut chars = format.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('{', Some('{')) | ('}', Some('}')) => {
+                chars.next();
+                pieces.last_mut().unwrap().push(c);
+            }
+            ('{', Some('}')) => {
+                chars.next();
+                pieces.push(String::new());
+            }
+            ('{' | '}', _) => {
+                return Err(ParserError::MalformedPrint(format!(
+                    "unmatched `{}` in format string",
+                    c
+                )));
+            }
+            _ => pieces.last_mut().unwrap().push(c),
+        }
+    }
+
+    Ok(pieces)
+}
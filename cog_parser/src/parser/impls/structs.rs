@@ -0,0 +1,195 @@
+use crate::parser::{
+    Parser,
+    core::{
+        expr::{Expr, StructField},
+        token::Token,
+        types::Types,
+    },
+    errors::ParserError,
+};
+
+impl Parser {
+    pub fn struct_declaration(&mut self) -> Result<Expr, ParserError> {
+        if !self.match_token(&Token::KeywordStruct) {
+            return Err(ParserError::ExpectedToken("expected `struct`".into()));
+        }
+
+        let identifier = if let Some(Token::Identifier(name)) = self.peek().cloned() {
+            self.advance();
+            name
+        } else {
+            return Err(ParserError::MalformedStructDecl(
+                "expected identifier after `struct`".into(),
+            ));
+        };
+
+        if self.peek() == Some(&Token::Less) {
+            return self.generic_item(|parser| parser.struct_fields(identifier));
+        }
+
+        self.struct_fields(identifier)
+    }
+
+    fn struct_fields(&mut self, identifier: String) -> Result<Expr, ParserError> {
+        if !self.match_token(&Token::LeftBrace) {
+            return Err(ParserError::MalformedStructDecl(
+                "expected `{` after struct name".into(),
+            ));
+        }
+
+        let mut fields = Vec::new();
+        while !self.match_token(&Token::RightBrace) {
+            let doc = self.doc_comment();
+            let public = self.match_token(&Token::KeywordPub);
+            let Some(Token::Identifier(name)) = self.peek().cloned() else {
+                return Err(ParserError::MalformedStructDecl(
+                    "expected field name or `}`".into(),
+                ));
+            };
+            self.advance();
+
+            if !self.match_token(&Token::Colon) {
+                return Err(ParserError::MalformedStructDecl(
+                    "expected `:` after field name".into(),
+                ));
+            }
+
+            fields.push(StructField {
+                identifier: name,
+                field_type: self.parse_type()?,
+                doc,
+                public,
+            });
+
+            if !self.match_token(&Token::Comma) && self.peek() != Some(&Token::RightBrace) {
+                return Err(ParserError::MalformedStructDecl(
+                    "expected `,` or `}` after field".into(),
+                ));
+            }
+        }
+
+        Ok(Expr::StructDeclaration { identifier, fields })
+    }
+
+    /// Parses the `{ field: value, ... }` part of a struct literal.
+    pub fn struct_literal(&mut self, identifier: String) -> Result<Expr, ParserError> {
+        if !self.match_token(&Token::LeftBrace) {
+            return Err(ParserError::MalformedExpression(
+                "expected `{` after struct name".into(),
+            ));
+        }
+
+        let mut fields = Vec::new();
+        while !self.match_token(&Token::RightBrace) {
+            let Some(Token::Identifier(name)) = self.peek().cloned() else {
+                return Err(ParserError::MalformedExpression(
+                    "expected field name or `}` in struct literal".into(),
+                ));
+            };
+            self.advance();
+
+            if !self.match_token(&Token::Colon) {
+                return Err(ParserError::MalformedExpression(
+                    "expected `:` after field name".into(),
+                ));
+            }
+
+            fields.push((name, self.expression()?));
+
+            if !self.match_token(&Token::Comma) && self.peek() != Some(&Token::RightBrace) {
+                return Err(ParserError::MalformedExpression(
+                    "expected `,` or `}` after field value".into(),
+                ));
+            }
+        }
+
+        Ok(Expr::StructLiteral { identifier, fields })
+    }
+
+    /// Parses `impl Type { ... }` or `impl Trait for Type { ... }`.
+    pub fn impl_block(&mut self) -> Result<Expr, ParserError> {
+        if !self.match_token(&Token::KeywordImpl) {
+            return Err(ParserError::ExpectedToken("expected `impl`".into()));
+        }
+
+        let first = self.impl_name("expected type name after `impl`")?;
+        let (trait_name, type_name) = if self.match_token(&Token::KeywordFor) {
+            (
+                Some(first),
+                self.impl_name("expected type name after `for`")?,
+            )
+        } else {
+            (None, first)
+        };
+
+        if !self.match_token(&Token::LeftBrace) {
+            return Err(ParserError::MalformedImpl(
+                "expected `{` after type name".into(),
+            ));
+        }
+
+        let outer = self.self_type.replace(Types::Struct(type_name.clone()));
+        let methods = self.impl_methods(trait_name.is_some());
+        self.self_type = outer;
+        let methods = methods?;
+
+        match trait_name {
+            Some(trait_name) => Ok(Expr::TraitImpl {
+                trait_name,
+                type_name,
+                methods,
+            }),
+            None => Ok(Expr::ImplBlock { type_name, methods }),
+        }
+    }
+
+    fn impl_name(&mut self, message: &str) -> Result<String, ParserError> {
+        if let Some(Token::Identifier(name)) = self.peek().cloned() {
+            self.advance();
+            self.qualified_name(name)
+        } else {
+            Err(ParserError::MalformedImpl(message.into()))
+        }
+    }
+
+    /// Parses the methods of an impl block. Methods of a trait impl are as
+    /// visible as the trait, so only inherent methods may be `pub`.
+    fn impl_methods(&mut self, trait_impl: bool) -> Result<Vec<Expr>, ParserError> {
+        let mut methods = Vec::new();
+        while !self.match_token(&Token::RightBrace) {
+            if self.is_at_end() {
+                return Err(ParserError::MalformedImpl(
+                    "expected `}` after impl block".into(),
+                ));
+            }
+            let doc = self.doc_comment();
+            let public = self.match_token(&Token::KeywordPub);
+            if public && trait_impl {
+                return Err(ParserError::MisplacedVisibility(
+                    "`pub` on a trait method".into(),
+                ));
+            }
+
+            let method = match self.func_declaration()? {
+                Expr::Generic { .. } => {
+                    return Err(ParserError::MalformedImpl(
+                        "methods cannot have type parameters".into(),
+                    ));
+                }
+                method if public => Expr::Public {
+                    item: Box::new(method),
+                },
+                method => method,
+            };
+            methods.push(match doc {
+                Some(doc) => Expr::Documented {
+                    doc,
+                    item: Box::new(method),
+                },
+                None => method,
+            });
+        }
+
+        Ok(methods)
+    }
+}
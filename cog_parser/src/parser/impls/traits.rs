@@ -0,0 +1,91 @@
+use crate::parser::{
+    Parser,
+    core::{
+        expr::{Expr, TraitMethod},
+        token::Token,
+        types::Types,
+    },
+    errors::ParserError,
+};
+
+impl Parser {
+    pub fn trait_declaration(&mut self) -> Result<Expr, ParserError> {
+        if !self.match_token(&Token::KeywordTrait) {
+            return Err(ParserError::ExpectedToken("expected `trait`".into()));
+        }
+
+        let identifier = if let Some(Token::Identifier(name)) = self.peek().cloned() {
+            self.advance();
+            name
+        } else {
+            return Err(ParserError::MalformedTraitDecl(
+                "expected identifier after `trait`".into(),
+            ));
+        };
+
+        if !self.match_token(&Token::LeftBrace) {
+            return Err(ParserError::MalformedTraitDecl(
+                "expected `{` after trait name".into(),
+            ));
+        }
+
+        // Inside a trait, `Self` stands for whichever type implements it.
+        let outer = self.self_type.replace(Types::Param("Self".into()));
+        let methods = self.trait_methods();
+        self.self_type = outer;
+
+        Ok(Expr::TraitDeclaration {
+            identifier,
+            methods: methods?,
+        })
+    }
+
+    fn trait_methods(&mut self) -> Result<Vec<TraitMethod>, ParserError> {
+        let mut methods = Vec::new();
+        while !self.match_token(&Token::RightBrace) {
+            if self.is_at_end() {
+                return Err(ParserError::MalformedTraitDecl(
+                    "expected `}` after trait body".into(),
+                ));
+            }
+            methods.push(self.trait_method()?);
+        }
+
+        Ok(methods)
+    }
+
+    /// Parses `fn name(...) -> T;`, or a method with a default body.
+    fn trait_method(&mut self) -> Result<TraitMethod, ParserError> {
+        let doc = self.doc_comment();
+        if !self.match_token(&Token::KeywordFn) {
+            return Err(ParserError::MalformedTraitDecl(
+                "expected `fn` in trait body".into(),
+            ));
+        }
+
+        let identifier = if let Some(Token::Identifier(name)) = self.peek().cloned() {
+            self.advance();
+            name
+        } else {
+            return Err(ParserError::MalformedTraitDecl(
+                "expected identifier after `fn`".into(),
+            ));
+        };
+
+        let (parameters, return_type) = self.func_signature()?;
+
+        let body = if self.match_token(&Token::Semicolon) {
+            None
+        } else {
+            Some(Box::new(self.func_body()?))
+        };
+
+        Ok(TraitMethod {
+            identifier,
+            parameters,
+            return_type,
+            body,
+            doc,
+        })
+    }
+}
@@ -1,6 +1,10 @@
 use crate::parser::{
     Parser,
-    core::{token::Token, types::Types},
+    core::{
+        expr::{Expr, TypeParameter},
+        token::Token,
+        types::Types,
+    },
     errors::ParserError,
 };
 
@@ -10,16 +14,53 @@ impl Parser {
             match token {
                 Token::Identifier(type_name) => {
                     self.advance();
+                    if self.peek() == Some(&Token::ColonColon) {
+                        return self.qualified_type(type_name);
+                    }
                     match type_name.as_str() {
+                        "i8" => Ok(Types::I8),
+                        "i16" => Ok(Types::I16),
                         "i32" => Ok(Types::I32),
                         "i64" => Ok(Types::I64),
+                        "isize" => Ok(Types::Isize),
+                        "u8" => Ok(Types::U8),
+                        "u16" => Ok(Types::U16),
+                        "u32" => Ok(Types::U32),
+                        "u64" => Ok(Types::U64),
+                        "usize" => Ok(Types::Usize),
+                        "char" => Ok(Types::Char),
                         "f32" => Ok(Types::F32),
                         "f64" => Ok(Types::F64),
                         "bool" => Ok(Types::Bool),
                         "String" => Ok(Types::String),
+                        "void" => Ok(Types::Void),
+                        "Option" | "Result" | "Box" => self.builtin_type(type_name),
+                        "Self" => self
+                            .self_type
+                            .clone()
+                            .ok_or(ParserError::UnknownType(type_name)),
+                        _ if self.type_parameters.contains(&type_name) => {
+                            Ok(Types::Param(type_name))
+                        }
+                        _ if self.alias_names.contains(&type_name) => Ok(Types::Alias(type_name)),
+                        _ if self.type_names.contains(&type_name) => {
+                            if self.peek() == Some(&Token::Less) {
+                                Ok(Types::Generic(type_name, self.type_arguments()?))
+                            } else {
+                                Ok(Types::Struct(type_name))
+                            }
+                        }
                         _ => Err(ParserError::UnknownType(type_name)),
                     }
                 }
+                Token::KeywordTypeI8 => {
+                    self.advance();
+                    Ok(Types::I8)
+                }
+                Token::KeywordTypeI16 => {
+                    self.advance();
+                    Ok(Types::I16)
+                }
                 Token::KeywordTypeI32 => {
                     self.advance();
                     Ok(Types::I32)
@@ -28,6 +69,34 @@ impl Parser {
                     self.advance();
                     Ok(Types::I64)
                 }
+                Token::KeywordTypeIsize => {
+                    self.advance();
+                    Ok(Types::Isize)
+                }
+                Token::KeywordTypeU8 => {
+                    self.advance();
+                    Ok(Types::U8)
+                }
+                Token::KeywordTypeU16 => {
+                    self.advance();
+                    Ok(Types::U16)
+                }
+                Token::KeywordTypeU32 => {
+                    self.advance();
+                    Ok(Types::U32)
+                }
+                Token::KeywordTypeU64 => {
+                    self.advance();
+                    Ok(Types::U64)
+                }
+                Token::KeywordTypeUsize => {
+                    self.advance();
+                    Ok(Types::Usize)
+                }
+                Token::KeywordTypeChar => {
+                    self.advance();
+                    Ok(Types::Char)
+                }
                 Token::KeywordTypeF32 => {
                     self.advance();
                     Ok(Types::F32)
@@ -44,15 +113,249 @@ impl Parser {
                     self.advance();
                     Ok(Types::String)
                 }
+                Token::KeywordTypeVoid => {
+                    self.advance();
+                    Ok(Types::Void)
+                }
+                Token::KeywordDyn => {
+                    self.advance();
+                    match self.peek().cloned() {
+                        Some(Token::Identifier(trait_name)) => {
+                            self.advance();
+                            Ok(Types::Dyn(self.qualified_name(trait_name)?))
+                        }
+                        _ => Err(ParserError::ExpectedToken(
+                            "expected trait name after `dyn`".into(),
+                        )),
+                    }
+                }
                 Token::Star => {
                     self.advance();
                     let pointee_type = self.parse_type()?;
                     Ok(Types::Pointer(Box::new(pointee_type)))
                 }
+                Token::KeywordFn => {
+                    self.advance();
+                    self.function_type()
+                }
+                Token::LeftParen => {
+                    self.advance();
+                    self.tuple_type()
+                }
                 _ => Err(ParserError::ExpectedToken("type".into())),
             }
         } else {
             Err(ParserError::ExpectedToken("type".into()))
         }
     }
+
+    /// Parses a type named through a module, as in `geo::Point`. Such names
+    /// are resolved when the modules are linked, so they are not checked
+    /// against the declared types here.
+    fn qualified_type(&mut self, first: String) -> Result<Types, ParserError> {
+        let name = self.qualified_name(first)?;
+        if self.peek() == Some(&Token::Less) {
+            Ok(Types::Generic(name, self.type_arguments()?))
+        } else {
+            Ok(Types::Struct(name))
+        }
+    }
+
+    /// Reads any `::segment`s following a name whose first segment has been
+    /// consumed, joining them into a single `a::b::c` name.
+    pub fn qualified_name(&mut self, first: String) -> Result<String, ParserError> {
+        let mut name = first;
+        while self.match_token(&Token::ColonColon) {
+            let Some(Token::Identifier(segment)) = self.peek().cloned() else {
+                return Err(ParserError::ExpectedToken(
+                    "expected identifier after `::`".into(),
+                ));
+            };
+            self.advance();
+            name = format!("{}::{}", name, segment);
+        }
+
+        Ok(name)
+    }
+
+    /// Parses the `(i32, bool) -> i32` after `fn` in a function type. Without
+    /// an arrow the function returns `void`.
+    fn function_type(&mut self) -> Result<Types, ParserError> {
+        if !self.match_token(&Token::LeftParen) {
+            return Err(ParserError::ExpectedToken(
+                "expected `(` after `fn` in function type".into(),
+            ));
+        }
+
+        let mut parameters = Vec::new();
+        if !self.match_token(&Token::RightParen) {
+            loop {
+                parameters.push(self.parse_type()?);
+
+                if self.match_token(&Token::RightParen) {
+                    break;
+                }
+                if !self.match_token(&Token::Comma) {
+                    return Err(ParserError::ExpectedToken(
+                        "expected `,` or `)` after parameter type".into(),
+                    ));
+                }
+            }
+        }
+
+        let return_type = if self.match_token(&Token::ArrowSmall) {
+            self.parse_type()?
+        } else {
+            Types::Void
+        };
+
+        Ok(Types::Function(parameters, Box::new(return_type)))
+    }
+
+    /// Parses the type arguments of `Option<T>`, `Result<T, E>` or `Box<T>`.
+    fn builtin_type(&mut self, name: String) -> Result<Types, ParserError> {
+        let mut arguments = self.type_arguments()?.into_iter();
+        match (
+            name.as_str(),
+            arguments.next(),
+            arguments.next(),
+            arguments.next(),
+        ) {
+            ("Option", Some(value), None, None) => Ok(Types::Option(Box::new(value))),
+            ("Box", Some(value), None, None) => Ok(Types::Box(Box::new(value))),
+            ("Result", Some(value), Some(error), None) => {
+                Ok(Types::Result(Box::new(value), Box::new(error)))
+            }
+            _ => Err(ParserError::ExpectedToken(format!(
+                "wrong number of type arguments for `{}`",
+                name
+            ))),
+        }
+    }
+
+    /// Parses a tuple type after its opening `(`. A single type in parentheses
+    /// is just that type, unless it is followed by a comma as in `(i32,)`.
+    fn tuple_type(&mut self) -> Result<Types, ParserError> {
+        let mut elements = vec![self.parse_type()?];
+        if self.match_token(&Token::RightParen) {
+            return Ok(elements.remove(0));
+        }
+
+        while self.match_token(&Token::Comma) {
+            if self.match_token(&Token::RightParen) {
+                return Ok(Types::Tuple(elements));
+            }
+            elements.push(self.parse_type()?);
+        }
+
+        if !self.match_token(&Token::RightParen) {
+            return Err(ParserError::ExpectedToken(
+                "expected `,` or `)` in tuple type".into(),
+            ));
+        }
+
+        Ok(Types::Tuple(elements))
+    }
+
+    /// Parses the `<T, U>` after a generic item's name, then the rest of the
+    /// item with those parameters in scope.
+    pub fn generic_item(
+        &mut self,
+        parse_item: impl FnOnce(&mut Self) -> Result<Expr, ParserError>,
+    ) -> Result<Expr, ParserError> {
+        let type_parameters = self.type_parameters()?;
+        let names = type_parameters
+            .iter()
+            .map(|param| param.identifier.clone())
+            .collect();
+
+        let outer = std::mem::replace(&mut self.type_parameters, names);
+        let item = parse_item(self);
+        self.type_parameters = outer;
+
+        Ok(Expr::Generic {
+            type_parameters,
+            item: Box::new(item?),
+        })
+    }
+
+    fn type_parameters(&mut self) -> Result<Vec<TypeParameter>, ParserError> {
+        if !self.match_token(&Token::Less) {
+            return Err(ParserError::ExpectedToken("expected `<`".into()));
+        }
+
+        let mut parameters = Vec::new();
+        loop {
+            let Some(Token::Identifier(identifier)) = self.peek().cloned() else {
+                return Err(ParserError::ExpectedToken(
+                    "expected type parameter name".into(),
+                ));
+            };
+            self.advance();
+
+            let mut bounds = Vec::new();
+            if self.match_token(&Token::Colon) {
+                loop {
+                    let Some(Token::Identifier(bound)) = self.peek().cloned() else {
+                        return Err(ParserError::ExpectedToken(
+                            "expected trait name in bound".into(),
+                        ));
+                    };
+                    self.advance();
+                    bounds.push(self.qualified_name(bound)?);
+
+                    if !self.match_token(&Token::Plus) {
+                        break;
+                    }
+                }
+            }
+            parameters.push(TypeParameter { identifier, bounds });
+
+            if self.match_token(&Token::Greater) {
+                return Ok(parameters);
+            }
+            if !self.match_token(&Token::Comma) {
+                return Err(ParserError::ExpectedToken(
+                    "expected `,` or `>` after type parameter".into(),
+                ));
+            }
+        }
+    }
+
+    /// Parses `<i32, bool>` after the name of a generic type.
+    pub fn type_arguments(&mut self) -> Result<Vec<Types>, ParserError> {
+        if !self.match_token(&Token::Less) {
+            return Err(ParserError::ExpectedToken("expected `<`".into()));
+        }
+
+        let mut arguments = Vec::new();
+        loop {
+            arguments.push(self.parse_type()?);
+
+            if self.match_closing_angle() {
+                return Ok(arguments);
+            }
+            if !self.match_token(&Token::Comma) {
+                return Err(ParserError::ExpectedToken(
+                    "expected `,` or `>` after type argument".into(),
+                ));
+            }
+        }
+    }
+
+    /// Consumes a `>`, splitting a `>>` token so nested argument lists such as
+    /// `Pair<Pair<i32, i32>>` close correctly.
+    fn match_closing_angle(&mut self) -> bool {
+        match self.peek() {
+            Some(Token::Greater) => {
+                self.advance();
+                true
+            }
+            Some(Token::GreaterGreater) => {
+                self.tokens[self.current_index] = Token::Greater;
+                true
+            }
+            _ => false,
+        }
+    }
 }
@@ -1,12 +1,15 @@
 use crate::parser::{
     Parser,
-    core::{expr::Expr, nodes::Nodes, token::Token},
+    core::{expr::Expr, token::Token},
     errors::ParserError,
 };
 
 impl Parser {
     pub fn assignment(&mut self) -> Result<Expr, ParserError> {
         if self.match_token(&Token::KeywordLet) {
+            if self.match_token(&Token::LeftParen) {
+                return self.tuple_declaration();
+            }
             if let Some(Token::Identifier(name)) = self.peek().cloned() {
                 self.advance(); // consume identifier
 
@@ -39,18 +42,115 @@ impl Parser {
         let expr = self.or()?;
 
         if self.match_token(&Token::Equal) {
-            if let Expr::Literal(Nodes::Identifier(name)) = expr {
+            if expr.is_place() {
                 let value = self.assignment()?;
                 return Ok(Expr::Assignment {
-                    identifier: name,
+                    target: Box::new(expr),
                     value: Box::new(value),
                 });
             }
             return Err(ParserError::InvalidAssignment(
-                "assignment target must be an identifier".into(),
+                "assignment target must be a variable, dereference or field".into(),
             ));
         }
 
+        if let Some(operator) = self.match_compound_assign_op() {
+            if !expr.is_place() {
+                return Err(ParserError::InvalidAssignment(
+                    "compound assignment target must be a variable, dereference or field".into(),
+                ));
+            }
+            let value = self.assignment()?;
+            return Ok(Expr::CompoundAssignment {
+                target: Box::new(expr),
+                operator: Box::new(operator),
+                value: Box::new(value),
+            });
+        }
+
         Ok(expr)
     }
+
+    /// Parses the rest of `let (a, b) = value` after the opening `(`.
+    fn tuple_declaration(&mut self) -> Result<Expr, ParserError> {
+        let mut identifiers = Vec::new();
+        loop {
+            let Some(Token::Identifier(name)) = self.peek().cloned() else {
+                return Err(ParserError::MalformedVarDecl(
+                    "expected identifier in tuple pattern".into(),
+                ));
+            };
+            self.advance();
+            identifiers.push(name);
+
+            if self.match_token(&Token::RightParen) {
+                break;
+            }
+            if !self.match_token(&Token::Comma) {
+                return Err(ParserError::MalformedVarDecl(
+                    "expected `,` or `)` in tuple pattern".into(),
+                ));
+            }
+            if self.match_token(&Token::RightParen) {
+                break;
+            }
+        }
+
+        let var_type = if self.match_token(&Token::Colon) {
+            Some(self.parse_type()?)
+        } else {
+            None
+        };
+
+        if !self.match_token(&Token::Equal) {
+            return Err(ParserError::MalformedVarDecl(
+                "expected `=` after tuple pattern".into(),
+            ));
+        }
+
+        let value = self.assignment()?;
+
+        Ok(Expr::TupleDeclaration {
+            identifiers,
+            var_type,
+            value: Box::new(value),
+        })
+    }
+
+    /// `static [mut] NAME: T = value;`. The type is required, as the value
+    /// is not checked in the context of any function.
+    pub fn static_declaration(&mut self) -> Result<Expr, ParserError> {
+        self.advance(); // consume `static`
+        let mutable = self.match_token(&Token::KeywordMut);
+
+        let Some(Token::Identifier(identifier)) = self.peek().cloned() else {
+            return Err(ParserError::MalformedStatic(
+                "expected identifier after `static`".into(),
+            ));
+        };
+        self.advance();
+
+        if !self.match_token(&Token::Colon) {
+            return Err(ParserError::MalformedStatic(format!(
+                "expected `:` and a type after `{}`",
+                identifier
+            )));
+        }
+        let var_type = self.parse_type()?;
+
+        if !self.match_token(&Token::Equal) {
+            return Err(ParserError::MalformedStatic(format!(
+                "expected `=` after the type of `{}`",
+                identifier
+            )));
+        }
+        let value = self.expression()?;
+
+        Ok(Expr::StaticDeclaration {
+            identifier,
+            var_type,
+            value: Box::new(value),
+            mutable,
+        })
+    }
 }
@@ -1,6 +1,11 @@
+use std::collections::HashSet;
+
 use logos::Logos;
 
-use crate::parser::{core::token::Token, errors::ParserError};
+use crate::parser::{
+    core::{token::Token, types::Types},
+    errors::{LexError, ParserError},
+};
 
 pub mod core;
 pub mod errors;
@@ -11,6 +16,10 @@ pub struct Parser {
     tokens: Vec<Token>,
     current_index: usize,
     source: String,
+    type_names: HashSet<String>,
+    alias_names: HashSet<String>,
+    type_parameters: Vec<String>,
+    self_type: Option<Types>,
 }
 
 impl Parser {
@@ -21,13 +30,10 @@ impl Parser {
         while let Some(token) = lexer.next() {
             match token {
                 Ok(t) => tokens.push(t),
+                Err(err) if err != LexError::UnknownInput => return Err(err.into()),
                 Err(_) => {
                     let slice = lexer.slice();
-                    if let Ok(num) = slice.parse::<i64>() {
-                        tokens.push(Token::Integer(num));
-                    } else if let Ok(num) = slice.parse::<f64>() {
-                        tokens.push(Token::Float(num));
-                    } else if slice.starts_with('"') && slice.ends_with('"') {
+                    if slice.starts_with('"') && slice.ends_with('"') {
                         let string_content = slice[1..slice.len() - 1].into();
                         tokens.push(Token::String(string_content));
                     } else if slice == "true" || slice == "false" {
@@ -43,22 +49,69 @@ impl Parser {
             }
         }
 
+        let type_names = collect_type_names(&tokens);
+        let alias_names = collect_alias_names(&tokens);
+
         Ok(Parser {
             tokens,
             current_index: 0,
             source: input,
+            type_names,
+            alias_names,
+            type_parameters: Vec::new(),
+            self_type: None,
         })
     }
 }
 
+/// Finds the names of all user-defined types up front, so they can be used
+/// before their declaration and distinguished from variables while parsing.
+/// The last segment of each `import` counts too, as it may name a struct
+/// declared in another file.
+fn collect_type_names(tokens: &[Token]) -> HashSet<String> {
+    let declared = tokens.windows(2).filter_map(|pair| match pair {
+        [Token::KeywordStruct, Token::Identifier(name)] => Some(name.clone()),
+        _ => None,
+    });
+
+    let imported = tokens
+        .iter()
+        .enumerate()
+        .filter(|(_, token)| **token == Token::KeywordImport)
+        .filter_map(|(start, _)| {
+            tokens[start + 1..]
+                .iter()
+                .take_while(|token| **token != Token::Semicolon)
+                .last()
+        })
+        .filter_map(|token| match token {
+            Token::Identifier(name) => Some(name.clone()),
+            _ => None,
+        });
+
+    declared.chain(imported).collect()
+}
+
+/// Finds the names declared by `type Name = ...;`, so they can be used
+/// before their declaration.
+fn collect_alias_names(tokens: &[Token]) -> HashSet<String> {
+    tokens
+        .windows(2)
+        .filter_map(|pair| match pair {
+            [Token::KeywordType, Token::Identifier(name)] => Some(name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
 impl Parser {
     /// Peeks into the next token, if the token matches it is consumed.
     fn match_token(&mut self, expected: &Token) -> bool {
-        if let Some(t) = self.peek() {
-            if std::mem::discriminant(t) == std::mem::discriminant(expected) {
-                self.advance();
-                return true;
-            }
+        if let Some(t) = self.peek()
+            && std::mem::discriminant(t) == std::mem::discriminant(expected)
+        {
+            self.advance();
+            return true;
         }
         false
     }
@@ -74,6 +127,10 @@ impl Parser {
         self.previous()
     }
 
+    fn peek_nth(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.current_index + offset)
+    }
+
     fn is_at_end(&self) -> bool {
         self.current_index >= self.tokens.len()
     }
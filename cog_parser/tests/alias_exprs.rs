@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod alias_exprs_test {
+    use cog_parser::parser::{
+        Parser,
+        core::{expr::Expr, types::Types},
+        errors::ParserError,
+    };
+
+    fn parse(input: &str) -> Result<Vec<Expr>, ParserError> {
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        parser.parse()
+    }
+
+    #[test]
+    fn alias_decl() {
+        let expected = vec![Expr::TypeAlias {
+            identifier: "Handle".into(),
+            aliased: Types::Pointer(Box::new(Types::Pointer(Box::new(Types::I64)))),
+        }];
+        assert_eq!(parse("type Handle = **i64;"), Ok(expected));
+    }
+
+    #[test]
+    fn alias_used_before_decl() {
+        let input = "let h: *Handle = p; type Handle = *i64;";
+        let expected = vec![
+            Expr::Declaration {
+                identifier: "h".into(),
+                var_type: Some(Types::Pointer(Box::new(Types::Alias("Handle".into())))),
+                value: Box::new(Expr::new_identifier("p")),
+            },
+            Expr::TypeAlias {
+                identifier: "Handle".into(),
+                aliased: Types::Pointer(Box::new(Types::I64)),
+            },
+        ];
+        assert_eq!(parse(input), Ok(expected));
+    }
+
+    #[test]
+    fn pub_alias() {
+        let expected = vec![Expr::Public {
+            item: Box::new(Expr::TypeAlias {
+                identifier: "Id".into(),
+                aliased: Types::U64,
+            }),
+        }];
+        assert_eq!(parse("pub type Id = u64;"), Ok(expected));
+    }
+
+    #[test]
+    fn alias_needs_equal() {
+        assert!(matches!(
+            parse("type Handle *i64;"),
+            Err(ParserError::MalformedTypeAlias(_))
+        ));
+    }
+
+    #[test]
+    fn alias_needs_semicolon() {
+        assert!(matches!(
+            parse("type Handle = *i64"),
+            Err(ParserError::MalformedTypeAlias(_))
+        ));
+    }
+}
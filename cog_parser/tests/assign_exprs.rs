@@ -2,7 +2,7 @@
 mod assign_exprs {
     use cog_parser::parser::{
         Parser,
-        core::{expr::Expr, types::Types},
+        core::{expr::Expr, ops::BinaryOp, types::Types},
     };
 
     #[test]
@@ -68,4 +68,60 @@ mod assign_exprs {
         let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
         assert!(parser.parse().is_err());
     }
+
+    #[test]
+    fn compound_add_assign() {
+        let input = "x += 1";
+        let expected = vec![Expr::CompoundAssignment {
+            target: Box::new(Expr::new_identifier("x")),
+            operator: Box::new(BinaryOp::Add),
+            value: Box::new(Expr::new_int_literal(1)),
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn compound_assign_ops() {
+        let cases = [
+            ("x -= 2", BinaryOp::Subtract),
+            ("x *= 2", BinaryOp::Multiply),
+            ("x /= 2", BinaryOp::Divide),
+            ("x %= 2", BinaryOp::Modulo),
+        ];
+
+        for (input, operator) in cases {
+            let expected = vec![Expr::CompoundAssignment {
+                target: Box::new(Expr::new_identifier("x")),
+                operator: Box::new(operator),
+                value: Box::new(Expr::new_int_literal(2)),
+            }];
+            let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+            assert_eq!(parser.parse(), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn compound_assign_deref() {
+        let input = "*ptr *= 3 + 1";
+        let expected = vec![Expr::CompoundAssignment {
+            target: Box::new(Expr::Dereference(Box::new(Expr::new_identifier("ptr")))),
+            operator: Box::new(BinaryOp::Multiply),
+            value: Box::new(Expr::Binary {
+                left: Box::new(Expr::new_int_literal(3)),
+                operator: Box::new(BinaryOp::Add),
+                right: Box::new(Expr::new_int_literal(1)),
+            }),
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn malformed_compound_assign_to_literal() {
+        let input = "1 += 2";
+
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert!(parser.parse().is_err());
+    }
 }
@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod bitwise_exprs_tests {
+    use cog_parser::parser::{
+        Parser,
+        core::{
+            expr::Expr,
+            ops::{BinaryOp, UnaryOp},
+        },
+    };
+
+    fn binary(left: Expr, operator: BinaryOp, right: Expr) -> Expr {
+        Expr::Binary {
+            left: Box::new(left),
+            operator: Box::new(operator),
+            right: Box::new(right),
+        }
+    }
+
+    #[test]
+    fn bitwise_ops() {
+        let cases = [
+            ("1 & 2", BinaryOp::BitAnd),
+            ("1 | 2", BinaryOp::BitOr),
+            ("1 ^ 2", BinaryOp::BitXor),
+            ("1 << 2", BinaryOp::ShiftLeft),
+            ("1 >> 2", BinaryOp::ShiftRight),
+        ];
+
+        for (input, operator) in cases {
+            let expected = vec![binary(
+                Expr::new_int_literal(1),
+                operator,
+                Expr::new_int_literal(2),
+            )];
+            let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+            assert_eq!(parser.parse(), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn bit_not_expr() {
+        let input = "~x";
+        let expected = vec![Expr::Unary {
+            operator: Box::new(UnaryOp::BitNot),
+            operand: Box::new(Expr::new_identifier("x")),
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn shift_binds_looser_than_term() {
+        let input = "1 << 2 + 3";
+        let expected = vec![binary(
+            Expr::new_int_literal(1),
+            BinaryOp::ShiftLeft,
+            binary(
+                Expr::new_int_literal(2),
+                BinaryOp::Add,
+                Expr::new_int_literal(3),
+            ),
+        )];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn bitwise_precedence() {
+        let input = "1 | 2 ^ 3 & 4";
+        let expected = vec![binary(
+            Expr::new_int_literal(1),
+            BinaryOp::BitOr,
+            binary(
+                Expr::new_int_literal(2),
+                BinaryOp::BitXor,
+                binary(
+                    Expr::new_int_literal(3),
+                    BinaryOp::BitAnd,
+                    Expr::new_int_literal(4),
+                ),
+            ),
+        )];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn bitwise_binds_tighter_than_comparison() {
+        let input = "x & 1 == 0";
+        let expected = vec![binary(
+            binary(
+                Expr::new_identifier("x"),
+                BinaryOp::BitAnd,
+                Expr::new_int_literal(1),
+            ),
+            BinaryOp::Equal,
+            Expr::new_int_literal(0),
+        )];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn leading_ampersand_is_address_of() {
+        let input = "&x";
+        let expected = vec![Expr::AddressOf(Box::new(Expr::new_identifier("x")))];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn compound_bitwise_assign_ops() {
+        let cases = [
+            ("x &= 2", BinaryOp::BitAnd),
+            ("x |= 2", BinaryOp::BitOr),
+            ("x ^= 2", BinaryOp::BitXor),
+            ("x <<= 2", BinaryOp::ShiftLeft),
+            ("x >>= 2", BinaryOp::ShiftRight),
+        ];
+
+        for (input, operator) in cases {
+            let expected = vec![Expr::CompoundAssignment {
+                target: Box::new(Expr::new_identifier("x")),
+                operator: Box::new(operator),
+                value: Box::new(Expr::new_int_literal(2)),
+            }];
+            let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+            assert_eq!(parser.parse(), Ok(expected));
+        }
+    }
+}
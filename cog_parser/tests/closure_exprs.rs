@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod closure_exprs_test {
+    use cog_parser::parser::{
+        Parser,
+        core::{expr::Expr, nodes::Nodes, ops::BinaryOp, types::Types},
+        errors::ParserError,
+    };
+
+    fn param(identifier: &str, var_type: Option<Types>) -> Expr {
+        Expr::Declaration {
+            identifier: identifier.into(),
+            var_type,
+            value: Box::new(Expr::new_identifier("placeholder")),
+        }
+    }
+
+    #[test]
+    fn function_type_param() {
+        let input = "fn apply(f: fn(i32, bool) -> i32, done: fn()) {}";
+        let expected = vec![Expr::FunctionDeclaration {
+            identifier: "apply".into(),
+            parameters: vec![
+                param(
+                    "f",
+                    Some(Types::Function(
+                        vec![Types::I32, Types::Bool],
+                        Box::new(Types::I32),
+                    )),
+                ),
+                param("done", Some(Types::Function(vec![], Box::new(Types::Void)))),
+            ],
+            body: Box::new(Expr::Block(vec![])),
+            return_type: None,
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn closure_with_params() {
+        let input = "let add = |x, y: i32| x + y;";
+        let expected = vec![Expr::Declaration {
+            identifier: "add".into(),
+            var_type: None,
+            value: Box::new(Expr::Closure {
+                parameters: vec![param("x", None), param("y", Some(Types::I32))],
+                return_type: None,
+                body: Box::new(Expr::Binary {
+                    left: Box::new(Expr::new_identifier("x")),
+                    operator: Box::new(BinaryOp::Add),
+                    right: Box::new(Expr::new_identifier("y")),
+                }),
+                captures: vec![],
+            }),
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn closure_without_params() {
+        let input = "run(|| 1);";
+        let expected = vec![Expr::Call {
+            callee: Box::new(Expr::new_identifier("run")),
+            arguments: vec![Expr::Closure {
+                parameters: vec![],
+                return_type: None,
+                body: Box::new(Expr::Literal(Nodes::Integer(1))),
+                captures: vec![],
+            }],
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn closure_with_return_type() {
+        let input = "let f = |x: i32| -> i32 { return x; };";
+        let expected = vec![Expr::Declaration {
+            identifier: "f".into(),
+            var_type: None,
+            value: Box::new(Expr::Closure {
+                parameters: vec![param("x", Some(Types::I32))],
+                return_type: Some(Types::I32),
+                body: Box::new(Expr::Block(vec![Expr::Return {
+                    value: Some(Box::new(Expr::new_identifier("x"))),
+                }])),
+                captures: vec![],
+            }),
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn return_type_without_block() {
+        let input = "let f = |x: i32| -> i32 x;";
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(
+            parser.parse(),
+            Err(ParserError::MalformedClosure(
+                "expected `{` after closure return type".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn call_through_value() {
+        let input = "make()(2);";
+        let expected = vec![Expr::Call {
+            callee: Box::new(Expr::Call {
+                callee: Box::new(Expr::new_identifier("make")),
+                arguments: vec![],
+            }),
+            arguments: vec![Expr::new_int_literal(2)],
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+}
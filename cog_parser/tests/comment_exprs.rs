@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod comment_exprs_test {
+    use cog_parser::parser::{
+        Parser,
+        core::{
+            expr::{Expr, StructField, TraitMethod},
+            types::Types,
+        },
+        errors::ParserError,
+    };
+
+    #[test]
+    fn comments_are_skipped() {
+        let input = "// line comment\n\
+                     let x = /* block /* nested */ comment */ 1; //// not a doc\n\
+                     /**/ x;";
+        let expected = vec![
+            Expr::Declaration {
+                identifier: "x".into(),
+                var_type: None,
+                value: Box::new(Expr::new_int_literal(1)),
+            },
+            Expr::new_identifier("x"),
+        ];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn doc_comment_on_function() {
+        let input = "/// Does nothing.\n///\n///   Really.\nfn f() {}";
+        let expected = vec![Expr::Documented {
+            doc: "Does nothing.\n\n  Really.".into(),
+            item: Box::new(Expr::FunctionDeclaration {
+                identifier: "f".into(),
+                parameters: vec![],
+                body: Box::new(Expr::Block(vec![])),
+                return_type: None,
+            }),
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn doc_comments_on_members() {
+        let input = "struct P {\n/// The x.\nx: i32,\ny: i32 }\n\
+                     trait T {\n/// Gets it.\nfn get(self: *Self) -> i32; }";
+        let expected = vec![
+            Expr::StructDeclaration {
+                identifier: "P".into(),
+                fields: vec![
+                    StructField {
+                        identifier: "x".into(),
+                        field_type: Types::I32,
+                        doc: Some("The x.".into()),
+                        public: false,
+                    },
+                    StructField {
+                        identifier: "y".into(),
+                        field_type: Types::I32,
+                        doc: None,
+                        public: false,
+                    },
+                ],
+            },
+            Expr::TraitDeclaration {
+                identifier: "T".into(),
+                methods: vec![TraitMethod {
+                    identifier: "get".into(),
+                    parameters: vec![Expr::Declaration {
+                        identifier: "self".into(),
+                        var_type: Some(Types::Pointer(Box::new(Types::Param("Self".into())))),
+                        value: Box::new(Expr::new_identifier("placeholder")),
+                    }],
+                    return_type: Some(Types::I32),
+                    body: None,
+                    doc: Some("Gets it.".into()),
+                }],
+            },
+        ];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn doc_comment_on_statement() {
+        let input = "/// Not an item.\nlet x = 1;";
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(
+            parser.parse(),
+            Err(ParserError::MisplacedDocComment("Not an item.".into()))
+        );
+    }
+
+    #[test]
+    fn unterminated_block_comment() {
+        let input = "let x = 1; /* /* */";
+        assert_eq!(
+            Parser::new(input.to_string()),
+            Err(ParserError::UnterminatedComment)
+        );
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod defer_exprs_test {
+    use cog_parser::parser::{
+        Parser,
+        core::{expr::Expr, nodes::Nodes},
+        errors::ParserError,
+    };
+
+    fn parse(input: &str) -> Result<Vec<Expr>, ParserError> {
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        parser.parse()
+    }
+
+    #[test]
+    fn defer_call() {
+        let expected = vec![Expr::Defer(Box::new(Expr::Call {
+            callee: Box::new(Expr::new_identifier("close")),
+            arguments: vec![Expr::new_identifier("fd")],
+        }))];
+        assert_eq!(parse("defer close(fd);"), Ok(expected));
+    }
+
+    #[test]
+    fn defer_block() {
+        let expected = vec![Expr::Defer(Box::new(Expr::Block(vec![Expr::Free(
+            Box::new(Expr::new_identifier("p")),
+        )])))];
+        assert_eq!(parse("defer { free(p); };"), Ok(expected));
+    }
+
+    #[test]
+    fn defer_in_function_body() {
+        let input = "fn f() { defer g(); }";
+        let expected = vec![Expr::FunctionDeclaration {
+            identifier: "f".into(),
+            parameters: vec![],
+            body: Box::new(Expr::Block(vec![Expr::Defer(Box::new(Expr::Call {
+                callee: Box::new(Expr::new_identifier("g")),
+                arguments: vec![],
+            }))])),
+            return_type: None,
+        }];
+        assert_eq!(parse(input), Ok(expected));
+    }
+
+    #[test]
+    fn defer_assignment() {
+        let expected = vec![Expr::Defer(Box::new(Expr::Assignment {
+            target: Box::new(Expr::new_identifier("x")),
+            value: Box::new(Expr::Literal(Nodes::Integer(0))),
+        }))];
+        assert_eq!(parse("defer x = 0;"), Ok(expected));
+    }
+
+    #[test]
+    fn defer_item_is_error() {
+        assert!(matches!(
+            parse("defer fn f() {}"),
+            Err(ParserError::MalformedDefer(_))
+        ));
+    }
+}
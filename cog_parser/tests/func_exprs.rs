@@ -27,7 +27,7 @@ mod func_exprs_test {
             identifier: "main".into(),
             parameters: vec![],
             body: Box::new(Expr::Block(vec![Expr::Return {
-                value: Box::new(Expr::new_int_literal(0)),
+                value: Some(Box::new(Expr::new_int_literal(0))),
             }])),
             return_type: Some(Types::I32),
         }];
@@ -35,6 +35,21 @@ mod func_exprs_test {
         assert_eq!(parser.parse(), Ok(expected));
     }
 
+    #[test]
+    fn bare_return() {
+        let input = "fn stop() {
+            return;
+        }";
+        let expected = vec![Expr::FunctionDeclaration {
+            identifier: "stop".into(),
+            parameters: vec![],
+            body: Box::new(Expr::Block(vec![Expr::Return { value: None }])),
+            return_type: None,
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
     #[test]
     fn add_decl() {
         let input = "fn add(a: i32, b: i32) -> i32 {
@@ -55,15 +70,102 @@ mod func_exprs_test {
                 },
             ],
             body: Box::new(Expr::Block(vec![Expr::Return {
-                value: Box::new(Expr::Binary {
+                value: Some(Box::new(Expr::Binary {
                     left: Box::new(Expr::new_identifier("a")),
                     operator: Box::new(BinaryOp::Add),
                     right: Box::new(Expr::new_identifier("b")),
-                }),
+                })),
             }])),
             return_type: Some(Types::I32),
         }];
         let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
         assert_eq!(parser.parse(), Ok(expected));
     }
+
+    #[test]
+    fn extern_variadic_decl() {
+        let input = "extern fn printf(fmt: *i8, ...) -> i32;";
+        let expected = vec![Expr::ExternFunctionDeclaration {
+            identifier: "printf".into(),
+            parameters: vec![Expr::Declaration {
+                identifier: "fmt".into(),
+                var_type: Some(Types::Pointer(Box::new(Types::I8))),
+                value: Box::new(Expr::new_identifier("placeholder")),
+            }],
+            return_type: Some(Types::I32),
+            is_variadic: true,
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn extern_void_pointer_decl() {
+        let input = "extern fn free(ptr: *void);";
+        let expected = vec![Expr::ExternFunctionDeclaration {
+            identifier: "free".into(),
+            parameters: vec![Expr::Declaration {
+                identifier: "ptr".into(),
+                var_type: Some(Types::Pointer(Box::new(Types::Void))),
+                value: Box::new(Expr::new_identifier("placeholder")),
+            }],
+            return_type: None,
+            is_variadic: false,
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn malformed_extern_with_body() {
+        let input = "extern fn puts(s: *i8) -> i32 { return 0; }";
+
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert!(parser.parse().is_err());
+    }
+
+    #[test]
+    fn malformed_variadic_non_extern() {
+        let input = "fn sum(count: i32, ...) -> i32 { return 0; }";
+
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert!(parser.parse().is_err());
+    }
+
+    #[test]
+    fn malformed_ellipsis_not_last() {
+        let input = "extern fn printf(..., fmt: *i8) -> i32;";
+
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert!(parser.parse().is_err());
+    }
+
+    #[test]
+    fn call_expr() {
+        let input = "printf(\"%d\", 1 + 2)";
+        let expected = vec![Expr::Call {
+            callee: Box::new(Expr::new_identifier("printf")),
+            arguments: vec![
+                Expr::new_string_literal("%d".into()),
+                Expr::Binary {
+                    left: Box::new(Expr::new_int_literal(1)),
+                    operator: Box::new(BinaryOp::Add),
+                    right: Box::new(Expr::new_int_literal(2)),
+                },
+            ],
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn call_no_args_expr() {
+        let input = "getchar()";
+        let expected = vec![Expr::Call {
+            callee: Box::new(Expr::new_identifier("getchar")),
+            arguments: vec![],
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
 }
@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod generic_exprs_test {
+    use cog_parser::parser::{
+        Parser,
+        core::{
+            expr::{Expr, StructField, TypeParameter},
+            types::Types,
+        },
+        errors::ParserError,
+    };
+
+    #[test]
+    fn generic_func_decl() {
+        let input = "fn id<T>(x: T) -> T { return x; }";
+        let expected = vec![Expr::Generic {
+            type_parameters: vec![TypeParameter {
+                identifier: "T".into(),
+                bounds: vec![],
+            }],
+            item: Box::new(Expr::FunctionDeclaration {
+                identifier: "id".into(),
+                parameters: vec![Expr::Declaration {
+                    identifier: "x".into(),
+                    var_type: Some(Types::Param("T".into())),
+                    value: Box::new(Expr::new_identifier("placeholder")),
+                }],
+                body: Box::new(Expr::Block(vec![Expr::Return {
+                    value: Some(Box::new(Expr::new_identifier("x"))),
+                }])),
+                return_type: Some(Types::Param("T".into())),
+            }),
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn generic_struct_decl() {
+        let input = "struct Pair<A, B> { a: A, b: *B }";
+        let expected = vec![Expr::Generic {
+            type_parameters: vec![
+                TypeParameter {
+                    identifier: "A".into(),
+                    bounds: vec![],
+                },
+                TypeParameter {
+                    identifier: "B".into(),
+                    bounds: vec![],
+                },
+            ],
+            item: Box::new(Expr::StructDeclaration {
+                identifier: "Pair".into(),
+                fields: vec![
+                    StructField {
+                        identifier: "a".into(),
+                        field_type: Types::Param("A".into()),
+                        doc: None,
+                        public: false,
+                    },
+                    StructField {
+                        identifier: "b".into(),
+                        field_type: Types::Pointer(Box::new(Types::Param("B".into()))),
+                        doc: None,
+                        public: false,
+                    },
+                ],
+            }),
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn type_arguments() {
+        let input = "struct Pair<A, B> { a: A, b: B }
+        let p: Pair<i32, bool> = Pair { a: 1, b: true };";
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        let statements = parser.parse().expect("Failed to parse");
+        assert_eq!(
+            statements[1],
+            Expr::Declaration {
+                identifier: "p".into(),
+                var_type: Some(Types::Generic("Pair".into(), vec![Types::I32, Types::Bool])),
+                value: Box::new(Expr::StructLiteral {
+                    identifier: "Pair".into(),
+                    fields: vec![
+                        ("a".into(), Expr::new_int_literal(1)),
+                        ("b".into(), Expr::new_boolean_literal(true)),
+                    ],
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn nested_type_arguments() {
+        let input = "struct Cell<T> { value: T }
+        let b: Cell<Cell<i32>> = x;";
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        let statements = parser.parse().expect("Failed to parse");
+        assert_eq!(
+            statements[1],
+            Expr::Declaration {
+                identifier: "b".into(),
+                var_type: Some(Types::Generic(
+                    "Cell".into(),
+                    vec![Types::Generic("Cell".into(), vec![Types::I32])]
+                )),
+                value: Box::new(Expr::new_identifier("x")),
+            }
+        );
+    }
+
+    #[test]
+    fn type_parameters_are_scoped() {
+        let input = "fn id<T>(x: T) -> T { return x; }
+        fn other(x: T) {}";
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Err(ParserError::UnknownType("T".into())));
+    }
+
+    #[test]
+    fn generic_method_rejected() {
+        let input = "struct S { x: i32 }
+        impl S { fn get<T>(self: S) {} }";
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert!(matches!(parser.parse(), Err(ParserError::MalformedImpl(_))));
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod heap_exprs_test {
+    use cog_parser::parser::{
+        Parser,
+        core::{expr::Expr, nodes::Nodes, types::Types},
+        errors::ParserError,
+    };
+
+    fn parse(input: &str) -> Result<Vec<Expr>, ParserError> {
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        parser.parse()
+    }
+
+    #[test]
+    fn alloc_and_free() {
+        let input = "let p = alloc<i64>(); free(p);";
+        let expected = vec![
+            Expr::Declaration {
+                identifier: "p".into(),
+                var_type: None,
+                value: Box::new(Expr::Alloc(Types::I64)),
+            },
+            Expr::Free(Box::new(Expr::new_identifier("p"))),
+        ];
+        assert_eq!(parse(input), Ok(expected));
+    }
+
+    #[test]
+    fn box_new() {
+        let input = "let b: Box<i32> = Box::new(1);";
+        let expected = vec![Expr::Declaration {
+            identifier: "b".into(),
+            var_type: Some(Types::Box(Box::new(Types::I32))),
+            value: Box::new(Expr::BoxNew(Box::new(Expr::Literal(Nodes::Integer(1))))),
+        }];
+        assert_eq!(parse(input), Ok(expected));
+    }
+
+    #[test]
+    fn nested_box_type() {
+        let input = "let b: Box<Box<i32>> = x;";
+        let expected = vec![Expr::Declaration {
+            identifier: "b".into(),
+            var_type: Some(Types::Box(Box::new(Types::Box(Box::new(Types::I32))))),
+            value: Box::new(Expr::new_identifier("x")),
+        }];
+        assert_eq!(parse(input), Ok(expected));
+    }
+
+    #[test]
+    fn alloc_needs_one_type() {
+        assert!(matches!(
+            parse("alloc<i32, i64>();"),
+            Err(ParserError::MalformedHeapOp(_))
+        ));
+    }
+
+    #[test]
+    fn free_takes_one_argument() {
+        assert!(matches!(
+            parse("free(a, b);"),
+            Err(ParserError::MalformedHeapOp(_))
+        ));
+    }
+}
@@ -47,4 +47,70 @@ mod if_exprs_tests {
 
         assert_eq!(parser.parse(), Ok(expected));
     }
+
+    #[test]
+    fn else_if_exprs() {
+        let input = "if a { 1 } else if b { 2 } else { 3 }";
+        let expected = vec![Expr::IfElse {
+            condition: Box::new(Expr::new_identifier("a")),
+            then_branch: Box::new(Expr::Block(vec![Expr::new_int_literal(1)])),
+            else_branch: Some(Box::new(Expr::IfElse {
+                condition: Box::new(Expr::new_identifier("b")),
+                then_branch: Box::new(Expr::Block(vec![Expr::new_int_literal(2)])),
+                else_branch: Some(Box::new(Expr::Block(vec![Expr::new_int_literal(3)]))),
+            })),
+        }];
+
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn else_if_without_final_else() {
+        let input = "if a { 1 } else if b { 2 }";
+        let expected = vec![Expr::IfElse {
+            condition: Box::new(Expr::new_identifier("a")),
+            then_branch: Box::new(Expr::Block(vec![Expr::new_int_literal(1)])),
+            else_branch: Some(Box::new(Expr::IfElse {
+                condition: Box::new(Expr::new_identifier("b")),
+                then_branch: Box::new(Expr::Block(vec![Expr::new_int_literal(2)])),
+                else_branch: None,
+            })),
+        }];
+
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn long_else_if_chain() {
+        let input = "if a { 1 } else if b { 2 } else if c { 3 } else if d { 4 } else { 5 }";
+
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        let statements = parser.parse().expect("Failed to parse");
+
+        let mut depth = 0;
+        let mut current = &statements[0];
+        while let Expr::IfElse {
+            else_branch: Some(else_branch),
+            ..
+        } = current
+        {
+            depth += 1;
+            current = else_branch;
+        }
+
+        assert_eq!(depth, 4);
+        assert_eq!(current, &Expr::Block(vec![Expr::new_int_literal(5)]));
+    }
+
+    #[test]
+    fn malformed_else_missing_block() {
+        let input = "if a { 1 } else 2";
+
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert!(parser.parse().is_err());
+    }
 }
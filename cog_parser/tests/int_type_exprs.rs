@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod int_type_exprs_test {
+    use cog_parser::parser::{
+        Parser,
+        core::{expr::Expr, ops::UnaryOp, types::Types},
+    };
+
+    fn declaration(identifier: &str, var_type: Types, value: Expr) -> Expr {
+        Expr::Declaration {
+            identifier: identifier.into(),
+            var_type: Some(var_type),
+            value: Box::new(value),
+        }
+    }
+
+    #[test]
+    fn integer_widths() {
+        let input = "let a: i16 = 1; let b: isize = 1; let c: u8 = 1; let d: u16 = 1; \
+                     let e: u32 = 1; let f: u64 = 1; let g: usize = 1;";
+        let expected = vec![
+            declaration("a", Types::I16, Expr::new_int_literal(1)),
+            declaration("b", Types::Isize, Expr::new_int_literal(1)),
+            declaration("c", Types::U8, Expr::new_int_literal(1)),
+            declaration("d", Types::U16, Expr::new_int_literal(1)),
+            declaration("e", Types::U32, Expr::new_int_literal(1)),
+            declaration("f", Types::U64, Expr::new_int_literal(1)),
+            declaration("g", Types::Usize, Expr::new_int_literal(1)),
+        ];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn char_and_pointer_to_unsigned() {
+        let input = "fn f(c: char, bytes: *u8) {}";
+        let expected = vec![Expr::FunctionDeclaration {
+            identifier: "f".into(),
+            parameters: vec![
+                declaration("c", Types::Char, Expr::new_identifier("placeholder")),
+                declaration(
+                    "bytes",
+                    Types::Pointer(Box::new(Types::U8)),
+                    Expr::new_identifier("placeholder"),
+                ),
+            ],
+            body: Box::new(Expr::Block(vec![])),
+            return_type: None,
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn negative_literal_for_narrow_type() {
+        let input = "let x: i16 = -5;";
+        let expected = vec![declaration(
+            "x",
+            Types::I16,
+            Expr::Unary {
+                operator: Box::new(UnaryOp::Minus),
+                operand: Box::new(Expr::new_int_literal(5)),
+            },
+        )];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn signedness() {
+        assert!(Types::Isize.is_signed() && Types::Isize.is_integer());
+        assert!(!Types::Usize.is_signed() && Types::Usize.is_integer());
+        assert!(!Types::Char.is_integer() && !Types::Char.is_numeric());
+    }
+}
@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod literal_exprs_test {
+    use cog_parser::parser::{
+        Parser,
+        core::{expr::Expr, nodes::Nodes},
+        errors::ParserError,
+    };
+
+    fn parse(input: &str) -> Result<Vec<Expr>, ParserError> {
+        Parser::new(input.to_string())?.parse()
+    }
+
+    #[test]
+    fn char_literals() {
+        let expected = vec![
+            Expr::Literal(Nodes::Char('a')),
+            Expr::Literal(Nodes::Char('\'')),
+            Expr::Literal(Nodes::Char('\n')),
+            Expr::Literal(Nodes::Char('é')),
+            Expr::Literal(Nodes::Char('😀')),
+        ];
+        assert_eq!(parse(r"'a'; '\''; '\n'; 'é'; '\u{1F600}';"), Ok(expected));
+    }
+
+    #[test]
+    fn byte_literals() {
+        let expected = vec![
+            Expr::Literal(Nodes::Byte(b'a')),
+            Expr::Literal(Nodes::Byte(0)),
+            Expr::Literal(Nodes::Byte(0xff)),
+        ];
+        assert_eq!(parse(r"b'a'; b'\0'; b'\xff';"), Ok(expected));
+    }
+
+    #[test]
+    fn string_escapes() {
+        let expected = vec![Expr::new_string_literal("tab\t nul\0 A \"q\" \\ é".into())];
+        assert_eq!(
+            parse(r#""tab\t nul\0 \x41 \"q\" \\ \u{e9}";"#),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn invalid_escapes() {
+        assert_eq!(
+            parse(r#""bad \q";"#),
+            Err(ParserError::InvalidEscape(r"\q".into()))
+        );
+        assert_eq!(
+            parse(r#""\x80";"#),
+            Err(ParserError::InvalidEscape(r"\x80".into()))
+        );
+        assert_eq!(
+            parse(r#""\u{D800}";"#),
+            Err(ParserError::InvalidEscape(r"\u{D800}".into()))
+        );
+        assert_eq!(
+            parse(r"b'\u{41}';"),
+            Err(ParserError::InvalidEscape(r"\u".into()))
+        );
+        assert_eq!(parse("b'é';"), Err(ParserError::InvalidEscape("é".into())));
+    }
+
+    #[test]
+    fn unclosed_unicode_escape() {
+        assert_eq!(
+            parse(r#""\u{41";"#),
+            Err(ParserError::InvalidEscape(r"\u{41".into()))
+        );
+        assert_eq!(
+            parse(r#""\u{41 and more";"#),
+            Err(ParserError::InvalidEscape(r"\u{41 and more".into()))
+        );
+    }
+
+    #[test]
+    fn malformed_char_literals() {
+        assert_eq!(
+            parse("'ab';"),
+            Err(ParserError::MalformedCharLiteral("ab".into()))
+        );
+        assert_eq!(
+            parse("'';"),
+            Err(ParserError::MalformedCharLiteral("".into()))
+        );
+    }
+}
@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod module_exprs_test {
+    use cog_parser::parser::{
+        Parser,
+        core::{expr::Expr, types::Types},
+        errors::ParserError,
+    };
+
+    fn parse(input: &str) -> Result<Vec<Expr>, ParserError> {
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        parser.parse()
+    }
+
+    #[test]
+    fn module_decl() {
+        let expected = vec![Expr::ModuleDeclaration {
+            identifier: "util".into(),
+        }];
+        assert_eq!(parse("mod util;"), Ok(expected));
+    }
+
+    #[test]
+    fn import_path() {
+        let expected = vec![Expr::Import {
+            path: vec!["geo".into(), "shapes".into(), "Point".into()],
+        }];
+        assert_eq!(parse("import geo::shapes::Point;"), Ok(expected));
+    }
+
+    #[test]
+    fn import_needs_module() {
+        assert!(matches!(
+            parse("import util;"),
+            Err(ParserError::MalformedImport(_))
+        ));
+    }
+
+    #[test]
+    fn module_decl_needs_semicolon() {
+        assert!(matches!(
+            parse("mod util"),
+            Err(ParserError::MalformedModuleDecl(_))
+        ));
+    }
+
+    #[test]
+    fn qualified_call() {
+        let expected = vec![Expr::Call {
+            callee: Box::new(Expr::Path(vec!["util".into(), "helper".into()])),
+            arguments: vec![Expr::new_int_literal(1)],
+        }];
+        assert_eq!(parse("util::helper(1);"), Ok(expected));
+    }
+
+    #[test]
+    fn qualified_type() {
+        let input = "let p: *geo::Pair<i32> = q;";
+        let expected = vec![Expr::Declaration {
+            identifier: "p".into(),
+            var_type: Some(Types::Pointer(Box::new(Types::Generic(
+                "geo::Pair".into(),
+                vec![Types::I32],
+            )))),
+            value: Box::new(Expr::new_identifier("q")),
+        }];
+        assert_eq!(parse(input), Ok(expected));
+    }
+
+    #[test]
+    fn qualified_struct_literal() {
+        let expected = vec![Expr::StructLiteral {
+            identifier: "geo::Point".into(),
+            fields: vec![("x".into(), Expr::new_int_literal(1))],
+        }];
+        assert_eq!(parse("geo::Point { x: 1 };"), Ok(expected));
+    }
+
+    #[test]
+    fn imported_struct_literal() {
+        let input = "import geo::Point; Point { x: 1 };";
+        let expected = vec![
+            Expr::Import {
+                path: vec!["geo".into(), "Point".into()],
+            },
+            Expr::StructLiteral {
+                identifier: "Point".into(),
+                fields: vec![("x".into(), Expr::new_int_literal(1))],
+            },
+        ];
+        assert_eq!(parse(input), Ok(expected));
+    }
+
+    #[test]
+    fn qualified_impl() {
+        let input = "impl geo::Point { }";
+        let expected = vec![Expr::ImplBlock {
+            type_name: "geo::Point".into(),
+            methods: vec![],
+        }];
+        assert_eq!(parse(input), Ok(expected));
+    }
+}
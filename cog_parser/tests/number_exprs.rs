@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod number_exprs_test {
+    use cog_parser::parser::{
+        Parser,
+        core::{expr::Expr, nodes::Nodes},
+        errors::ParserError,
+    };
+
+    fn parse(input: &str) -> Result<Vec<Expr>, ParserError> {
+        Parser::new(input.to_string())?.parse()
+    }
+
+    #[test]
+    fn integer_forms() {
+        let expected = vec![
+            Expr::new_int_literal(255),
+            Expr::new_int_literal(8),
+            Expr::new_int_literal(5),
+            Expr::new_int_literal(1_000_000),
+            Expr::Literal(Nodes::Integer(u64::MAX)),
+        ];
+        assert_eq!(
+            parse("0xFf; 0o10; 0b101; 1_000_000; 0xFFFF_FFFF_FFFF_FFFF;"),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn float_forms() {
+        let expected = vec![
+            Expr::Literal(Nodes::Float(1.5)),
+            Expr::Literal(Nodes::Float(1e-9)),
+            Expr::Literal(Nodes::Float(2.5e3)),
+            Expr::Literal(Nodes::Float(1000.0)),
+            Expr::Literal(Nodes::Float(1e9)),
+        ];
+        assert_eq!(parse("1.5; 1e-9; 2.5E+3; 1_000.0; 1e9;"), Ok(expected));
+    }
+
+    #[test]
+    fn tuple_index_after_integer_literal_is_unchanged() {
+        let input = "t.0;";
+        let expected = vec![Expr::FieldAccess {
+            object: Box::new(Expr::new_identifier("t")),
+            field: "0".into(),
+        }];
+        assert_eq!(parse(input), Ok(expected));
+    }
+
+    #[test]
+    fn malformed_numbers() {
+        for literal in ["0x", "0b102", "0o8", "12ab", "0x_"] {
+            assert_eq!(
+                parse(&format!("{};", literal)),
+                Err(ParserError::InvalidNumber(literal.into()))
+            );
+        }
+    }
+
+    #[test]
+    fn overflowing_numbers() {
+        for literal in ["18446744073709551616", "0x1_0000_0000_0000_0000", "1e999"] {
+            assert_eq!(
+                parse(&format!("{};", literal)),
+                Err(ParserError::InvalidNumber(literal.into()))
+            );
+        }
+    }
+}
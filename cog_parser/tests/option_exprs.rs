@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod option_exprs_test {
+    use cog_parser::parser::{
+        Parser,
+        core::{
+            expr::{Expr, Variant},
+            nodes::Nodes,
+            types::Types,
+        },
+        errors::ParserError,
+    };
+
+    #[test]
+    fn option_and_result_types() {
+        let input = "fn f(x: Option<Option<i32>>) -> Result<*i8, bool> {}";
+        let expected = vec![Expr::FunctionDeclaration {
+            identifier: "f".into(),
+            parameters: vec![Expr::Declaration {
+                identifier: "x".into(),
+                var_type: Some(Types::Option(Box::new(Types::Option(Box::new(Types::I32))))),
+                value: Box::new(Expr::new_identifier("placeholder")),
+            }],
+            body: Box::new(Expr::Block(vec![])),
+            return_type: Some(Types::Result(
+                Box::new(Types::Pointer(Box::new(Types::I8))),
+                Box::new(Types::Bool),
+            )),
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn wrong_number_of_type_arguments() {
+        let input = "let x: Result<i32> = Ok(1);";
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert!(matches!(parser.parse(), Err(ParserError::ExpectedToken(_))));
+    }
+
+    #[test]
+    fn variant_constructors() {
+        let input = "let a: Option<i32> = None; let b: Result<i32, bool> = Err(true);";
+        let expected = vec![
+            Expr::Declaration {
+                identifier: "a".into(),
+                var_type: Some(Types::Option(Box::new(Types::I32))),
+                value: Box::new(Expr::Variant {
+                    variant: Variant::None,
+                    value: None,
+                }),
+            },
+            Expr::Declaration {
+                identifier: "b".into(),
+                var_type: Some(Types::Result(Box::new(Types::I32), Box::new(Types::Bool))),
+                value: Box::new(Expr::Variant {
+                    variant: Variant::Err,
+                    value: Some(Box::new(Expr::Literal(Nodes::Boolean(true)))),
+                }),
+            },
+        ];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn variant_name_without_parens_is_identifier() {
+        let input = "Some;";
+        let expected = vec![Expr::new_identifier("Some")];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn try_operator() {
+        let input = "f(x)?.unwrap();";
+        let expected = vec![Expr::MethodCall {
+            receiver: Box::new(Expr::Try(Box::new(Expr::Call {
+                callee: Box::new(Expr::new_identifier("f")),
+                arguments: vec![Expr::new_identifier("x")],
+            }))),
+            method: "unwrap".into(),
+            arguments: vec![],
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+}
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod print_exprs_test {
+    use cog_parser::parser::{
+        Parser,
+        core::{expr::Expr, nodes::Nodes},
+        errors::ParserError,
+    };
+
+    fn parse(input: &str) -> Result<Vec<Expr>, ParserError> {
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        parser.parse()
+    }
+
+    #[test]
+    fn println_with_placeholders() {
+        let expected = vec![Expr::Print {
+            pieces: vec!["x = ".into(), ", y = ".into(), "".into()],
+            arguments: vec![Expr::new_identifier("x"), Expr::Literal(Nodes::Integer(2))],
+            newline: true,
+        }];
+        assert_eq!(parse("println(\"x = {}, y = {}\", x, 2);"), Ok(expected));
+    }
+
+    #[test]
+    fn print_without_arguments() {
+        let expected = vec![Expr::Print {
+            pieces: vec!["done".into()],
+            arguments: vec![],
+            newline: false,
+        }];
+        assert_eq!(parse("print(\"done\");"), Ok(expected));
+    }
+
+    #[test]
+    fn escaped_braces() {
+        let expected = vec![Expr::Print {
+            pieces: vec!["{".into(), "}".into()],
+            arguments: vec![Expr::new_identifier("v")],
+            newline: true,
+        }];
+        assert_eq!(parse("println(\"{{{}}}\", v);"), Ok(expected));
+    }
+
+    #[test]
+    fn unmatched_brace() {
+        assert!(matches!(
+            parse("println(\"{x}\", x);"),
+            Err(ParserError::MalformedPrint(_))
+        ));
+    }
+
+    #[test]
+    fn format_must_be_literal() {
+        assert!(matches!(
+            parse("println(fmt, x);"),
+            Err(ParserError::MalformedPrint(_))
+        ));
+    }
+
+    #[test]
+    fn print_as_value_is_identifier() {
+        let expected = vec![Expr::Declaration {
+            identifier: "f".into(),
+            var_type: None,
+            value: Box::new(Expr::new_identifier("print")),
+        }];
+        assert_eq!(parse("let f = print;"), Ok(expected));
+    }
+}
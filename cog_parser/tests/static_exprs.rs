@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod static_exprs_test {
+    use cog_parser::parser::{
+        Parser,
+        core::{expr::Expr, ops::BinaryOp, types::Types},
+        errors::ParserError,
+    };
+
+    fn parse(input: &str) -> Result<Vec<Expr>, ParserError> {
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        parser.parse()
+    }
+
+    #[test]
+    fn static_declaration() {
+        let input = "static LIMIT: i32 = 1 << 10;";
+        let expected = vec![Expr::StaticDeclaration {
+            identifier: "LIMIT".into(),
+            var_type: Types::I32,
+            value: Box::new(Expr::Binary {
+                left: Box::new(Expr::new_int_literal(1)),
+                operator: Box::new(BinaryOp::ShiftLeft),
+                right: Box::new(Expr::new_int_literal(10)),
+            }),
+            mutable: false,
+        }];
+        assert_eq!(parse(input), Ok(expected));
+    }
+
+    #[test]
+    fn public_static_mut() {
+        let input = "pub static mut COUNT: u32 = 0;";
+        let expected = vec![Expr::Public {
+            item: Box::new(Expr::StaticDeclaration {
+                identifier: "COUNT".into(),
+                var_type: Types::U32,
+                value: Box::new(Expr::new_int_literal(0)),
+                mutable: true,
+            }),
+        }];
+        assert_eq!(parse(input), Ok(expected));
+    }
+
+    #[test]
+    fn assign_to_static_path() {
+        let input = "counter::COUNT = 1;";
+        let expected = vec![Expr::Assignment {
+            target: Box::new(Expr::Path(vec!["counter".into(), "COUNT".into()])),
+            value: Box::new(Expr::new_int_literal(1)),
+        }];
+        assert_eq!(parse(input), Ok(expected));
+    }
+
+    #[test]
+    fn static_without_type_is_error() {
+        let input = "static LIMIT = 10;";
+        assert!(matches!(parse(input), Err(ParserError::MalformedStatic(_))));
+    }
+}
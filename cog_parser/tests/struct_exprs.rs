@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod struct_exprs_test {
+    use cog_parser::parser::{
+        Parser,
+        core::{
+            expr::{Expr, StructField},
+            ops::BinaryOp,
+            types::Types,
+        },
+        errors::ParserError,
+    };
+
+    fn point_decl() -> Expr {
+        Expr::StructDeclaration {
+            identifier: "Point".into(),
+            fields: vec![
+                StructField {
+                    identifier: "x".into(),
+                    field_type: Types::I32,
+                    doc: None,
+                    public: false,
+                },
+                StructField {
+                    identifier: "y".into(),
+                    field_type: Types::I32,
+                    doc: None,
+                    public: false,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn struct_decl() {
+        let input = "struct Point { x: i32, y: i32 }";
+        let expected = vec![point_decl()];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn struct_literal() {
+        let input = "struct Point { x: i32, y: i32 }
+        let p: Point = Point { x: 1, y: 2 };";
+        let expected = vec![
+            point_decl(),
+            Expr::Declaration {
+                identifier: "p".into(),
+                var_type: Some(Types::Struct("Point".into())),
+                value: Box::new(Expr::StructLiteral {
+                    identifier: "Point".into(),
+                    fields: vec![
+                        ("x".into(), Expr::new_int_literal(1)),
+                        ("y".into(), Expr::new_int_literal(2)),
+                    ],
+                }),
+            },
+        ];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn field_assignment() {
+        let input = "p.x = p.y + 1;";
+        let expected = vec![Expr::Assignment {
+            target: Box::new(Expr::FieldAccess {
+                object: Box::new(Expr::new_identifier("p")),
+                field: "x".into(),
+            }),
+            value: Box::new(Expr::Binary {
+                left: Box::new(Expr::FieldAccess {
+                    object: Box::new(Expr::new_identifier("p")),
+                    field: "y".into(),
+                }),
+                operator: Box::new(BinaryOp::Add),
+                right: Box::new(Expr::new_int_literal(1)),
+            }),
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn impl_block() {
+        let input = "struct Point { x: i32, y: i32 }
+        impl Point {
+            fn sum(self: *Point) -> i32 {
+                return self.x + self.y;
+            }
+        }";
+        let expected = vec![
+            point_decl(),
+            Expr::ImplBlock {
+                type_name: "Point".into(),
+                methods: vec![Expr::FunctionDeclaration {
+                    identifier: "sum".into(),
+                    parameters: vec![Expr::Declaration {
+                        identifier: "self".into(),
+                        var_type: Some(Types::Pointer(Box::new(Types::Struct("Point".into())))),
+                        value: Box::new(Expr::new_identifier("placeholder")),
+                    }],
+                    body: Box::new(Expr::Block(vec![Expr::Return {
+                        value: Some(Box::new(Expr::Binary {
+                            left: Box::new(Expr::FieldAccess {
+                                object: Box::new(Expr::new_identifier("self")),
+                                field: "x".into(),
+                            }),
+                            operator: Box::new(BinaryOp::Add),
+                            right: Box::new(Expr::FieldAccess {
+                                object: Box::new(Expr::new_identifier("self")),
+                                field: "y".into(),
+                            }),
+                        })),
+                    }])),
+                    return_type: Some(Types::I32),
+                }],
+            },
+        ];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn method_call() {
+        let input = "p.translate(1, 2);";
+        let expected = vec![Expr::MethodCall {
+            receiver: Box::new(Expr::new_identifier("p")),
+            method: "translate".into(),
+            arguments: vec![Expr::new_int_literal(1), Expr::new_int_literal(2)],
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn associated_call() {
+        let input = "Point::new(1, 2);";
+        let expected = vec![Expr::Call {
+            callee: Box::new(Expr::Path(vec!["Point".into(), "new".into()])),
+            arguments: vec![Expr::new_int_literal(1), Expr::new_int_literal(2)],
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn impl_requires_functions() {
+        let input = "impl Point { let x: i32 = 1; }";
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert!(matches!(
+            parser.parse(),
+            Err(ParserError::ExpectedToken(_) | ParserError::MalformedFuncDecl(_))
+        ));
+    }
+}
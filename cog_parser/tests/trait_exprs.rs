@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod trait_exprs_test {
+    use cog_parser::parser::{
+        Parser,
+        core::{
+            expr::{Expr, TraitMethod, TypeParameter},
+            nodes::Nodes,
+            types::Types,
+        },
+        errors::ParserError,
+    };
+
+    fn self_param(self_type: Types) -> Expr {
+        Expr::Declaration {
+            identifier: "self".into(),
+            var_type: Some(Types::Pointer(Box::new(self_type))),
+            value: Box::new(Expr::new_identifier("placeholder")),
+        }
+    }
+
+    #[test]
+    fn trait_decl() {
+        let input = "trait Shape {
+            fn area(self: *Self) -> f64;
+            fn sides(self: *Self) -> i32 { return 0; }
+        }";
+        let expected = vec![Expr::TraitDeclaration {
+            identifier: "Shape".into(),
+            methods: vec![
+                TraitMethod {
+                    identifier: "area".into(),
+                    parameters: vec![self_param(Types::Param("Self".into()))],
+                    return_type: Some(Types::F64),
+                    body: None,
+                    doc: None,
+                },
+                TraitMethod {
+                    identifier: "sides".into(),
+                    parameters: vec![self_param(Types::Param("Self".into()))],
+                    return_type: Some(Types::I32),
+                    body: Some(Box::new(Expr::Block(vec![Expr::Return {
+                        value: Some(Box::new(Expr::new_int_literal(0))),
+                    }]))),
+                    doc: None,
+                },
+            ],
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn trait_impl() {
+        let input = "struct Square { side: f64 }
+        impl Shape for Square {
+            fn area(self: *Self) -> f64 { return 1.0; }
+        }";
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        let statements = parser.parse().expect("Failed to parse");
+        assert_eq!(
+            statements[1],
+            Expr::TraitImpl {
+                trait_name: "Shape".into(),
+                type_name: "Square".into(),
+                methods: vec![Expr::FunctionDeclaration {
+                    identifier: "area".into(),
+                    parameters: vec![self_param(Types::Struct("Square".into()))],
+                    body: Box::new(Expr::Block(vec![Expr::Return {
+                        value: Some(Box::new(Expr::Literal(Nodes::Float(1.0)))),
+                    }])),
+                    return_type: Some(Types::F64),
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn trait_bounds() {
+        let input = "fn total<T: Shape + Named, U>(a: T, b: U) {}";
+        let expected = vec![Expr::Generic {
+            type_parameters: vec![
+                TypeParameter {
+                    identifier: "T".into(),
+                    bounds: vec!["Shape".into(), "Named".into()],
+                },
+                TypeParameter {
+                    identifier: "U".into(),
+                    bounds: vec![],
+                },
+            ],
+            item: Box::new(Expr::FunctionDeclaration {
+                identifier: "total".into(),
+                parameters: vec![
+                    Expr::Declaration {
+                        identifier: "a".into(),
+                        var_type: Some(Types::Param("T".into())),
+                        value: Box::new(Expr::new_identifier("placeholder")),
+                    },
+                    Expr::Declaration {
+                        identifier: "b".into(),
+                        var_type: Some(Types::Param("U".into())),
+                        value: Box::new(Expr::new_identifier("placeholder")),
+                    },
+                ],
+                body: Box::new(Expr::Block(vec![])),
+                return_type: None,
+            }),
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn self_outside_trait_or_impl() {
+        let input = "fn f(x: Self) {}";
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Err(ParserError::UnknownType("Self".into())));
+    }
+
+    #[test]
+    fn dyn_pointer_param() {
+        let input = "fn draw(shape: *dyn Shape) {}";
+        let expected = vec![Expr::FunctionDeclaration {
+            identifier: "draw".into(),
+            parameters: vec![Expr::Declaration {
+                identifier: "shape".into(),
+                var_type: Some(Types::Pointer(Box::new(Types::Dyn("Shape".into())))),
+                value: Box::new(Expr::new_identifier("placeholder")),
+            }],
+            body: Box::new(Expr::Block(vec![])),
+            return_type: None,
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn dyn_without_trait_name() {
+        let input = "fn draw(shape: *dyn) {}";
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(
+            parser.parse(),
+            Err(ParserError::ExpectedToken(
+                "expected trait name after `dyn`".into()
+            ))
+        );
+    }
+}
@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tuple_exprs_test {
+    use cog_parser::parser::{
+        Parser,
+        core::{expr::Expr, nodes::Nodes, ops::BinaryOp, types::Types},
+        errors::ParserError,
+    };
+
+    #[test]
+    fn tuple_type() {
+        let input = "fn f() -> (i32, (bool, f64)) {}";
+        let expected = vec![Expr::FunctionDeclaration {
+            identifier: "f".into(),
+            parameters: vec![],
+            body: Box::new(Expr::Block(vec![])),
+            return_type: Some(Types::Tuple(vec![
+                Types::I32,
+                Types::Tuple(vec![Types::Bool, Types::F64]),
+            ])),
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn parenthesized_type_is_not_tuple() {
+        let input = "let x: (i32) = 1; let y: (i32,) = (1,);";
+        let expected = vec![
+            Expr::Declaration {
+                identifier: "x".into(),
+                var_type: Some(Types::I32),
+                value: Box::new(Expr::new_int_literal(1)),
+            },
+            Expr::Declaration {
+                identifier: "y".into(),
+                var_type: Some(Types::Tuple(vec![Types::I32])),
+                value: Box::new(Expr::Tuple(vec![Expr::new_int_literal(1)])),
+            },
+        ];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn tuple_expr_and_parens() {
+        let input = "(1, true); (1);";
+        let expected = vec![
+            Expr::Tuple(vec![
+                Expr::new_int_literal(1),
+                Expr::new_boolean_literal(true),
+            ]),
+            Expr::new_int_literal(1),
+        ];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn nested_index_access() {
+        let input = "t.1.0 + 1.5;";
+        let expected = vec![Expr::Binary {
+            left: Box::new(Expr::FieldAccess {
+                object: Box::new(Expr::FieldAccess {
+                    object: Box::new(Expr::new_identifier("t")),
+                    field: "1".into(),
+                }),
+                field: "0".into(),
+            }),
+            operator: Box::new(BinaryOp::Add),
+            right: Box::new(Expr::Literal(Nodes::Float(1.5))),
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn destructuring() {
+        let input = "let (q, r): (i32, i32) = divmod(7, 2);";
+        let expected = vec![Expr::TupleDeclaration {
+            identifiers: vec!["q".into(), "r".into()],
+            var_type: Some(Types::Tuple(vec![Types::I32, Types::I32])),
+            value: Box::new(Expr::Call {
+                callee: Box::new(Expr::new_identifier("divmod")),
+                arguments: vec![Expr::new_int_literal(7), Expr::new_int_literal(2)],
+            }),
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn destructuring_needs_names() {
+        let input = "let (1, r) = t;";
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(
+            parser.parse(),
+            Err(ParserError::MalformedVarDecl(
+                "expected identifier in tuple pattern".into()
+            ))
+        );
+    }
+}
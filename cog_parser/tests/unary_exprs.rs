@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod unary_exprs_tests {
+    use cog_parser::parser::{
+        Parser,
+        core::{expr::Expr, ops::UnaryOp},
+    };
+
+    #[test]
+    fn pre_increment_expr() {
+        let input = "++x";
+        let expected = vec![Expr::Unary {
+            operator: Box::new(UnaryOp::PreIncrement),
+            operand: Box::new(Expr::new_identifier("x")),
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn pre_decrement_expr() {
+        let input = "--x";
+        let expected = vec![Expr::Unary {
+            operator: Box::new(UnaryOp::PreDecrement),
+            operand: Box::new(Expr::new_identifier("x")),
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn post_increment_expr() {
+        let input = "x++";
+        let expected = vec![Expr::Unary {
+            operator: Box::new(UnaryOp::PostIncrement),
+            operand: Box::new(Expr::new_identifier("x")),
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn post_decrement_expr() {
+        let input = "x--";
+        let expected = vec![Expr::Unary {
+            operator: Box::new(UnaryOp::PostDecrement),
+            operand: Box::new(Expr::new_identifier("x")),
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn pre_increment_deref_expr() {
+        let input = "++*ptr";
+        let expected = vec![Expr::Unary {
+            operator: Box::new(UnaryOp::PreIncrement),
+            operand: Box::new(Expr::Dereference(Box::new(Expr::new_identifier("ptr")))),
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn post_increment_paren_deref_expr() {
+        let input = "(*ptr)++";
+        let expected = vec![Expr::Unary {
+            operator: Box::new(UnaryOp::PostIncrement),
+            operand: Box::new(Expr::Dereference(Box::new(Expr::new_identifier("ptr")))),
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn post_increment_binds_tighter_than_minus() {
+        let input = "-x++";
+        let expected = vec![Expr::Unary {
+            operator: Box::new(UnaryOp::Minus),
+            operand: Box::new(Expr::Unary {
+                operator: Box::new(UnaryOp::PostIncrement),
+                operand: Box::new(Expr::new_identifier("x")),
+            }),
+        }];
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        assert_eq!(parser.parse(), Ok(expected));
+    }
+}
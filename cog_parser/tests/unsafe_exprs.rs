@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod unsafe_exprs_test {
+    use cog_parser::parser::{
+        Parser,
+        core::{expr::Expr, nodes::Nodes, ops::BinaryOp, types::Types},
+        errors::ParserError,
+    };
+
+    fn parse(input: &str) -> Result<Vec<Expr>, ParserError> {
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        parser.parse()
+    }
+
+    fn pointer(t: Types) -> Types {
+        Types::Pointer(Box::new(t))
+    }
+
+    #[test]
+    fn null_literal() {
+        let input = "let p: *i32 = null;";
+        let expected = vec![Expr::Declaration {
+            identifier: "p".into(),
+            var_type: Some(pointer(Types::I32)),
+            value: Box::new(Expr::Literal(Nodes::Null)),
+        }];
+        assert_eq!(parse(input), Ok(expected));
+    }
+
+    #[test]
+    fn cast_binds_tighter_than_add() {
+        let input = "p as *u8 + 1;";
+        let expected = vec![Expr::Binary {
+            left: Box::new(Expr::Cast {
+                value: Box::new(Expr::new_identifier("p")),
+                target: pointer(Types::U8),
+            }),
+            operator: Box::new(BinaryOp::Add),
+            right: Box::new(Expr::new_int_literal(1)),
+        }];
+        assert_eq!(parse(input), Ok(expected));
+    }
+
+    #[test]
+    fn chained_casts() {
+        let input = "p as *void as *i64;";
+        let expected = vec![Expr::Cast {
+            value: Box::new(Expr::Cast {
+                value: Box::new(Expr::new_identifier("p")),
+                target: pointer(Types::Void),
+            }),
+            target: pointer(Types::I64),
+        }];
+        assert_eq!(parse(input), Ok(expected));
+    }
+
+    #[test]
+    fn unsafe_block() {
+        let input = "unsafe { p + 1; }";
+        let expected = vec![Expr::Unsafe(Box::new(Expr::Block(vec![Expr::Binary {
+            left: Box::new(Expr::new_identifier("p")),
+            operator: Box::new(BinaryOp::Add),
+            right: Box::new(Expr::new_int_literal(1)),
+        }])))];
+        assert_eq!(parse(input), Ok(expected));
+    }
+
+    #[test]
+    fn unsafe_without_block_is_error() {
+        let input = "unsafe p + 1;";
+        assert!(matches!(parse(input), Err(ParserError::MalformedUnsafe(_))));
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod visibility_exprs_test {
+    use cog_parser::parser::{
+        Parser,
+        core::{
+            expr::{Expr, StructField},
+            types::Types,
+        },
+        errors::ParserError,
+    };
+
+    fn parse(input: &str) -> Result<Vec<Expr>, ParserError> {
+        let mut parser = Parser::new(input.to_string()).expect("Failed to create parser");
+        parser.parse()
+    }
+
+    #[test]
+    fn pub_func_decl() {
+        let expected = vec![Expr::Public {
+            item: Box::new(Expr::FunctionDeclaration {
+                identifier: "f".into(),
+                parameters: vec![],
+                body: Box::new(Expr::Block(vec![])),
+                return_type: None,
+            }),
+        }];
+        assert_eq!(parse("pub fn f() {}"), Ok(expected));
+    }
+
+    #[test]
+    fn pub_struct_fields() {
+        let input = "pub struct Point { pub x: i32, y: i32 }";
+        let expected = vec![Expr::Public {
+            item: Box::new(Expr::StructDeclaration {
+                identifier: "Point".into(),
+                fields: vec![
+                    StructField {
+                        identifier: "x".into(),
+                        field_type: Types::I32,
+                        doc: None,
+                        public: true,
+                    },
+                    StructField {
+                        identifier: "y".into(),
+                        field_type: Types::I32,
+                        doc: None,
+                        public: false,
+                    },
+                ],
+            }),
+        }];
+        assert_eq!(parse(input), Ok(expected));
+    }
+
+    #[test]
+    fn documented_pub_item() {
+        let input = "/// Does nothing.\npub fn f() {}";
+        let parsed = parse(input).expect("Failed to parse");
+        assert!(matches!(&parsed[0], Expr::Documented { .. }));
+        assert!(parsed[0].is_public());
+        assert!(matches!(parsed[0].item(), Expr::FunctionDeclaration { .. }));
+    }
+
+    #[test]
+    fn pub_method() {
+        let input = "struct P { x: i32 } impl P { pub fn get(self: P) -> i32 { return self.x; } fn hidden() {} }";
+        let parsed = parse(input).expect("Failed to parse");
+        let Expr::ImplBlock { methods, .. } = &parsed[1] else {
+            panic!("expected impl block, got {:?}", parsed[1]);
+        };
+        assert!(methods[0].is_public());
+        assert!(!methods[1].is_public());
+    }
+
+    #[test]
+    fn pub_impl_block() {
+        assert!(matches!(
+            parse("struct P { x: i32 } pub impl P { }"),
+            Err(ParserError::MisplacedVisibility(_))
+        ));
+    }
+
+    #[test]
+    fn pub_trait_impl_method() {
+        let input = "trait T { fn f(self: *Self); } struct P { x: i32 } impl T for P { pub fn f(self: *P) {} }";
+        assert!(matches!(
+            parse(input),
+            Err(ParserError::MisplacedVisibility(_))
+        ));
+    }
+
+    #[test]
+    fn pub_expression() {
+        assert!(matches!(
+            parse("pub 1 + 2;"),
+            Err(ParserError::MisplacedVisibility(_))
+        ));
+    }
+}